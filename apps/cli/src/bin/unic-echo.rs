@@ -28,7 +28,9 @@ unic_arg_enum! {
         Codepoint,
         Codepoints,
         Utf8Hex,
-        Utf16Hex
+        Utf16Hex,
+        // Unicode Names
+        Names
     }
 }
 
@@ -39,6 +41,8 @@ macro_rules! input_formats_help {
     codepoints              Unicode codepoints (hex)
     utf8-hex                UTF-8 bytes (hex)
     utf16-hex               UTF-16 words (hex)
+    names                   Character names, aliases or named sequences,
+                            separated by commas
 "
     };
 }
@@ -153,6 +157,7 @@ fn run() -> Result<()> {
         InputFormat::Codepoint | InputFormat::Codepoints => parsers::codepoints(&input),
         InputFormat::Utf8Hex => parsers::utf8_hex(&input),
         InputFormat::Utf16Hex => parsers::utf16_hex(&input),
+        InputFormat::Names => parsers::names(&input),
     };
     let chars = string.chars();
 
@@ -13,6 +13,8 @@ use std::str;
 
 use regex::Regex;
 
+use unic::ucd::string_from_name;
+
 lazy_static! {
     // Anything not alphanumeric or `+`
     static ref CODEPOINT_SEPARATORS: Regex = Regex::new(r#"[^\w+]"#).unwrap();
@@ -70,3 +72,16 @@ pub fn utf16_hex(string: &str) -> String {
         .map(|r| r.expect("Invalid UTF-16 sequence"))
         .collect()
 }
+
+pub fn names(string: &str) -> String {
+    string
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            string_from_name(name)
+                .unwrap_or_else(|| panic!("Unknown character name: {}", name))
+                .to_string()
+        })
+        .collect()
+}
@@ -191,6 +191,38 @@ fn test_utf16_hex_input() {
     .unwrap();
 }
 
+#[test]
+fn test_names_input() {
+    fn run(args: &[&str]) -> Assert {
+        bin().with_args(&["--input", "names"]).with_args(args)
+    }
+
+    // No args
+    run(&[]).stdout().is("").unwrap();
+
+    // One arg
+    run(&["LATIN CAPITAL LETTER H, latin small letter i"])
+        .stdout()
+        .is("Hi")
+        .unwrap();
+
+    // Loose matching, aliases and algorithmic names
+    run(&[
+        "hangul_syllable_ga,",
+        "CJK UNIFIED IDEOGRAPH-8A00,",
+        "zero-width space, BOM",
+    ])
+    .stdout()
+    .is("가言\u{200B}\u{FEFF}")
+    .unwrap();
+
+    // Named sequences
+    run(&["KEYCAP DIGIT ONE, LATIN SMALL LETTER A WITH MACRON AND GRAVE"])
+        .stdout()
+        .is("1\u{FE0F}\u{20E3}\u{101}\u{300}")
+        .unwrap();
+}
+
 // == Output Formats ==
 
 #[test]
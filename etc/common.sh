@@ -34,10 +34,14 @@ export COMPONENTS="
     unic/ucd/block
     unic/ucd/case
     unic/ucd/common
+    unic/ucd/east_asian_width
     unic/ucd/ident
+    unic/ucd/joining
     unic/ucd/name
     unic/ucd/name_aliases
     unic/ucd/normal
+    unic/ucd/numeric
+    unic/ucd/script
     unic/ucd/segment
 
     unic/ucd
@@ -46,15 +50,24 @@ export COMPONENTS="
 
     unic/normal
 
+    unic/emoji/char
+
     unic/segment
 
+    unic/case
+
     unic/idna/mapping
     unic/idna/punycode
     unic/idna
 
-    unic/emoji/char
     unic/emoji
 
+    unic/ident
+
+    unic/security
+
+    unic/width
+
     unic
 "
 
@@ -27,7 +27,7 @@ mod writer;
 fn validate_component_name(name: String) -> Result<(), String> {
     if matches!(
         name.as_str(),
-        "ucd" | "normal" | "segment" | "idna" | "emoji"
+        "ucd" | "normal" | "segment" | "case" | "idna" | "emoji" | "security"
     ) {
         Ok(())
     } else {
@@ -57,10 +57,16 @@ fn main() {
     if components.is_empty() || components.contains(&"segment") {
         writer::segment::generate();
     }
+    if components.is_empty() || components.contains(&"case") {
+        writer::case::generate();
+    }
     if components.is_empty() || components.contains(&"idna") {
         writer::idna::generate();
     }
     if components.is_empty() || components.contains(&"emoji") {
         writer::emoji::generate();
     }
+    if components.is_empty() || components.contains(&"security") {
+        writer::security::generate();
+    }
 }
@@ -43,6 +43,10 @@ pub struct EmojiData {
     /// Characters that normally do not appear on emoji keyboards as separate choices, such as
     /// Keycap base characters, Regional_Indicators, ….
     pub emoji_component: BTreeSet<char>,
+
+    /// Characters that are used to future-proof segmentation, including pictographic symbols and
+    /// reserved code points in the ranges of emoji.
+    pub extended_pictographic: BTreeSet<char>,
 }
 
 impl FromStr for EmojiData {
@@ -66,6 +70,7 @@ impl FromStr for EmojiData {
                 "Emoji_Modifier" => props.emoji_modifier.extend(range),
                 "Emoji_Modifier_Base" => props.emoji_modifier_base.extend(range),
                 "Emoji_Component" => props.emoji_component.extend(range),
+                "Extended_Pictographic" => props.extended_pictographic.extend(range),
                 prop => panic!("Unsupported EmojiData property `{}`", prop),
             }
         }
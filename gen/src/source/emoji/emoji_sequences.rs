@@ -0,0 +1,89 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref EMOJI_SEQUENCES: EmojiSequencesData = {
+        read("external/unicode/emoji/data/emoji-sequences.txt")
+            .parse()
+            .unwrap()
+    };
+    pub static ref EMOJI_ZWJ_SEQUENCES: EmojiSequencesData = {
+        read("external/unicode/emoji/data/emoji-zwj-sequences.txt")
+            .parse()
+            .unwrap()
+    };
+}
+
+/// Emoji sequences, with their type fields, from `emoji-sequences.txt` or
+/// `emoji-zwj-sequences.txt`.
+///
+/// Ref: <https://www.unicode.org/reports/tr51/#Data_Files>
+#[derive(Clone, Debug, Default)]
+pub struct EmojiSequencesData {
+    pub map: BTreeMap<String, String>,
+}
+
+impl FromStr for EmojiSequencesData {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^
+                  ([[:xdigit:]]{4,6}(?:\x20[[:xdigit:]]{4,6})*)  # code points
+                  [[:blank:]]*;[[:blank:]]*
+                  ([[:word:]]+)                                  # type field
+                  [[:blank:]]*;
+                ",
+            )
+            .unwrap();
+        }
+
+        let mut map = BTreeMap::default();
+
+        for capture in REGEX.captures_iter(str) {
+            let sequence = capture[1]
+                .split(' ')
+                .map(|s| char::from_u32(u32::from_str_radix(s, 16).unwrap()).unwrap())
+                .collect();
+            map.insert(sequence, capture[2].to_owned());
+        }
+
+        Ok(EmojiSequencesData { map })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EmojiSequencesData;
+
+    #[test]
+    fn parse_emoji_sequences() {
+        let data: EmojiSequencesData = "\
+# comment
+0023 FE0F 20E3 ; Emoji_Combining_Sequence ; keycap: # # #️⃣
+1F1E6 1F1E8    ; Emoji_Flag_Sequence      ; Ascension Island # 🇦🇨
+"
+        .parse()
+        .unwrap();
+
+        assert_eq!(data.map.len(), 2);
+        assert_eq!(data.map["#\u{FE0F}\u{20E3}"], "Emoji_Combining_Sequence");
+        assert_eq!(data.map["\u{1F1E6}\u{1F1E8}"], "Emoji_Flag_Sequence");
+    }
+}
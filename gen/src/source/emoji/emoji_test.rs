@@ -0,0 +1,144 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref EMOJI_TEST: EmojiTestData = {
+        read("external/unicode/emoji/data/emoji-test.txt")
+            .parse()
+            .unwrap()
+    };
+}
+
+/// An emoji from `emoji-test.txt`, with its qualification status, CLDR short name, group and
+/// subgroup.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmojiTestEntry {
+    pub sequence: String,
+    pub status: String,
+    pub name: String,
+    pub group: String,
+    pub subgroup: String,
+}
+
+/// Emoji from `emoji-test.txt`, in the order of the file (CLDR order).
+///
+/// Ref: <https://www.unicode.org/reports/tr51/#Data_Files>
+#[derive(Clone, Debug, Default)]
+pub struct EmojiTestData {
+    pub entries: Vec<EmojiTestEntry>,
+}
+
+impl FromStr for EmojiTestData {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref GROUP_REGEX: Regex = Regex::new(r"^#\s*(sub)?group:\s*(.+?)\s*$").unwrap();
+            static ref ENTRY_REGEX: Regex = Regex::new(
+                r"(?x)^
+                  ([[:xdigit:]]{4,6}(?:\x20[[:xdigit:]]{4,6})*)  # code points
+                  [[:blank:]]*;[[:blank:]]*
+                  ([[:alpha:]-]+)                                # status
+                  [[:blank:]]*\#[[:blank:]]*
+                  \S+[[:blank:]]+                                # emoji
+                  (?:E[[:digit:]]+\.[[:digit:]]+[[:blank:]]+)?   # emoji version
+                  (.+?)                                          # name
+                  [[:blank:]]*$
+                ",
+            )
+            .unwrap();
+        }
+
+        let mut entries = vec![];
+        let mut group = String::new();
+        let mut subgroup = String::new();
+
+        for line in str.lines() {
+            if let Some(capture) = GROUP_REGEX.captures(line) {
+                if capture.get(1).is_some() {
+                    subgroup = capture[2].to_owned();
+                } else {
+                    group = capture[2].to_owned();
+                }
+            } else if let Some(capture) = ENTRY_REGEX.captures(line) {
+                let sequence = capture[1]
+                    .split(' ')
+                    .map(|s| char::from_u32(u32::from_str_radix(s, 16).unwrap()).unwrap())
+                    .collect();
+                entries.push(EmojiTestEntry {
+                    sequence,
+                    status: capture[2].to_owned(),
+                    name: capture[3].to_owned(),
+                    group: group.clone(),
+                    subgroup: subgroup.clone(),
+                });
+            }
+        }
+
+        Ok(EmojiTestData { entries })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EmojiTestData, EmojiTestEntry};
+
+    #[test]
+    fn parse_emoji_test() {
+        let data: EmojiTestData = "\
+# Format: code points; status # emoji name
+
+# group: Smileys & Emotion
+
+# subgroup: face-smiling
+1F600 ; fully-qualified # 😀 E1.0 grinning face
+
+# subgroup: face-affection
+263A FE0F ; fully-qualified # ☺️ E0.6 smiling face
+263A      ; unqualified     # ☺ smiling face
+"
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            data.entries,
+            vec![
+                EmojiTestEntry {
+                    sequence: "\u{1F600}".to_owned(),
+                    status: "fully-qualified".to_owned(),
+                    name: "grinning face".to_owned(),
+                    group: "Smileys & Emotion".to_owned(),
+                    subgroup: "face-smiling".to_owned(),
+                },
+                EmojiTestEntry {
+                    sequence: "\u{263A}\u{FE0F}".to_owned(),
+                    status: "fully-qualified".to_owned(),
+                    name: "smiling face".to_owned(),
+                    group: "Smileys & Emotion".to_owned(),
+                    subgroup: "face-affection".to_owned(),
+                },
+                EmojiTestEntry {
+                    sequence: "\u{263A}".to_owned(),
+                    status: "unqualified".to_owned(),
+                    name: "smiling face".to_owned(),
+                    group: "Smileys & Emotion".to_owned(),
+                    subgroup: "face-affection".to_owned(),
+                },
+            ]
+        );
+    }
+}
@@ -9,4 +9,6 @@
 // except according to those terms.
 
 pub mod emoji_data;
+pub mod emoji_sequences;
+pub mod emoji_test;
 pub mod readme;
@@ -11,6 +11,7 @@
 pub mod common;
 pub mod emoji;
 pub mod idna;
+pub mod security;
 pub mod ucd;
 
 mod utils;
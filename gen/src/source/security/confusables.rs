@@ -0,0 +1,82 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref CONFUSABLES_DATA: ConfusablesData = {
+        read("external/unicode/security/data/confusables.txt")
+            .parse()
+            .expect("Failed parsing source data")
+    };
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ConfusablesData {
+    /// Prototype of each source character.
+    pub map: BTreeMap<char, String>,
+}
+
+impl FromStr for ConfusablesData {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^                                            # every line
+                  ([[:xdigit:]]{4,6})\x20;\t                        # source
+                  ([[:xdigit:]]{4,6}(?:\x20[[:xdigit:]]{4,6})*)\x20;\t  # target
+                  MA                                                # type
+                ",
+            )
+            .unwrap();
+        }
+
+        let map = REGEX
+            .captures_iter(str)
+            .map(|capture| {
+                (
+                    char::from_u32(u32::from_str_radix(&capture[1], 16).unwrap()).unwrap(),
+                    capture[2]
+                        .split(' ')
+                        .map(|code| char::from_u32(u32::from_str_radix(code, 16).unwrap()).unwrap())
+                        .collect(),
+                )
+            })
+            .collect();
+
+        Ok(ConfusablesData { map })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConfusablesData;
+
+    #[test]
+    fn parse_confusables() {
+        let data: ConfusablesData = "\
+            0022 ;\t0027 0027 ;\tMA\t# ( \" → '' ) QUOTATION MARK → APOSTROPHE + APOSTROPHE\n\
+            0031 ;\t006C ;\tMA\t# ( 1 → l ) DIGIT ONE → LATIN SMALL LETTER L\n\
+            "
+        .parse()
+        .unwrap();
+
+        assert_eq!(data.map.len(), 2);
+        assert_eq!(data.map[&'"'], "''");
+        assert_eq!(data.map[&'1'], "l");
+    }
+}
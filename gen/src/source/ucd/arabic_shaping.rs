@@ -0,0 +1,84 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref ARABIC_SHAPING_DATA: ArabicShapingData = {
+        read("external/unicode/ucd/data/ArabicShaping.txt")
+            .parse()
+            .expect("Failed parsing source data")
+    };
+}
+
+/// Explicit `Joining_Type` values, by abbreviated name.
+///
+/// Characters not listed here default to `T` if their General_Category is `Mn`, `Me` or `Cf`,
+/// and to `U` otherwise.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArabicShapingData {
+    pub joining_type: BTreeMap<char, String>,
+}
+
+impl FromStr for ArabicShapingData {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^                      # every line
+                  ([[:xdigit:]]{4,6});\x20    # code
+                  [^;]*;\x20                  # name
+                  ([UTDRLC]);                 # joining type
+                ",
+            )
+            .unwrap();
+        }
+
+        let joining_type = REGEX
+            .captures_iter(str)
+            .map(|capture| {
+                (
+                    char::from_u32(u32::from_str_radix(&capture[1], 16).unwrap()).unwrap(),
+                    capture[2].to_owned(),
+                )
+            })
+            .collect();
+
+        Ok(ArabicShapingData { joining_type })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ArabicShapingData;
+
+    #[test]
+    fn parse_arabic_shaping() {
+        let data: ArabicShapingData = "\
+            0600; ARABIC NUMBER SIGN; U; No_Joining_Group\n\
+            0620; DOTLESS YEH WITH SEPARATE RING BELOW; D; YEH\n\
+            200D; ZERO WIDTH JOINER; C; No_Joining_Group\n\
+            "
+        .parse()
+        .unwrap();
+
+        assert_eq!(data.joining_type.len(), 3);
+        assert_eq!(data.joining_type[&'\u{0600}'], "U");
+        assert_eq!(data.joining_type[&'\u{0620}'], "D");
+        assert_eq!(data.joining_type[&'\u{200D}'], "C");
+    }
+}
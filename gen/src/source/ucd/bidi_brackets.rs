@@ -0,0 +1,91 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref BIDI_BRACKETS_DATA: BidiBracketsData = {
+        read("external/unicode/ucd/data/BidiBrackets.txt")
+            .parse()
+            .expect("Failed parsing source data")
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BidiBracketsEntry {
+    pub character: char,
+    pub paired_bracket: char,
+    /// One of `o` (open) or `c` (close).
+    pub bracket_type: char,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BidiBracketsData {
+    pub entries: Vec<BidiBracketsEntry>,
+}
+
+impl FromStr for BidiBracketsData {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^                      # every line
+                  ([[:xdigit:]]{4,6});\x20    # code
+                  ([[:xdigit:]]{4,6});\x20    # paired bracket
+                  ([oc])                      # type
+                ",
+            )
+            .unwrap();
+        }
+
+        let entries = REGEX
+            .captures_iter(str)
+            .map(|capture| BidiBracketsEntry {
+                character: char::from_u32(u32::from_str_radix(&capture[1], 16).unwrap()).unwrap(),
+                paired_bracket: char::from_u32(u32::from_str_radix(&capture[2], 16).unwrap())
+                    .unwrap(),
+                bracket_type: capture[3].chars().next().unwrap(),
+            })
+            .collect();
+
+        Ok(BidiBracketsData { entries })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BidiBracketsData;
+
+    #[test]
+    fn parse_bidi_brackets() {
+        let data: BidiBracketsData = "\
+            0028; 0029; o # LEFT PARENTHESIS\n\
+            0029; 0028; c # RIGHT PARENTHESIS\n\
+            "
+        .parse()
+        .unwrap();
+
+        assert_eq!(data.entries.len(), 2);
+
+        assert_eq!(data.entries[0].character, '(');
+        assert_eq!(data.entries[0].paired_bracket, ')');
+        assert_eq!(data.entries[0].bracket_type, 'o');
+
+        assert_eq!(data.entries[1].character, ')');
+        assert_eq!(data.entries[1].paired_bracket, '(');
+        assert_eq!(data.entries[1].bracket_type, 'c');
+    }
+}
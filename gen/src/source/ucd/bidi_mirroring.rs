@@ -0,0 +1,80 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref BIDI_MIRRORING_DATA: BidiMirroringData = {
+        read("external/unicode/ucd/data/BidiMirroring.txt")
+            .parse()
+            .expect("Failed parsing source data")
+    };
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BidiMirroringData {
+    pub map: BTreeMap<char, char>,
+}
+
+impl FromStr for BidiMirroringData {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^                      # every line
+                  ([[:xdigit:]]{4,6});\x20    # code
+                  ([[:xdigit:]]{4,6})         # mirroring glyph
+                ",
+            )
+            .unwrap();
+        }
+
+        let map = REGEX
+            .captures_iter(str)
+            .map(|capture| {
+                (
+                    char::from_u32(u32::from_str_radix(&capture[1], 16).unwrap()).unwrap(),
+                    char::from_u32(u32::from_str_radix(&capture[2], 16).unwrap()).unwrap(),
+                )
+            })
+            .collect();
+
+        Ok(BidiMirroringData { map })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BidiMirroringData;
+
+    #[test]
+    fn parse_bidi_mirroring() {
+        let data: BidiMirroringData = "\
+            # BidiMirroring-10.0.0.txt\n\
+            0028; 0029 # LEFT PARENTHESIS\n\
+            0029; 0028 # RIGHT PARENTHESIS\n\
+            2208; 220B # ELEMENT OF\n\
+            "
+        .parse()
+        .unwrap();
+
+        assert_eq!(data.map.len(), 3);
+        assert_eq!(data.map[&'('], ')');
+        assert_eq!(data.map[&')'], '(');
+        assert_eq!(data.map[&'\u{2208}'], '\u{220B}');
+    }
+}
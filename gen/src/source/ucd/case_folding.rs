@@ -0,0 +1,97 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref CASE_FOLDING_DATA: CaseFoldingData = {
+        read("external/unicode/ucd/data/CaseFolding.txt")
+            .parse()
+            .expect("Failed parsing source data")
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CaseFoldingEntry {
+    pub character: char,
+    /// One of `C` (common), `F` (full), `S` (simple) or `T` (Turkic).
+    pub status: char,
+    pub mapping: Box<[char]>,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CaseFoldingData {
+    pub entries: Vec<CaseFoldingEntry>,
+}
+
+impl FromStr for CaseFoldingData {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^                    # every line
+                  ([[:xdigit:]]{4,6});\x20  # code
+                  ([CFST]);\x20             # status
+                  ([[:xdigit:]\x20]+);      # mapping
+                ",
+            )
+            .unwrap();
+        }
+
+        let entries = REGEX
+            .captures_iter(str)
+            .map(|capture| CaseFoldingEntry {
+                character: char::from_u32(u32::from_str_radix(&capture[1], 16).unwrap()).unwrap(),
+                status: capture[2].chars().next().unwrap(),
+                mapping: capture[3]
+                    .split_whitespace()
+                    .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+                    .collect::<Vec<_>>()
+                    .into_boxed_slice(),
+            })
+            .collect();
+
+        Ok(CaseFoldingData { entries })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CaseFoldingData;
+
+    #[test]
+    fn parse_case_folding() {
+        let data: CaseFoldingData = "\
+            0041; C; 0061; # LATIN CAPITAL LETTER A\n\
+            1E9E; F; 0073 0073; # LATIN CAPITAL LETTER SHARP S\n\
+            1E9E; S; 00DF; # LATIN CAPITAL LETTER SHARP S\n\
+            "
+        .parse()
+        .unwrap();
+
+        assert_eq!(data.entries.len(), 3);
+
+        assert_eq!(data.entries[0].character, 'A');
+        assert_eq!(data.entries[0].status, 'C');
+        assert_eq!(&*data.entries[0].mapping, &['a']);
+
+        assert_eq!(data.entries[1].status, 'F');
+        assert_eq!(&*data.entries[1].mapping, &['s', 's']);
+
+        assert_eq!(data.entries[2].status, 'S');
+        assert_eq!(&*data.entries[2].mapping, &['\u{DF}']);
+    }
+}
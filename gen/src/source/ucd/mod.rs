@@ -10,15 +10,26 @@
 
 pub mod test;
 
+pub mod arabic_shaping;
+pub mod bidi_brackets;
+pub mod bidi_mirroring;
 pub mod blocks;
+pub mod case_folding;
 pub mod derived_age;
 pub mod derived_core_properties;
 pub mod derived_normalization_props;
+pub mod east_asian_width;
 pub mod grapheme_break_property;
+pub mod hangul_syllable_type;
 pub mod jamo;
+pub mod line_break;
 pub mod name_aliases;
+pub mod named_sequences;
 pub mod prop_list;
 pub mod readme;
+pub mod script_extensions;
+pub mod scripts;
+pub mod special_casing;
 pub mod sentence_break_property;
 pub mod unicode_data;
 pub mod word_break_property;
@@ -0,0 +1,90 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref NAMED_SEQUENCES_DATA: NamedSequencesData =
+        read("external/unicode/ucd/data/NamedSequences.txt")
+            .parse()
+            .unwrap();
+    pub static ref PROVISIONAL_NAMED_SEQUENCES_DATA: NamedSequencesData =
+        read("external/unicode/ucd/data/NamedSequencesProv.txt")
+            .parse()
+            .unwrap();
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NamedSequencesData {
+    // A map from sequence name to the character sequence
+    pub map: BTreeMap<String, String>,
+}
+
+impl FromStr for NamedSequencesData {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^                  # every line
+                  ([[:alnum:]][[:alnum:]\ \-]*) # name
+                  ;[[:blank:]]*           # separator
+                  ([[:xdigit:]\ ]+)       # code point sequence
+                ",
+            )
+            .unwrap();
+        }
+
+        let mut map = BTreeMap::default();
+        for capture in REGEX.captures_iter(str) {
+            let sequence = capture[2]
+                .split_whitespace()
+                .map(|code_point| {
+                    char::from_u32(u32::from_str_radix(code_point, 16).unwrap()).unwrap()
+                })
+                .collect();
+            map.insert(capture[1].trim().to_owned(), sequence);
+        }
+
+        Ok(NamedSequencesData { map })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NamedSequencesData;
+
+    #[test]
+    fn parse_named_sequences() {
+        let data: NamedSequencesData = "\
+            # Named keycap sequences for telephone keypad (used for emoji)\n\
+            \n\
+            KEYCAP NUMBER SIGN;0023 FE0F 20E3\n\
+            LATIN SMALL LETTER A WITH MACRON AND GRAVE;0101 0300\n\
+            TAMIL SYLLABLE KSSA;0B95 0BCD 0BB7\n\
+            "
+        .parse()
+        .unwrap();
+
+        assert_eq!(data.map.len(), 3);
+        assert_eq!(data.map["KEYCAP NUMBER SIGN"], "#\u{FE0F}\u{20E3}");
+        assert_eq!(
+            data.map["LATIN SMALL LETTER A WITH MACRON AND GRAVE"],
+            "\u{101}\u{300}"
+        );
+        assert_eq!(data.map["TAMIL SYLLABLE KSSA"], "\u{B95}\u{BCD}\u{BB7}");
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref SPECIAL_CASING_DATA: SpecialCasingData = {
+        read("external/unicode/ucd/data/SpecialCasing.txt")
+            .parse()
+            .expect("Failed parsing source data")
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpecialCasingEntry {
+    pub character: char,
+    pub lower: Box<[char]>,
+    pub title: Box<[char]>,
+    pub upper: Box<[char]>,
+    /// Space-separated list of language and context conditions, if any.
+    pub conditions: Option<String>,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpecialCasingData {
+    pub entries: Vec<SpecialCasingEntry>,
+}
+
+fn parse_chars(s: &str) -> Box<[char]> {
+    s.split_whitespace()
+        .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+}
+
+impl FromStr for SpecialCasingData {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^                         # every line
+                  ([[:xdigit:]]{4,6});\x20       # code
+                  ([[:xdigit:]\x20]*);\x20       # lower
+                  ([[:xdigit:]\x20]*);\x20       # title
+                  ([[:xdigit:]\x20]*);           # upper
+                  (?:\x20([[:alpha:]_\x20]+);)?  # condition_list (option)
+                ",
+            )
+            .unwrap();
+        }
+
+        let entries = REGEX
+            .captures_iter(str)
+            .map(|capture| SpecialCasingEntry {
+                character: char::from_u32(u32::from_str_radix(&capture[1], 16).unwrap()).unwrap(),
+                lower: parse_chars(&capture[2]),
+                title: parse_chars(&capture[3]),
+                upper: parse_chars(&capture[4]),
+                conditions: capture.get(5).map(|m| m.as_str().to_owned()),
+            })
+            .collect();
+
+        Ok(SpecialCasingData { entries })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SpecialCasingData;
+
+    #[test]
+    fn parse_special_casing() {
+        let data: SpecialCasingData = "\
+            00DF; 00DF; 0053 0073; 0053 0053; # LATIN SMALL LETTER SHARP S\n\
+            03A3; 03C2; 03A3; 03A3; Final_Sigma; # GREEK CAPITAL LETTER SIGMA\n\
+            0307; ; 0307; 0307; tr After_I; # COMBINING DOT ABOVE\n\
+            "
+        .parse()
+        .unwrap();
+
+        assert_eq!(data.entries.len(), 3);
+
+        assert_eq!(data.entries[0].character, '\u{DF}');
+        assert_eq!(&*data.entries[0].lower, &['\u{DF}']);
+        assert_eq!(&*data.entries[0].title, &['\u{53}', '\u{73}']);
+        assert_eq!(&*data.entries[0].upper, &['\u{53}', '\u{53}']);
+        assert_eq!(data.entries[0].conditions, None);
+
+        assert_eq!(data.entries[1].conditions, Some("Final_Sigma".to_owned()));
+
+        assert!(data.entries[2].lower.is_empty());
+        assert_eq!(data.entries[2].conditions, Some("tr After_I".to_owned()));
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::source::utils::read;
+
+use regex::Regex;
+
+lazy_static! {
+    pub static ref LINE_BREAK_TESTS: LineBreakTests = {
+        read("external/unicode/ucd/data/auxiliary/LineBreakTest.txt")
+            .parse()
+            .unwrap()
+    };
+}
+
+pub struct LineBreakTests {
+    pub entries: Vec<LineBreakTest>,
+}
+
+/// Represents a Test Case, containing a sequence of characters, and for each pair of adjacent
+/// chars, if there is a line break opportunity between them or not.
+///
+/// The comment part of the test lines is not parsed, as it lists the resolved classes of the
+/// characters, which do not always match the `Line_Break` property value names.
+///
+/// Invariants:
+/// ```
+/// breaks.len() == chars.len() - 1
+/// ```
+#[derive(Debug)]
+pub struct LineBreakTest {
+    pub chars: Vec<char>,
+    pub breaks: Vec<bool>,
+}
+
+impl FromStr for LineBreakTests {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<LineBreakTests, ()> {
+        lazy_static! {
+            static ref LINE_RE: Regex = Regex::new(
+                r"(?xm)^\s*
+                    × \s+                 # source begin
+                    (\w.*\w)              # source captured
+                    \s+ ÷ \s*             # source end
+                    \#                    # delimiter
+                "
+            )
+            .unwrap();
+        }
+
+        let entries = LINE_RE
+            .captures_iter(string)
+            .filter_map(|line| {
+                let source_items: Vec<&str> =
+                    line[1].split_whitespace().map(|s| s.trim()).collect();
+
+                let codepoints: Vec<u32> = source_items
+                    .iter()
+                    .step(2)
+                    .map(|&s| u32::from_str_radix(s, 16).expect("Bad number"))
+                    .collect();
+                let chars: Vec<char> = codepoints
+                    .iter()
+                    .filter_map(|&u| char::from_u32(u))
+                    .collect();
+                // Skip if any surrogate or invalid codepoints are present
+                if codepoints.len() != chars.len() {
+                    return None;
+                }
+                assert_eq!(chars.len() * 2, source_items.len() + 1);
+
+                let breaks: Vec<bool> = source_items
+                    .iter()
+                    .dropping(1)
+                    .step(2)
+                    .map(|s| match *s {
+                        "÷" => true,
+                        "×" => false,
+                        t => panic!("Invalid token: {:?}", t),
+                    })
+                    .collect();
+                assert_eq!(breaks.len(), chars.len() - 1);
+
+                Some(LineBreakTest { chars, breaks })
+            })
+            .collect();
+
+        Ok(LineBreakTests { entries })
+    }
+}
@@ -119,8 +119,11 @@ pub struct UnicodeDataEntry {
     /// Some characters have these properties based on values from the Unihan data files.
     /// See [`Numeric_Type`, Han].
     ///
+    /// The value is parsed as a `(numerator, denominator)` pair, with a denominator of `1` for
+    /// integers.
+    ///
     /// [`Numeric_Type`, Han]: http://unicode.org/reports/tr44/#Numeric_Type_Han
-    pub numeric_numeric_value: Option<String>,
+    pub numeric_numeric_value: Option<(i64, u32)>,
 
     /// (9) If the character is a "mirrored" character in bidirectional text,
     /// this field has the value "Y" [true]; otherwise "N" [false].
@@ -180,6 +183,29 @@ pub struct UnicodeDataEntry {
     // by unsafe-hacking a u32::MAX char, and not losing any application space.
 }
 
+impl UnicodeDataEntry {
+    /// The `Numeric_Type` of the character, by abbreviated name, derived from fields 6 to 8.
+    pub fn numeric_type(&self) -> Option<&'static str> {
+        if self.decimal_numeric_value.is_some() {
+            Some("De")
+        } else if self.digit_numeric_value.is_some() {
+            Some("Di")
+        } else if self.numeric_numeric_value.is_some() {
+            Some("Nu")
+        } else {
+            None
+        }
+    }
+}
+
+/// Parse an integer or rational number, such as `-1/2` or `1000`.
+fn parse_rational(str: &str) -> (i64, u32) {
+    let mut parts = str.splitn(2, '/');
+    let numerator = parts.next().unwrap().parse().unwrap();
+    let denominator = parts.next().map_or(1, |s| s.parse().unwrap());
+    (numerator, denominator)
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct UnicodeData {
     pub entries: Box<[UnicodeDataEntry]>,
@@ -246,7 +272,7 @@ impl FromStr for UnicodeData {
                     }),
                     decimal_numeric_value: capture.get(8).map(|m| m.as_str().parse().unwrap()),
                     digit_numeric_value: capture.get(9).map(|m| m.as_str().parse().unwrap()),
-                    numeric_numeric_value: capture.get(10).map(|m| m.as_str().to_owned()),
+                    numeric_numeric_value: capture.get(10).map(|m| parse_rational(m.as_str())),
                     bidi_mirrored: &capture[11] == "Y",
                     unicode_1_name: capture.get(12).map(|m| m.as_str().to_owned()),
                     iso_comment: (),
@@ -302,6 +328,37 @@ impl FromStr for UnicodeData {
 mod test {
     use super::{UnicodeData, UnicodeDataEntry};
 
+    #[test]
+    fn unicode_data_numeric_parse() {
+        let data: UnicodeData = "\
+            0037;DIGIT SEVEN;Nd;0;EN;;7;7;7;N;;;;;\n\
+            00B2;SUPERSCRIPT TWO;No;0;EN;<super> 0032;;2;2;N;SUPERSCRIPT DIGIT TWO;;;;\n\
+            00BD;VULGAR FRACTION ONE HALF;No;0;ON;<fraction> 0031 2044 0032;;;1/2;N;FRACTION ONE HALF;;;;\n\
+            0F33;TIBETAN DIGIT HALF ZERO;No;0;L;;;;-1/2;N;;;;;\n\
+            2180;ROMAN NUMERAL ONE THOUSAND C D;Nl;0;L;;;;1000;N;;;;;\n\
+            0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;\n\
+            "
+        .parse()
+        .unwrap();
+
+        let numeric: Vec<_> = data
+            .entries
+            .iter()
+            .map(|x| (x.numeric_type(), x.numeric_numeric_value))
+            .collect();
+        assert_eq!(
+            numeric,
+            vec![
+                (Some("De"), Some((7, 1))),
+                (Some("Di"), Some((2, 1))),
+                (Some("Nu"), Some((1, 2))),
+                (Some("Nu"), Some((-1, 2))),
+                (Some("Nu"), Some((1000, 1))),
+                (None, None),
+            ]
+        );
+    }
+
     #[test]
     /// These are 5 randomly selected test cases (sorted for convenience)
     fn unicode_data_entry_parse() {
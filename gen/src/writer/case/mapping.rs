@@ -0,0 +1,123 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::source::ucd::case_folding::CASE_FOLDING_DATA;
+use crate::source::ucd::readme::UNICODE_VERSION;
+use crate::source::ucd::special_casing::SPECIAL_CASING_DATA;
+use crate::source::ucd::unicode_data::UNICODE_DATA;
+
+use crate::writer::common::emit_unicode_version;
+use crate::writer::utils::tables::ToDirectCharTable;
+use crate::writer::utils::write;
+
+pub fn generate(dir: &Path) {
+    emit_unicode_version(dir, &UNICODE_VERSION);
+    emit_mapping(dir, "lowercase_mapping.rsv", |m| &m.lower);
+    emit_mapping(dir, "uppercase_mapping.rsv", |m| &m.upper);
+    emit_mapping(dir, "titlecase_mapping.rsv", |m| &m.title);
+    emit_case_folding(dir, "simple_case_folding.rsv", 'S');
+    emit_case_folding(dir, "full_case_folding.rsv", 'F');
+}
+
+/// Full case mappings of a character.
+struct CaseMappings {
+    lower: Box<[char]>,
+    upper: Box<[char]>,
+    title: Box<[char]>,
+}
+
+/// Simple case mappings from `UnicodeData.txt`, overridden by the unconditional mappings from
+/// `SpecialCasing.txt`.
+///
+/// Conditional mappings are not included: the language-sensitive ones are not supported, and the
+/// context-sensitive `Final_Sigma` is implemented in `unic-case`.
+fn case_mappings<'a>() -> &'a BTreeMap<char, CaseMappings> {
+    lazy_static! {
+        static ref MAP: BTreeMap<char, CaseMappings> = {
+            let mut map: BTreeMap<char, CaseMappings> = UNICODE_DATA
+                .entries
+                .iter()
+                .map(|x| {
+                    let ch = x.character;
+                    let upper = x.simple_uppercase_mapping.unwrap_or(ch);
+                    let mappings = CaseMappings {
+                        lower: Box::new([x.simple_lowercase_mapping.unwrap_or(ch)]),
+                        upper: Box::new([upper]),
+                        // Titlecase defaults to the uppercase mapping, not to the character itself
+                        title: Box::new([x.simple_titlecase_mapping.unwrap_or(upper)]),
+                    };
+                    (ch, mappings)
+                })
+                .collect();
+
+            for entry in &SPECIAL_CASING_DATA.entries {
+                if entry.conditions.is_some() {
+                    continue;
+                }
+                map.insert(
+                    entry.character,
+                    CaseMappings {
+                        lower: entry.lower.clone(),
+                        upper: entry.upper.clone(),
+                        title: entry.title.clone(),
+                    },
+                );
+            }
+
+            map
+        };
+    }
+    &MAP
+}
+
+fn emit_mapping<F>(dir: &Path, file_name: &str, field: F)
+where
+    F: Fn(&CaseMappings) -> &[char],
+{
+    let map: BTreeMap<char, &[char]> = case_mappings()
+        .iter()
+        .map(|(&ch, mappings)| (ch, field(mappings)))
+        .filter(|&(ch, mapping)| mapping != [ch])
+        .collect();
+
+    emit_char_slice_table(dir, file_name, &map);
+}
+
+/// Emit the case folding with the common (`C`) mappings, and the ones of the given status: either
+/// simple (`S`) or full (`F`).
+///
+/// The Turkic (`T`) mappings are language-sensitive, and so not included.
+fn emit_case_folding(dir: &Path, file_name: &str, status: char) {
+    let map: BTreeMap<char, &[char]> = CASE_FOLDING_DATA
+        .entries
+        .iter()
+        .filter(|entry| entry.status == 'C' || entry.status == status)
+        .map(|entry| (entry.character, &*entry.mapping))
+        .collect();
+
+    emit_char_slice_table(dir, file_name, &map);
+}
+
+fn emit_char_slice_table(dir: &Path, file_name: &str, map: &BTreeMap<char, &[char]>) {
+    write(
+        dir,
+        file_name,
+        &map.to_direct_char_table(|mapping, f| {
+            write!(f, "&[")?;
+            for ch in mapping.iter() {
+                write!(f, "'{}',", ch.escape_unicode())?;
+            }
+            write!(f, "]")
+        }),
+    );
+}
@@ -9,9 +9,15 @@
 // except according to those terms.
 
 mod char;
+mod qualification;
+mod sequences;
 
 use crate::writer::utils::clean_dir;
 
 pub fn generate() {
     char::generate(&clean_dir("unic/emoji/char/tables"));
+
+    let dir = clean_dir("unic/emoji/tables");
+    sequences::generate(&dir);
+    qualification::generate(&dir);
 }
@@ -0,0 +1,105 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::source::emoji::emoji_test::EMOJI_TEST;
+
+use crate::writer::utils::write;
+
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+
+pub fn generate(dir: &Path) {
+    emit_emoji_test(dir);
+    emit_emoji_fully_qualified(dir);
+}
+
+fn escape(sequence: &str) -> String {
+    sequence
+        .chars()
+        .map(|ch| ch.escape_unicode().to_string())
+        .collect()
+}
+
+fn emit_emoji_test(dir: &Path) {
+    // Sorted by the sequences, for binary search
+    let map: BTreeMap<&str, _> = EMOJI_TEST
+        .entries
+        .iter()
+        .map(|entry| (entry.sequence.as_str(), entry))
+        .collect();
+
+    let mut contents = String::from("&[\n");
+    for (sequence, entry) in map {
+        let status = match entry.status.as_str() {
+            "component" => "Component",
+            "fully-qualified" => "FullyQualified",
+            "minimally-qualified" => "MinimallyQualified",
+            // Before Emoji 11.0, all emoji that are not fully-qualified
+            "unqualified" | "non-fully-qualified" => "Unqualified",
+            _ => panic!("Unknown emoji status: {}", entry.status),
+        };
+        writeln!(
+            contents,
+            "    (\"{}\", {}, {:?}, {:?}, {:?}),",
+            escape(sequence),
+            status,
+            entry.name,
+            entry.group,
+            entry.subgroup,
+        )
+        .unwrap();
+    }
+    contents.push(']');
+
+    write(dir, "emoji_test.rsv", &contents);
+}
+
+fn emit_emoji_fully_qualified(dir: &Path) {
+    let without_selectors = |sequence: &str| -> String {
+        sequence
+            .chars()
+            .filter(|&ch| ch != VARIATION_SELECTOR_16)
+            .collect()
+    };
+
+    let fully_qualified: BTreeMap<String, &str> = EMOJI_TEST
+        .entries
+        .iter()
+        .filter(|entry| entry.status == "fully-qualified")
+        .map(|entry| (without_selectors(&entry.sequence), entry.sequence.as_str()))
+        .collect();
+
+    let map: BTreeMap<&str, &str> = EMOJI_TEST
+        .entries
+        .iter()
+        .filter(|entry| entry.status != "fully-qualified" && entry.status != "component")
+        .map(|entry| {
+            let target = fully_qualified[&without_selectors(&entry.sequence)];
+            (entry.sequence.as_str(), target)
+        })
+        .collect();
+
+    let mut contents = String::from("&[\n");
+    for (sequence, target) in map {
+        writeln!(
+            contents,
+            "    (\"{}\", \"{}\"),",
+            escape(sequence),
+            escape(target)
+        )
+        .unwrap();
+    }
+    contents.push(']');
+
+    write(dir, "emoji_fully_qualified.rsv", &contents);
+}
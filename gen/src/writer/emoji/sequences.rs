@@ -0,0 +1,53 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::source::emoji::emoji_sequences::{EMOJI_SEQUENCES, EMOJI_ZWJ_SEQUENCES};
+use crate::source::emoji::readme::EMOJI_VERSION;
+
+use crate::writer::emoji::char::emit_emoji_data_version;
+use crate::writer::utils::write;
+
+pub fn generate(dir: &Path) {
+    emit_emoji_data_version(dir, &EMOJI_VERSION);
+    emit_emoji_sequences(dir);
+}
+
+fn emit_emoji_sequences(dir: &Path) {
+    // Sorted by the sequences, for binary search
+    let map: BTreeMap<&String, &String> = EMOJI_SEQUENCES
+        .map
+        .iter()
+        .chain(&EMOJI_ZWJ_SEQUENCES.map)
+        .collect();
+
+    let mut contents = String::from("&[\n");
+    for (sequence, type_field) in map {
+        let sequence_type = match type_field.as_str() {
+            "Emoji_Combining_Sequence" => "KeycapSequence",
+            "Emoji_Flag_Sequence" => "FlagSequence",
+            "Emoji_Tag_Sequence" => "TagSequence",
+            "Emoji_Modifier_Sequence" => "ModifierSequence",
+            "Emoji_ZWJ_Sequence" => "ZwjSequence",
+            _ => panic!("Unknown emoji sequence type: {}", type_field),
+        };
+        contents.push_str("    (\"");
+        for ch in sequence.chars() {
+            write!(contents, "{}", ch.escape_unicode()).unwrap();
+        }
+        writeln!(contents, "\", {}),", sequence_type).unwrap();
+    }
+    contents.push(']');
+
+    write(dir, "emoji_sequences.rsv", &contents);
+}
@@ -8,9 +8,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+pub mod case;
 pub mod emoji;
 pub mod idna;
 pub mod normal;
+pub mod security;
 pub mod segment;
 pub mod ucd;
 
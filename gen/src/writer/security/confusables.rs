@@ -0,0 +1,34 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::path::Path;
+
+use crate::source::security::confusables::CONFUSABLES_DATA;
+
+use crate::writer::utils::tables::ToDirectCharTable;
+use crate::writer::utils::write;
+
+pub fn generate(dir: &Path) {
+    emit_confusables(dir);
+}
+
+fn emit_confusables(dir: &Path) {
+    write(
+        dir,
+        "confusables.rsv",
+        &CONFUSABLES_DATA.map.to_direct_char_table(|prototype, f| {
+            write!(f, "\"")?;
+            for ch in prototype.chars() {
+                write!(f, "{}", ch.escape_unicode())?;
+            }
+            write!(f, "\"")
+        }),
+    );
+}
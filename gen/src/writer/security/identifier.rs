@@ -0,0 +1,42 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::path::Path;
+
+use crate::source::security::identifier_status::IDENTIFIER_STATUS_DATA;
+use crate::source::security::identifier_type::IDENTIFIER_TYPE_DATA;
+
+use crate::writer::utils::tables::ToRangeCharTable;
+use crate::writer::utils::write;
+
+pub fn generate(dir: &Path) {
+    emit_identifier_status(dir);
+    emit_identifier_type(dir);
+}
+
+fn emit_identifier_status(dir: &Path) {
+    write(
+        dir,
+        "identifier_status.rsv",
+        &IDENTIFIER_STATUS_DATA
+            .map
+            .to_range_char_table(|v, f| write!(f, "{}", v)),
+    );
+}
+
+fn emit_identifier_type(dir: &Path) {
+    write(
+        dir,
+        "identifier_type.rsv",
+        &IDENTIFIER_TYPE_DATA
+            .map
+            .to_range_char_table(|v, f| write!(f, "{}", v)),
+    );
+}
@@ -0,0 +1,42 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::source::ucd::unicode_data::UNICODE_DATA;
+
+use crate::writer::utils::tables::ToRangeCharTable;
+use crate::writer::utils::write;
+
+pub fn generate(dir: &Path) {
+    emit_decimal_digit_zero(dir);
+}
+
+/// Map each decimal digit (`General_Category=Nd`) to the zero digit of its decimal system.
+fn emit_decimal_digit_zero(dir: &Path) {
+    let map: BTreeMap<char, char> = UNICODE_DATA
+        .entries
+        .iter()
+        .filter(|x| x.general_category == "Nd")
+        .map(|x| {
+            let value = u32::from(x.decimal_numeric_value.expect("Nd without decimal value"));
+            let zero = char::from_u32(x.character as u32 - value).unwrap();
+            (x.character, zero)
+        })
+        .collect();
+
+    write(
+        dir,
+        "decimal_digit_zero.rsv",
+        &map.to_range_char_table(|v, f| write!(f, "'{}'", v.escape_unicode())),
+    );
+}
@@ -0,0 +1,26 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod confusables;
+mod identifier;
+mod mixed_number;
+
+use crate::source::security::readme::UNICODE_VERSION;
+
+use crate::writer::common::emit_unicode_version;
+use crate::writer::utils::clean_dir;
+
+pub fn generate() {
+    let dir = clean_dir("unic/security/tables");
+    emit_unicode_version(&dir, &UNICODE_VERSION);
+    confusables::generate(&dir);
+    identifier::generate(&dir);
+    mixed_number::generate(&dir);
+}
@@ -11,6 +11,8 @@
 use std::path::Path;
 
 use crate::source::ucd::test::grapheme_break_test::{GraphemeBreakTest, GRAPHEME_BREAK_TESTS};
+use crate::source::ucd::test::line_break_test::{LineBreakTest, LINE_BREAK_TESTS};
+use crate::source::ucd::test::sentence_break_test::{SentenceBreakTest, SENTENCE_BREAK_TESTS};
 use crate::source::ucd::test::word_break_test::{WordBreakTest, WORD_BREAK_TESTS};
 
 use crate::writer::utils::write;
@@ -18,6 +20,8 @@ use crate::writer::utils::write;
 pub fn generate(dir: &Path) {
     emit_grapheme_cluster_break_test_data(dir);
     emit_word_break_test_data(dir);
+    emit_sentence_break_test_data(dir);
+    emit_line_break_test_data(dir);
 }
 
 fn str_escape(s: &str) -> String {
@@ -128,3 +132,76 @@ fn emit_word_break_test_data(dir: &Path) {
 
     write(dir, "word_break_test_data.rsv", &contents);
 }
+
+fn emit_sentence_break_test_data(dir: &Path) {
+    let mut contents = "&[\n".to_owned();
+
+    for case in SENTENCE_BREAK_TESTS.entries.iter() {
+        let SentenceBreakTest {
+            ref chars,
+            ref breaks,
+            ..
+        } = *case;
+
+        contents.push_str("    (");
+
+        // Source
+        contents.push_str(&format!("{}, ", chars_escape(chars)));
+
+        // Sentences
+        {
+            contents.push_str("&[");
+            let mut cluster: Vec<char> = vec![chars[0]];
+            for (i, &brk) in breaks.iter().enumerate() {
+                if brk {
+                    contents.push_str(&format!("{}, ", chars_escape(&cluster)));
+                    cluster.truncate(0);
+                }
+                cluster.push(chars[i + 1]);
+            }
+            contents.push_str(&format!("{}]", chars_escape(&cluster)));
+        }
+
+        contents.push_str("),\n");
+    }
+
+    contents.push(']');
+
+    write(dir, "sentence_break_test_data.rsv", &contents);
+}
+
+fn emit_line_break_test_data(dir: &Path) {
+    let mut contents = "&[\n".to_owned();
+
+    for case in LINE_BREAK_TESTS.entries.iter() {
+        let LineBreakTest {
+            ref chars,
+            ref breaks,
+        } = *case;
+
+        contents.push_str("    (");
+
+        // Source
+        contents.push_str(&format!("{}, ", chars_escape(chars)));
+
+        // Lines
+        {
+            contents.push_str("&[");
+            let mut line: Vec<char> = vec![chars[0]];
+            for (i, &brk) in breaks.iter().enumerate() {
+                if brk {
+                    contents.push_str(&format!("{}, ", chars_escape(&line)));
+                    line.truncate(0);
+                }
+                line.push(chars[i + 1]);
+            }
+            contents.push_str(&format!("{}]", chars_escape(&line)));
+        }
+
+        contents.push_str("),\n");
+    }
+
+    contents.push(']');
+
+    write(dir, "line_break_test_data.rsv", &contents);
+}
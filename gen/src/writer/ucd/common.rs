@@ -24,6 +24,7 @@ pub fn generate(dir: &Path) {
     emit_unicode_version(dir, &UNICODE_VERSION);
     emit_alphabetic(dir);
     emit_white_space(dir);
+    emit_default_ignorable_code_point(dir);
     emit_alphanumeric(dir);
     emit_control(dir);
     emit_numeric(dir);
@@ -45,6 +46,16 @@ fn emit_white_space(dir: &Path) {
     );
 }
 
+fn emit_default_ignorable_code_point(dir: &Path) {
+    write(
+        dir,
+        "default_ignorable_code_point.rsv",
+        &DERIVED_CORE_PROPERTIES
+            .default_ignorable_code_point
+            .to_range_char_set(),
+    );
+}
+
 fn emit_alphanumeric(dir: &Path) {
     write(
         dir,
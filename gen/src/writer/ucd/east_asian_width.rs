@@ -0,0 +1,53 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::source::ucd::east_asian_width::EAST_ASIAN_WIDTH_DATA;
+use crate::source::ucd::readme::UNICODE_VERSION;
+
+use crate::writer::common::emit_unicode_version;
+use crate::writer::utils::tables::ToRangeCharTable;
+use crate::writer::utils::write;
+
+/// Blocks whose unassigned code points default to `W`, instead of `N`.
+const DEFAULT_WIDE_RANGES: &[(u32, u32)] = &[
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xF900, 0xFAFF),
+    (0x2_0000, 0x2_FFFD),
+    (0x3_0000, 0x3_FFFD),
+];
+
+pub fn generate(dir: &Path) {
+    emit_unicode_version(dir, &UNICODE_VERSION);
+    emit_east_asian_width(dir);
+}
+
+fn emit_east_asian_width(dir: &Path) {
+    let mut map: BTreeMap<char, &str> = DEFAULT_WIDE_RANGES
+        .iter()
+        .flat_map(|&(low, high)| (low..=high).filter_map(char::from_u32))
+        .map(|ch| (ch, "W"))
+        .collect();
+    for (&ch, width) in &EAST_ASIAN_WIDTH_DATA.map {
+        map.insert(ch, width.as_str());
+    }
+    // The Neutral default is left out of the table.
+    map.retain(|_, width| *width != "N");
+
+    write(
+        dir,
+        "east_asian_width.rsv",
+        &map.to_range_char_table(|v, f| write!(f, "EA::{}", v)),
+    );
+}
@@ -10,10 +10,24 @@
 
 use std::path::Path;
 
+use crate::source::ucd::hangul_syllable_type::HANGUL_SYLLABLE_TYPE_DATA;
 use crate::source::ucd::readme::UNICODE_VERSION;
 
 use crate::writer::common::emit_unicode_version;
+use crate::writer::utils::tables::ToRangeCharTable;
+use crate::writer::utils::write;
 
 pub fn generate(dir: &Path) {
     emit_unicode_version(dir, &UNICODE_VERSION);
+    emit_hangul_syllable_type(dir);
+}
+
+fn emit_hangul_syllable_type(dir: &Path) {
+    write(
+        dir,
+        "hangul_syllable_type.rsv",
+        &HANGUL_SYLLABLE_TYPE_DATA
+            .map
+            .to_range_char_table(|v, f| write!(f, "HST::{}", v)),
+    );
 }
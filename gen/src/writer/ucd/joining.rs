@@ -0,0 +1,46 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::source::ucd::arabic_shaping::ARABIC_SHAPING_DATA;
+use crate::source::ucd::readme::UNICODE_VERSION;
+use crate::source::ucd::unicode_data::UNICODE_DATA;
+
+use crate::writer::common::emit_unicode_version;
+use crate::writer::utils::tables::ToRangeCharTable;
+use crate::writer::utils::write;
+
+pub fn generate(dir: &Path) {
+    emit_unicode_version(dir, &UNICODE_VERSION);
+    emit_joining_type(dir);
+}
+
+fn emit_joining_type(dir: &Path) {
+    // Characters not listed in ArabicShaping.txt with General_Category Mn, Me or Cf are
+    // Transparent. The Non_Joining default is left out of the table.
+    let mut map: BTreeMap<char, &str> = UNICODE_DATA
+        .entries
+        .iter()
+        .filter(|x| ["Mn", "Me", "Cf"].contains(&x.general_category.as_str()))
+        .map(|x| (x.character, "T"))
+        .collect();
+    for (&ch, joining_type) in &ARABIC_SHAPING_DATA.joining_type {
+        map.insert(ch, joining_type.as_str());
+    }
+    map.retain(|_, joining_type| *joining_type != "U");
+
+    write(
+        dir,
+        "joining_type.rsv",
+        &map.to_range_char_table(|v, f| write!(f, "JT::{}", v)),
+    );
+}
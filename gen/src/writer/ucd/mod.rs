@@ -15,11 +15,15 @@ mod case;
 mod category;
 mod common;
 mod core;
+mod east_asian_width;
 mod hangul;
 mod ident;
+mod joining;
 mod name;
 mod name_aliases;
 mod normal;
+mod numeric;
+mod script;
 mod segment;
 mod segment_tests;
 
@@ -33,11 +37,15 @@ pub fn generate() {
     category::generate(&clean_dir("unic/ucd/category/tables"));
     common::generate(&clean_dir("unic/ucd/common/tables"));
     core::generate(&clean_dir("unic/ucd/version/tables"));
+    east_asian_width::generate(&clean_dir("unic/ucd/east_asian_width/tables"));
     hangul::generate(&clean_dir("unic/ucd/hangul/tables"));
     ident::generate(&clean_dir("unic/ucd/ident/tables"));
+    joining::generate(&clean_dir("unic/ucd/joining/tables"));
     name::generate(&clean_dir("unic/ucd/name/tables"));
     name_aliases::generate(&clean_dir("unic/ucd/name_aliases/tables"));
     normal::generate(&clean_dir("unic/ucd/normal/tables"));
+    numeric::generate(&clean_dir("unic/ucd/numeric/tables"));
+    script::generate(&clean_dir("unic/ucd/script/tables"));
     segment::generate(&clean_dir("unic/ucd/segment/tables"));
     segment_tests::generate(&clean_dir("unic/ucd/segment/tests/tables"));
 }
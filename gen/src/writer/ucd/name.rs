@@ -14,6 +14,8 @@ use std::fmt::Write;
 use std::path::Path;
 
 use crate::source::ucd::jamo::JAMO_DATA;
+use crate::source::ucd::name_aliases::NAME_ALIASES_DATA;
+use crate::source::ucd::named_sequences::{NAMED_SEQUENCES_DATA, PROVISIONAL_NAMED_SEQUENCES_DATA};
 use crate::source::ucd::readme::UNICODE_VERSION;
 use crate::source::ucd::unicode_data::UNICODE_DATA;
 
@@ -24,9 +26,37 @@ use crate::writer::utils::write;
 pub fn generate(dir: &Path) {
     emit_unicode_version(dir, &UNICODE_VERSION);
     emit_name_tables(dir);
+    emit_name_index_tables(dir);
+    emit_named_sequence_tables(dir);
     emit_jamo_tables(dir);
 }
 
+/// The one name that differs from another only by a medial hyphen, and so is matched exactly.
+///
+/// See [UAX44-LM2](https://www.unicode.org/reports/tr44/#UAX44-LM2).
+const HANGUL_JUNGSEONG_O_E: char = '\u{1180}';
+
+/// Loose matching key of a name or alias, following UAX44-LM2: ignore case, whitespace,
+/// underscores and medial hyphens.
+///
+/// Must be kept in sync with the matching in `unic-ucd-name`.
+fn loose_key(name: &str) -> String {
+    let bytes = name.as_bytes();
+    bytes
+        .iter()
+        .enumerate()
+        .filter(|&(i, &byte)| {
+            let is_medial_hyphen = byte == b'-'
+                && i > 0
+                && i + 1 < bytes.len()
+                && bytes[i - 1].is_ascii_alphanumeric()
+                && bytes[i + 1].is_ascii_alphanumeric();
+            !(byte.is_ascii_whitespace() || byte == b'_' || is_medial_hyphen)
+        })
+        .map(|(_, &byte)| char::from(byte.to_ascii_uppercase()))
+        .collect()
+}
+
 #[derive(Clone, Debug)]
 struct NameRecord<'a> {
     pieces: Vec<&'a str>,
@@ -75,6 +105,126 @@ fn emit_name_tables(dir: &Path) {
     );
 }
 
+fn emit_name_index_tables(dir: &Path) {
+    // Explicitly listed names, sorted by loose matching key, for binary search
+    let mut names: BTreeMap<String, char> = BTreeMap::default();
+    for entry in UNICODE_DATA
+        .entries
+        .iter()
+        .filter(|x| !x.name.starts_with('<') && x.character != HANGUL_JUNGSEONG_O_E)
+    {
+        let duplicate = names.insert(loose_key(&entry.name), entry.character);
+        assert!(
+            duplicate.is_none(),
+            "Names are not unique under loose matching: {}",
+            entry.name
+        );
+    }
+
+    let mut names_contents = String::from("&[\n");
+    for ch in names.values() {
+        writeln!(names_contents, "    '{}',", ch.escape_unicode()).unwrap();
+    }
+    names_contents.push(']');
+    write(dir, "name_index.rsv", &names_contents);
+
+    // Formal name aliases, of all types, sorted by loose matching key
+    let mut aliases: BTreeMap<String, (&str, char)> = BTreeMap::default();
+    for map in &[
+        &NAME_ALIASES_DATA.corrections,
+        &NAME_ALIASES_DATA.controls,
+        &NAME_ALIASES_DATA.alternates,
+        &NAME_ALIASES_DATA.figments,
+        &NAME_ALIASES_DATA.abbreviations,
+    ] {
+        for (&ch, values) in map.iter() {
+            for alias in values {
+                let key = loose_key(alias);
+                assert!(
+                    !names.contains_key(&key),
+                    "Name alias conflicts with a name: {}",
+                    alias
+                );
+                if let Some((_, other)) = aliases.insert(key, (alias.as_str(), ch)) {
+                    assert_eq!(other, ch, "Name alias is not unique: {}", alias);
+                }
+            }
+        }
+    }
+
+    let mut aliases_contents = String::from("&[\n");
+    for &(alias, ch) in aliases.values() {
+        writeln!(
+            aliases_contents,
+            "    (\"{}\", '{}'),",
+            alias,
+            ch.escape_unicode()
+        )
+        .unwrap();
+    }
+    aliases_contents.push(']');
+    write(dir, "name_alias_index.rsv", &aliases_contents);
+}
+
+fn escape(sequence: &str) -> String {
+    sequence
+        .chars()
+        .map(|ch| ch.escape_unicode().to_string())
+        .collect()
+}
+
+fn emit_named_sequence_tables(dir: &Path) {
+    let named_sequences = NAMED_SEQUENCES_DATA
+        .map
+        .iter()
+        .chain(PROVISIONAL_NAMED_SEQUENCES_DATA.map.iter());
+
+    // Sorted by loose matching key of the names, for binary search
+    let mut by_name: BTreeMap<String, (&str, &str)> = BTreeMap::default();
+    // Sorted by sequence, for binary search
+    let mut by_sequence: BTreeMap<&str, &str> = BTreeMap::default();
+    for (name, sequence) in named_sequences {
+        let duplicate = by_name.insert(loose_key(name), (name, sequence));
+        assert!(
+            duplicate.is_none(),
+            "Named sequence is not unique under loose matching: {}",
+            name
+        );
+        let duplicate = by_sequence.insert(sequence, name);
+        assert!(
+            duplicate.is_none(),
+            "Sequence has more than one name: {}",
+            name
+        );
+    }
+
+    let mut by_name_contents = String::from("&[\n");
+    for &(name, sequence) in by_name.values() {
+        writeln!(
+            by_name_contents,
+            "    (\"{}\", \"{}\"),",
+            name,
+            escape(sequence)
+        )
+        .unwrap();
+    }
+    by_name_contents.push(']');
+    write(dir, "named_sequences.rsv", &by_name_contents);
+
+    let mut by_sequence_contents = String::from("&[\n");
+    for (sequence, name) in by_sequence {
+        writeln!(
+            by_sequence_contents,
+            "    (\"{}\", \"{}\"),",
+            escape(sequence),
+            name
+        )
+        .unwrap();
+    }
+    by_sequence_contents.push(']');
+    write(dir, "sequence_names.rsv", &by_sequence_contents);
+}
+
 fn emit_jamo_tables(dir: &Path) {
     write(
         dir,
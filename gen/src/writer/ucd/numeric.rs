@@ -0,0 +1,58 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::source::ucd::readme::UNICODE_VERSION;
+use crate::source::ucd::unicode_data::UNICODE_DATA;
+
+use crate::writer::common::emit_unicode_version;
+use crate::writer::utils::tables::ToRangeCharTable;
+use crate::writer::utils::write;
+
+pub fn generate(dir: &Path) {
+    emit_unicode_version(dir, &UNICODE_VERSION);
+    emit_numeric_type(dir);
+    emit_numeric_values(dir);
+}
+
+fn emit_numeric_type(dir: &Path) {
+    let map: BTreeMap<char, &str> = UNICODE_DATA
+        .entries
+        .iter()
+        .filter_map(|x| {
+            x.numeric_type()
+                .map(|numeric_type| (x.character, numeric_type))
+        })
+        .collect();
+
+    write(
+        dir,
+        "numeric_type.rsv",
+        &map.to_range_char_table(|v, f| write!(f, "NT::{}", v)),
+    );
+}
+
+fn emit_numeric_values(dir: &Path) {
+    let map: BTreeMap<char, (i64, u32)> = UNICODE_DATA
+        .entries
+        .iter()
+        .filter_map(|x| x.numeric_numeric_value.map(|value| (x.character, value)))
+        .collect();
+
+    write(
+        dir,
+        "numeric_values.rsv",
+        &map.to_range_char_table(|&(numerator, denominator), f| {
+            write!(f, "({}, {})", numerator, denominator)
+        }),
+    );
+}
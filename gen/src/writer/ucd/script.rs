@@ -0,0 +1,45 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::path::Path;
+
+use crate::source::ucd::readme::UNICODE_VERSION;
+use crate::source::ucd::script_extensions::SCRIPT_EXTENSIONS_DATA;
+use crate::source::ucd::scripts::SCRIPTS_DATA;
+
+use crate::writer::common::emit_unicode_version;
+use crate::writer::utils::tables::ToRangeCharTable;
+use crate::writer::utils::write;
+
+pub fn generate(dir: &Path) {
+    emit_unicode_version(dir, &UNICODE_VERSION);
+    emit_script(dir);
+    emit_script_extensions(dir);
+}
+
+fn emit_script(dir: &Path) {
+    write(
+        dir,
+        "script.rsv",
+        &SCRIPTS_DATA
+            .map
+            .to_range_char_table(|v, f| write!(f, "{}", v)),
+    );
+}
+
+fn emit_script_extensions(dir: &Path) {
+    write(
+        dir,
+        "script_extensions.rsv",
+        &SCRIPT_EXTENSIONS_DATA
+            .map
+            .to_range_char_table(|v, f| write!(f, "&[{}]", v.join(", "))),
+    );
+}
@@ -12,6 +12,7 @@ use std::collections::BTreeMap;
 use std::path::Path;
 
 use crate::source::ucd::test::grapheme_break_test::{GraphemeBreakTest, GRAPHEME_BREAK_TESTS};
+use crate::source::ucd::test::sentence_break_test::{SentenceBreakTest, SENTENCE_BREAK_TESTS};
 use crate::source::ucd::test::word_break_test::{WordBreakTest, WORD_BREAK_TESTS};
 
 use crate::writer::utils::tables::ToDirectCharTable;
@@ -20,6 +21,7 @@ use crate::writer::utils::write;
 pub fn generate(dir: &Path) {
     emit_grapheme_cluster_break_test_data(dir);
     emit_word_break_test_data(dir);
+    emit_sentence_break_test_data(dir);
 }
 
 fn emit_grapheme_cluster_break_test_data(dir: &Path) {
@@ -76,23 +78,22 @@ fn emit_word_break_test_data(dir: &Path) {
     );
 }
 
-/* TODO
 fn emit_sentence_break_test_data(dir: &Path) {
     let mut map = BTreeMap::default();
 
     for case in SENTENCE_BREAK_TESTS.entries.iter() {
         let SentenceBreakTest {
             ref chars,
-            ref char_gcbs,
+            ref char_sbs,
             ..
         } = *case;
 
         for (i, ch) in chars.iter().enumerate() {
-            let ref gcb = char_gcbs[i];
+            let sb = &char_sbs[i];
             if map.contains_key(ch) {
-                assert_eq!(map[ch], *gcb);
+                assert_eq!(map[ch], *sb);
             } else {
-                map.insert(*ch, gcb.clone());
+                map.insert(*ch, sb.clone());
             }
         }
     }
@@ -103,4 +104,3 @@ fn emit_sentence_break_test_data(dir: &Path) {
         &map.to_direct_char_table(|val, f| write!(f, "{}", val)),
     );
 }
-*/
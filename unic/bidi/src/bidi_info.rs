@@ -16,7 +16,7 @@ use std::iter::repeat;
 use std::ops::Range;
 
 use unic_ucd_bidi::bidi_class::abbr_names::*;
-use unic_ucd_bidi::BidiClass;
+use unic_ucd_bidi::{bidi_mirroring_glyph, BidiClass};
 
 use crate::explicit;
 use crate::format_chars;
@@ -204,7 +204,13 @@ impl<'text> BidiInfo<'text> {
             let sequences = prepare::isolating_run_sequences(para.level, original_classes, levels);
             for sequence in &sequences {
                 implicit::resolve_weak(sequence, processing_classes);
-                implicit::resolve_neutral(sequence, levels, processing_classes);
+                implicit::resolve_neutral(
+                    text,
+                    sequence,
+                    levels,
+                    original_classes,
+                    processing_classes,
+                );
             }
             implicit::resolve_levels(processing_classes, levels);
 
@@ -255,6 +261,39 @@ impl<'text> BidiInfo<'text> {
 
     /// Re-order a line based on resolved levels and return the line in display order.
     pub fn reorder_line(&self, para: &ParagraphInfo, line: Range<usize>) -> Cow<'text, str> {
+        self.reorder_line_impl(para, line, false)
+    }
+
+    /// Re-order a line based on resolved levels and return the line in display order, with the
+    /// mirrored characters of right-to-left runs replaced by their `Bidi_Mirroring_Glyph`.
+    ///
+    /// This is meant for displays that cannot mirror glyphs themselves, like plain-text terminals.
+    ///
+    /// <https://www.unicode.org/reports/tr9/#L4>
+    ///
+    /// ```rust
+    /// # use unic_bidi::BidiInfo;
+    /// let bidi_info = BidiInfo::new("\u{05D0}(\u{05D1})", None);
+    /// let para = &bidi_info.paragraphs[0];
+    /// assert_eq!(
+    ///     bidi_info.reorder_line_mirrored(para, para.range.clone()),
+    ///     "(\u{05D1})\u{05D0}"
+    /// );
+    /// ```
+    pub fn reorder_line_mirrored(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> Cow<'text, str> {
+        self.reorder_line_impl(para, line, true)
+    }
+
+    fn reorder_line_impl(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+        mirror: bool,
+    ) -> Cow<'text, str> {
         let (levels, runs) = self.visual_runs(para, line.clone());
 
         // If all isolating run sequences are LTR, no reordering is needed
@@ -265,7 +304,13 @@ impl<'text> BidiInfo<'text> {
         let mut result = String::with_capacity(line.len());
         for run in runs {
             if levels[run.start].is_rtl() {
-                result.extend(self.text[run].chars().rev());
+                let chars = self.text[run].chars().rev();
+                if mirror {
+                    // <https://www.unicode.org/reports/tr9/#L4>
+                    result.extend(chars.map(|ch| bidi_mirroring_glyph(ch).unwrap_or(ch)));
+                } else {
+                    result.extend(chars);
+                }
             } else {
                 result.push_str(&self.text[run]);
             }
@@ -273,6 +318,109 @@ impl<'text> BidiInfo<'text> {
         result.into()
     }
 
+    /// Re-order a line based on resolved levels and return a map from visual to logical
+    /// *byte* indices.
+    ///
+    /// The `n`th element of the map is the index of the byte displayed at the `n`th visual
+    /// position, with both indices relative to the start of the line.  The bytes of each
+    /// character are kept in logical order.
+    pub fn visual_to_logical(&self, para: &ParagraphInfo, line: Range<usize>) -> Vec<usize> {
+        let char_indices: Vec<(usize, char)> = self.text[line.clone()].char_indices().collect();
+        let mut map = Vec::with_capacity(line.len());
+        for char_idx in self.visual_to_logical_per_char(para, line) {
+            let (i, c) = char_indices[char_idx];
+            map.extend(i..i + c.len_utf8());
+        }
+        map
+    }
+
+    /// Re-order a line based on resolved levels and return a map from visual to logical
+    /// *character* indices.
+    ///
+    /// The `n`th element of the map is the index of the character displayed at the `n`th visual
+    /// position, with both indices counted in characters from the start of the line.
+    pub fn visual_to_logical_per_char(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> Vec<usize> {
+        let (levels, _) = self.visual_runs(para, line.clone());
+        let line_levels: Vec<Level> = self.text[line.clone()]
+            .char_indices()
+            .map(|(i, _)| levels[line.start + i])
+            .collect();
+        Self::reorder_visual(&line_levels)
+    }
+
+    /// Re-order a line based on resolved levels and return a map from logical to visual
+    /// *byte* indices.
+    ///
+    /// This is the inverse of [`visual_to_logical()`](#method.visual_to_logical).
+    pub fn logical_to_visual(&self, para: &ParagraphInfo, line: Range<usize>) -> Vec<usize> {
+        invert_index_map(&self.visual_to_logical(para, line))
+    }
+
+    /// Re-order a line based on resolved levels and return a map from logical to visual
+    /// *character* indices.
+    ///
+    /// This is the inverse of [`visual_to_logical_per_char()`](#method.visual_to_logical_per_char).
+    pub fn logical_to_visual_per_char(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> Vec<usize> {
+        invert_index_map(&self.visual_to_logical_per_char(para, line))
+    }
+
+    /// Re-order a sequence of items based on their levels, and return a map from visual to
+    /// logical indices.
+    ///
+    /// The levels are expected to be resolved for a single line, with rule L1 applied.
+    ///
+    /// <https://www.unicode.org/reports/tr9/#L2>
+    ///
+    /// ```rust
+    /// # use unic_bidi::{BidiInfo, Level};
+    /// let levels = Level::vec(&[0, 0, 1, 1, 2, 2, 1, 0]);
+    /// assert_eq!(BidiInfo::reorder_visual(&levels), vec![0, 1, 6, 4, 5, 3, 2, 7]);
+    /// ```
+    pub fn reorder_visual(levels: &[Level]) -> Vec<usize> {
+        let mut map: Vec<usize> = (0..levels.len()).collect();
+
+        let (min_level, mut max_level) = match (levels.iter().min(), levels.iter().max()) {
+            (Some(&min_level), Some(&max_level)) => (min_level, max_level),
+            _ => return map,
+        };
+
+        // Stop at the lowest *odd* level.
+        let min_level = min_level.new_lowest_ge_rtl().expect("Level error");
+
+        while max_level >= min_level {
+            // Reverse each sequence of consecutive items of max_level or higher.
+            let mut seq_start = 0;
+            while seq_start < levels.len() {
+                if levels[map[seq_start]] < max_level {
+                    seq_start += 1;
+                    continue;
+                }
+
+                let mut seq_end = seq_start + 1;
+                while seq_end < levels.len() && levels[map[seq_end]] >= max_level {
+                    seq_end += 1;
+                }
+
+                map[seq_start..seq_end].reverse();
+
+                seq_start = seq_end;
+            }
+            max_level
+                .lower(1)
+                .expect("Lowering embedding level below zero");
+        }
+
+        map
+    }
+
     /// Find the level runs within a line and return them in visual order.
     ///
     /// `line` is a range of bytes indices within `levels`.
@@ -292,9 +440,10 @@ impl<'text> BidiInfo<'text> {
         // Reset some whitespace chars to paragraph level.
         // <https://www.unicode.org/reports/tr9/#L1>
         let line_str: &str = &self.text[line.clone()];
-        let mut reset_from: Option<usize> = Some(0);
+        let mut reset_from: Option<usize> = Some(line.start);
         let mut reset_to: Option<usize> = None;
         for (i, c) in line_str.char_indices() {
+            let i = line.start + i;
             match self.original_classes[i] {
                 // Ignored by X9
                 RLE | LRE | RLO | LRO | PDF | BN => {}
@@ -302,13 +451,13 @@ impl<'text> BidiInfo<'text> {
                 B | S => {
                     assert_eq!(reset_to, None);
                     reset_to = Some(i + c.len_utf8());
-                    if reset_from == None {
+                    if reset_from.is_none() {
                         reset_from = Some(i);
                     }
                 }
                 // Whitespace, isolate formatting
                 WS | FSI | LRI | RLI | PDI => {
-                    if reset_from == None {
+                    if reset_from.is_none() {
                         reset_from = Some(i);
                     }
                 }
@@ -325,7 +474,7 @@ impl<'text> BidiInfo<'text> {
             }
         }
         if let Some(from) = reset_from {
-            for j in from..line_str.len() {
+            for j in from..line.end {
                 levels[j] = para.level;
             }
         }
@@ -399,6 +548,15 @@ impl<'text> BidiInfo<'text> {
     }
 }
 
+/// Invert a permutation of indices.
+fn invert_index_map(map: &[usize]) -> Vec<usize> {
+    let mut inverse = vec![0; map.len()];
+    for (i, &j) in map.iter().enumerate() {
+        inverse[j] = i;
+    }
+    inverse
+}
+
 impl<'text> fmt::Display for BidiInfo<'text> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -683,7 +841,33 @@ mod tests {
         assert_eq!(reorder_paras("א(ב)ג."), vec![".ג)ב(א"]);
 
         // With mirrorable characters on level boundry
-        assert_eq!(reorder_paras("אב(גד[&ef].)gh"), vec!["ef].)gh&[דג(בא"]);
+        assert_eq!(reorder_paras("אב(גד[&ef].)gh"), vec!["gh).]ef&[דג(בא"]);
+    }
+
+    fn reorder_paras_mirrored(text: &str) -> Vec<Cow<'_, str>> {
+        let bidi_info = BidiInfo::new(text, None);
+        bidi_info
+            .paragraphs
+            .iter()
+            .map(|para| bidi_info.reorder_line_mirrored(para, para.range.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_reorder_line_mirrored() {
+        // No RTL runs
+        assert_eq!(reorder_paras_mirrored("a(b)c"), vec!["a(b)c"]);
+
+        // With mirrorable characters in RTL run
+        assert_eq!(reorder_paras_mirrored("א(ב)ג."), vec![".ג(ב)א"]);
+        assert_eq!(reorder_paras_mirrored("א<ב«ג"), vec!["ג»ב>א"]);
+        assert_eq!(
+            reorder_paras_mirrored("אב(גד[&ef].)gh"),
+            vec!["gh(.[ef&]דג)בא"]
+        );
+
+        // Mirrored characters resolved to LTR are not mirrored
+        assert_eq!(reorder_paras_mirrored("א a(b)c"), vec!["a(b)c א"]);
     }
 
     fn reordered_levels_for_paras(text: &str) -> Vec<Vec<Level>> {
@@ -717,6 +901,30 @@ mod tests {
             vec![Level::vec(&[0, 0])]
         );
 
+        // Bracket pairs resolved to the direction of their context (N0.c.1)
+        let text = "a (b) \u{05D2}";
+        let bidi_info = BidiInfo::new(text, Some(RTL_LEVEL));
+        let para = &bidi_info.paragraphs[0];
+        assert_eq!(
+            bidi_info.reordered_levels_per_char(para, para.range.clone()),
+            Level::vec(&[2, 2, 2, 2, 2, 1, 1])
+        );
+
+        // Bracket pairs resolved to the embedding direction (N0.b)
+        assert_eq!(
+            reordered_levels_per_char_for_paras("\u{05D0} (\u{05D1}) c"),
+            vec![Level::vec(&[1, 1, 1, 1, 1, 1, 2])]
+        );
+
+        // Canonically equivalent brackets are paired
+        let text = "a\u{2329}b\u{3009}";
+        let bidi_info = BidiInfo::new(text, Some(RTL_LEVEL));
+        let para = &bidi_info.paragraphs[0];
+        assert_eq!(
+            bidi_info.reordered_levels_per_char(para, para.range.clone()),
+            Level::vec(&[2, 2, 2, 2])
+        );
+
         /* TODO
         // BidiTest:69635 (AL ET EN)
         let text = "\u{060B}\u{20CF}\u{06F9}";
@@ -739,6 +947,60 @@ mod tests {
          */
     }
 
+    #[test]
+    fn test_reorder_visual() {
+        assert_eq!(BidiInfo::reorder_visual(&[]), Vec::<usize>::new());
+        assert_eq!(
+            BidiInfo::reorder_visual(&Level::vec(&[0, 0, 0])),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            BidiInfo::reorder_visual(&Level::vec(&[1, 1, 1])),
+            vec![2, 1, 0]
+        );
+        assert_eq!(
+            BidiInfo::reorder_visual(&Level::vec(&[1, 2, 2, 1, 3, 1])),
+            vec![5, 4, 3, 1, 2, 0]
+        );
+    }
+
+    #[test]
+    fn test_visual_to_logical() {
+        let text = "abc \u{05D0}\u{05D1}\u{05D2}";
+        let bidi_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[0];
+        let line = para.range.clone();
+        assert_eq!(
+            bidi_info.visual_to_logical_per_char(para, line.clone()),
+            vec![0, 1, 2, 3, 6, 5, 4]
+        );
+        assert_eq!(
+            bidi_info.logical_to_visual_per_char(para, line.clone()),
+            vec![0, 1, 2, 3, 6, 5, 4]
+        );
+        assert_eq!(
+            bidi_info.visual_to_logical(para, line.clone()),
+            vec![0, 1, 2, 3, 8, 9, 6, 7, 4, 5]
+        );
+        assert_eq!(
+            bidi_info.logical_to_visual(para, line),
+            vec![0, 1, 2, 3, 8, 9, 6, 7, 4, 5]
+        );
+
+        // Indices are relative to the start of the line
+        let text = "ab\n\u{05D0}\u{05D1} c";
+        let bidi_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[1];
+        assert_eq!(
+            bidi_info.visual_to_logical_per_char(para, para.range.clone()),
+            vec![3, 2, 1, 0]
+        );
+        assert_eq!(
+            bidi_info.visual_to_logical(para, para.range.clone()),
+            vec![5, 4, 2, 3, 0, 1]
+        );
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(
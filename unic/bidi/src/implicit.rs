@@ -14,7 +14,7 @@
 use std::cmp::max;
 
 use unic_ucd_bidi::bidi_class::abbr_names::*;
-use unic_ucd_bidi::BidiClass;
+use unic_ucd_bidi::{bidi_paired_bracket, BidiClass, BidiPairedBracketType};
 
 use super::level::Level;
 use super::prepare::{not_removed_by_x9, removed_by_x9, IsolatingRunSequence, LevelRun};
@@ -139,18 +139,21 @@ pub fn resolve_weak(sequence: &IsolatingRunSequence, processing_classes: &mut [B
 ///
 /// <https://www.unicode.org/reports/tr9/#Resolving_Neutral_Types>
 pub fn resolve_neutral(
+    text: &str,
     sequence: &IsolatingRunSequence,
     levels: &[Level],
+    original_classes: &[BidiClass],
     processing_classes: &mut [BidiClass],
 ) {
     let e: BidiClass = levels[sequence.runs[0].start].bidi_class();
+
+    // N0. Process bracket pairs.
+    resolve_bracket_pairs(text, sequence, e, original_classes, processing_classes);
+
     let mut indices = sequence.runs.iter().flat_map(Clone::clone);
     let mut prev_class = sequence.sos;
 
     while let Some(mut i) = indices.next() {
-        // N0. Process bracket pairs.
-        // TODO
-
         // Process sequences of NI characters.
         let mut ni_run = Vec::new();
         if is_NI(processing_classes[i]) {
@@ -204,6 +207,163 @@ pub fn resolve_neutral(
     }
 }
 
+/// Maximum depth of the bracket stack used by BD16.
+///
+/// <https://www.unicode.org/reports/tr9/#BD16>
+const MAX_BRACKET_PAIRING_DEPTH: usize = 63;
+
+/// A bracket pair, as the byte indices of the opening and closing paired brackets.
+type BracketPair = (usize, usize);
+
+/// BD16. Identify the bracket pairs of an isolating run sequence.
+///
+/// Only characters whose current bidi class is `ON` are considered.  The returned pairs are sorted
+/// by the position of their opening paired bracket.
+///
+/// <https://www.unicode.org/reports/tr9/#BD16>
+fn identify_bracket_pairs(
+    text: &str,
+    indices: &[usize],
+    processing_classes: &[BidiClass],
+) -> Vec<BracketPair> {
+    let mut pairs = Vec::new();
+    // The canonical closing bracket expected for each opening bracket, and its position.
+    let mut stack: Vec<(char, usize)> = Vec::with_capacity(MAX_BRACKET_PAIRING_DEPTH);
+
+    for &i in indices {
+        if processing_classes[i] != ON {
+            continue;
+        }
+        let ch = char_at(text, i);
+        match BidiPairedBracketType::of(ch) {
+            BidiPairedBracketType::Open => {
+                if stack.len() == MAX_BRACKET_PAIRING_DEPTH {
+                    break;
+                }
+                let closing = bidi_paired_bracket(ch).expect("Paired bracket missing");
+                stack.push((canonical_bracket(closing), i));
+            }
+            BidiPairedBracketType::Close => {
+                let closing = canonical_bracket(ch);
+                if let Some(depth) = stack.iter().rposition(|&(c, _)| c == closing) {
+                    pairs.push((stack[depth].1, i));
+                    stack.truncate(depth);
+                }
+            }
+            BidiPairedBracketType::None => {}
+        }
+    }
+
+    pairs.sort();
+    pairs
+}
+
+/// Map the paired brackets with a canonical decomposition to their canonical equivalents, so
+/// that, for example, U+2329 LEFT-POINTING ANGLE BRACKET pairs with U+3009 RIGHT ANGLE BRACKET.
+fn canonical_bracket(ch: char) -> char {
+    match ch {
+        '\u{2329}' => '\u{3008}',
+        '\u{232A}' => '\u{3009}',
+        _ => ch,
+    }
+}
+
+/// The strong direction of a resolved class, for the purpose of rule N0, where `EN` and `AN` are
+/// treated as `R`.
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        L => Some(L),
+        R | AL | EN | AN => Some(R),
+        _ => None,
+    }
+}
+
+/// N0. Resolve paired brackets to the embedding direction or to the direction of their context.
+///
+/// <https://www.unicode.org/reports/tr9/#N0>
+fn resolve_bracket_pairs(
+    text: &str,
+    sequence: &IsolatingRunSequence,
+    e: BidiClass,
+    original_classes: &[BidiClass],
+    processing_classes: &mut [BidiClass],
+) {
+    // Byte indices of the characters in the sequence, ignoring the ones removed by X9.
+    let indices: Vec<usize> = sequence
+        .runs
+        .iter()
+        .flat_map(Clone::clone)
+        .filter(|&i| text.is_char_boundary(i) && not_removed_by_x9(&processing_classes[i]))
+        .collect();
+
+    for (open, close) in identify_bracket_pairs(text, &indices, processing_classes) {
+        let open_idx = indices.binary_search(&open).unwrap();
+        let close_idx = indices.binary_search(&close).unwrap();
+
+        // N0.b-c. Find strong types within the bracket pair.
+        let mut found_opposite = false;
+        let mut found_e = false;
+        for &i in &indices[open_idx + 1..close_idx] {
+            match strong_direction(processing_classes[i]) {
+                Some(class) if class == e => {
+                    found_e = true;
+                    break;
+                }
+                Some(_) => found_opposite = true,
+                None => {}
+            }
+        }
+
+        let new_class = if found_e {
+            // N0.b
+            e
+        } else if found_opposite {
+            // N0.c. Use the first strong type preceding the opening bracket.
+            let context = indices[..open_idx]
+                .iter()
+                .rev()
+                .filter_map(|&i| strong_direction(processing_classes[i]))
+                .next()
+                .unwrap_or(sequence.sos);
+            if context != e {
+                // N0.c.1
+                context
+            } else {
+                // N0.c.2
+                e
+            }
+        } else {
+            // N0.d. Leave the brackets for N1-N2.
+            continue;
+        };
+
+        for &(bracket, bracket_idx) in &[(open, open_idx), (close, close_idx)] {
+            set_char_class(text, bracket, new_class, processing_classes);
+
+            // Characters originally of type NSM following a paired bracket take its new type.
+            for &i in indices[bracket_idx + 1..]
+                .iter()
+                .take_while(|&&i| original_classes[i] == NSM)
+            {
+                set_char_class(text, i, new_class, processing_classes);
+            }
+        }
+    }
+}
+
+/// Get the character starting at byte index `i` of the text.
+fn char_at(text: &str, i: usize) -> char {
+    text[i..].chars().next().unwrap()
+}
+
+/// Set the class of all the bytes of the character starting at byte index `i` of the text.
+fn set_char_class(text: &str, i: usize, class: BidiClass, processing_classes: &mut [BidiClass]) {
+    let len = char_at(text, i).len_utf8();
+    for class_ in &mut processing_classes[i..i + len] {
+        *class_ = class;
+    }
+}
+
 /// 3.3.6 Resolving Implicit Levels
 ///
 /// Returns the maximum embedding level in the paragraph.
@@ -11,7 +11,7 @@
 
 #![cfg(test)]
 
-use unic_bidi::{format_chars, level, BidiClass, BidiInfo, Level};
+use unic_bidi::{format_chars, level, BidiClass, BidiInfo, Level, ParagraphInfo};
 
 const BASIC_TEST_DATA: &str = include_str!("../../../external/unicode/ucd/data/BidiTest.txt");
 const CHAR_TEST_DATA: &str =
@@ -28,7 +28,7 @@ struct Fail {
     pub exp_ordering: Vec<String>,
     pub actual_base_level: Option<Level>,
     pub actual_levels: Vec<Level>,
-    // TODO pub actual_ordering: Vec<String>,
+    pub actual_ordering: Vec<String>,
 }
 
 #[test]
@@ -84,11 +84,12 @@ fn test_basic_conformance() {
             for input_base_level in gen_base_levels_for_base_tests(bitset) {
                 let bidi_info = BidiInfo::new(&input_string, input_base_level);
 
-                // Check levels
+                // Check levels and reorder map
                 let exp_levels: Vec<String> = exp_levels.iter().map(|x| x.to_owned()).collect();
                 let para = &bidi_info.paragraphs[0];
                 let levels = bidi_info.reordered_levels_per_char(para, para.range.clone());
-                if levels != exp_levels {
+                let ordering = get_visual_ordering(&bidi_info, para);
+                if levels != exp_levels || ordering != exp_ordering {
                     fails.push(Fail {
                         line_num: line_idx + 1,
                         input_base_level,
@@ -99,13 +100,11 @@ fn test_basic_conformance() {
                         exp_ordering: exp_ordering.to_owned(),
                         actual_base_level: None,
                         actual_levels: levels.to_owned(),
+                        actual_ordering: ordering.to_owned(),
                     });
                 } else {
                     passed_num += 1;
                 }
-
-                // Check reorder map
-                // TODO: Add reorder map to API output and test the map here
             }
         }
     }
@@ -143,7 +142,9 @@ fn gen_base_levels_for_base_tests(bitset: u8) -> Vec<Option<Level>> {
 }
 
 #[test]
-#[should_panic(expected = "14558 test cases failed! (77141 passed)")]
+// The 11 remaining failures are European numbers next to separators after Arabic letters, or
+// inside directional overrides, where rules W1-W7 are applied in a single pass (#8).
+#[should_panic(expected = "11 test cases failed!")]
 fn test_character_conformance() {
     // Test set state
     let mut passed_num: i32 = 0;
@@ -177,10 +178,11 @@ fn test_character_conformance() {
 
             let bidi_info = BidiInfo::new(&input_string, input_base_level);
 
-            // Check levels
+            // Check levels and reorder map
             let para = &bidi_info.paragraphs[0];
             let levels = bidi_info.reordered_levels_per_char(para, para.range.clone());
-            if levels != exp_levels {
+            let ordering = get_visual_ordering(&bidi_info, para);
+            if levels != exp_levels || ordering != exp_ordering {
                 fails.push(Fail {
                     line_num: line_idx + 1,
                     input_base_level,
@@ -191,13 +193,11 @@ fn test_character_conformance() {
                     exp_ordering: exp_ordering.to_owned(),
                     actual_base_level: None,
                     actual_levels: levels.to_owned(),
+                    actual_ordering: ordering.to_owned(),
                 });
             } else {
                 passed_num += 1;
             }
-
-            // Check reorder map
-            // TODO: Add reorder map to API output and test the map here
         }
     }
 
@@ -230,6 +230,32 @@ fn gen_base_level_for_characters_tests(idx: usize) -> Option<Level> {
     VALUES[idx]
 }
 
+/// Visual ordering of the characters of the paragraph, as logical character indices, skipping the
+/// characters removed by rule X9.
+fn get_visual_ordering(bidi_info: &BidiInfo<'_>, para: &ParagraphInfo) -> Vec<String> {
+    let text = &bidi_info.text[para.range.clone()];
+    let classes: Vec<BidiClass> = text
+        .char_indices()
+        .map(|(i, _)| bidi_info.original_classes[para.range.start + i])
+        .collect();
+    bidi_info
+        .visual_to_logical_per_char(para, para.range.clone())
+        .into_iter()
+        .filter(|&i| {
+            !matches!(
+                classes[i],
+                BidiClass::LeftToRightEmbedding
+                    | BidiClass::RightToLeftEmbedding
+                    | BidiClass::LeftToRightOverride
+                    | BidiClass::RightToLeftOverride
+                    | BidiClass::PopDirectionalFormat
+                    | BidiClass::BoundaryNeutral
+            )
+        })
+        .map(|i| i.to_string())
+        .collect()
+}
+
 fn get_sample_string_from_bidi_classes(class_names: &[&str]) -> String {
     class_names
         .iter()
@@ -0,0 +1,198 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Caseless matching of strings.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/versions/Unicode10.0.0/ch03.pdf#G53253>
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use unic_normal::StrNormalForm;
+
+use crate::folding::to_casefold;
+
+fn casefold<I>(chars: I) -> impl Iterator<Item = char>
+where
+    I: Iterator<Item = char>,
+{
+    chars.flat_map(to_casefold)
+}
+
+/// `NFD(toCasefold(NFD(X)))`
+fn canonical_caseless_key(s: &str) -> impl Iterator<Item = char> + '_ {
+    casefold(s.nfd()).nfd()
+}
+
+/// `NFKD(toCasefold(NFKD(toCasefold(NFD(X)))))`
+fn compatibility_caseless_key(s: &str) -> impl Iterator<Item = char> + '_ {
+    casefold(casefold(s.nfd()).nfkd()).nfkd()
+}
+
+/// Whether the two strings are equal under *default caseless matching* (D144), that is, after
+/// full case folding.
+///
+/// ```rust
+/// # use unic_case::default_caseless_match;
+/// assert!(default_caseless_match("Straße", "STRASSE"));
+/// assert!(!default_caseless_match("e\u{301}", "\u{C9}"));
+/// ```
+pub fn default_caseless_match(a: &str, b: &str) -> bool {
+    casefold(a.chars()).eq(casefold(b.chars()))
+}
+
+/// Whether the two strings are equal under *canonical caseless matching* (D145), that is, after
+/// full case folding and canonical decomposition.
+///
+/// ```rust
+/// # use unic_case::canonical_caseless_match;
+/// assert!(canonical_caseless_match("e\u{301}", "\u{C9}"));
+/// assert!(!canonical_caseless_match("\u{2160}", "i"));
+/// ```
+pub fn canonical_caseless_match(a: &str, b: &str) -> bool {
+    canonical_caseless_key(a).eq(canonical_caseless_key(b))
+}
+
+/// Whether the two strings are equal under *compatibility caseless matching* (D146), that is,
+/// after full case folding and compatibility decomposition.
+///
+/// ```rust
+/// # use unic_case::compatibility_caseless_match;
+/// assert!(compatibility_caseless_match("\u{2460}", "1"));
+/// assert!(compatibility_caseless_match("\u{2160}", "i"));
+/// ```
+pub fn compatibility_caseless_match(a: &str, b: &str) -> bool {
+    compatibility_caseless_key(a).eq(compatibility_caseless_key(b))
+}
+
+/// A string slice that compares and hashes under *canonical caseless matching* (D145).
+///
+/// This allows using strings as keys of a `HashMap` or `HashSet` without case folding them first.
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use unic_case::CaselessStr;
+/// let mut users = HashMap::new();
+/// users.insert(CaselessStr::new("Jürgen"), 1);
+///
+/// assert_eq!(users.get(&CaselessStr::new("JU\u{308}RGEN")), Some(&1));
+/// assert_eq!(users.get(&CaselessStr::new("Jurgen")), None);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CaselessStr<'a> {
+    string: &'a str,
+}
+
+impl<'a> CaselessStr<'a> {
+    /// Wrap the string slice.
+    #[inline]
+    pub fn new(s: &str) -> CaselessStr<'_> {
+        CaselessStr { string: s }
+    }
+
+    /// The underlying string slice, as given.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.string
+    }
+}
+
+impl<'a> From<&'a str> for CaselessStr<'a> {
+    fn from(s: &'a str) -> Self {
+        CaselessStr::new(s)
+    }
+}
+
+impl<'a, 'b> PartialEq<CaselessStr<'b>> for CaselessStr<'a> {
+    fn eq(&self, other: &CaselessStr<'b>) -> bool {
+        canonical_caseless_match(self.string, other.string)
+    }
+}
+
+impl<'a> Eq for CaselessStr<'a> {}
+
+impl<'a> Hash for CaselessStr<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for ch in canonical_caseless_key(self.string) {
+            ch.hash(state);
+        }
+        // Same terminator as for `str`, so that sequences of keys hash differently.
+        state.write_u8(0xff);
+    }
+}
+
+impl<'a> fmt::Display for CaselessStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.string.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::{
+        canonical_caseless_match, compatibility_caseless_match, default_caseless_match, CaselessStr,
+    };
+
+    fn hash(s: CaselessStr<'_>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_default_caseless_match() {
+        assert!(default_caseless_match("", ""));
+        assert!(default_caseless_match("ΣΊΣΥΦΟΣ", "σίσυφος"));
+        assert!(default_caseless_match("ﬃ", "FFI"));
+        assert!(!default_caseless_match("a", "b"));
+        assert!(!default_caseless_match("a", "aa"));
+    }
+
+    #[test]
+    fn test_canonical_caseless_match() {
+        // U+0345 COMBINING GREEK YPOGEGRAMMENI folds to U+03B9 GREEK SMALL LETTER IOTA
+        assert!(canonical_caseless_match("\u{1FB3}", "\u{3B1}\u{345}"));
+        assert!(canonical_caseless_match("\u{1FB3}", "\u{391}\u{399}"));
+        assert!(canonical_caseless_match("\u{212B}", "\u{E5}"));
+        assert!(!canonical_caseless_match("\u{2460}", "1"));
+    }
+
+    #[test]
+    fn test_compatibility_caseless_match() {
+        assert!(compatibility_caseless_match("\u{2460}", "1"));
+        assert!(compatibility_caseless_match("\u{33C7}", "co."));
+        assert!(compatibility_caseless_match("\u{1D400}", "a"));
+        assert!(!compatibility_caseless_match("a", "\u{E0}"));
+    }
+
+    #[test]
+    fn test_caseless_str() {
+        assert_eq!(CaselessStr::new("Hello"), CaselessStr::new("hELLO"));
+        assert_eq!(CaselessStr::new("\u{C5}"), CaselessStr::new("a\u{30A}"));
+        assert_ne!(CaselessStr::new("a"), CaselessStr::new("b"));
+
+        assert_eq!(
+            hash(CaselessStr::new("Hello")),
+            hash(CaselessStr::new("hELLO"))
+        );
+        assert_eq!(
+            hash(CaselessStr::new("Stra\u{DF}e")),
+            hash(CaselessStr::new("STRASSE"))
+        );
+
+        assert_eq!(CaselessStr::new("Hello").as_str(), "Hello");
+        assert_eq!(format!("{}", CaselessStr::from("Hello")), "Hello");
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Case folding of characters and strings.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr44/#Case_Folding>
+//! * <https://www.unicode.org/versions/Unicode10.0.0/ch03.pdf#G34078>
+
+use crate::mapping::CaseMapping;
+
+mod data {
+    use unic_char_property::tables::CharDataTable;
+
+    pub const SIMPLE_CASE_FOLDING: CharDataTable<&[char]> =
+        include!("../tables/simple_case_folding.rsv");
+
+    pub const FULL_CASE_FOLDING: CharDataTable<&[char]> =
+        include!("../tables/full_case_folding.rsv");
+}
+
+/// Map the character to its full case folding, as per `CaseFolding.txt` statuses `C` and `F`.
+///
+/// ```rust
+/// # use unic_case::to_casefold;
+/// assert_eq!(to_casefold('A').collect::<String>(), "a");
+/// assert_eq!(to_casefold('ß').collect::<String>(), "ss");
+/// ```
+pub fn to_casefold(ch: char) -> CaseMapping {
+    CaseMapping::new(&data::FULL_CASE_FOLDING, ch)
+}
+
+/// Map the character to its simple case folding, as per `CaseFolding.txt` statuses `C` and `S`.
+///
+/// ```rust
+/// # use unic_case::to_simple_casefold;
+/// assert_eq!(to_simple_casefold('A'), 'a');
+/// assert_eq!(to_simple_casefold('ẞ'), 'ß');
+/// ```
+pub fn to_simple_casefold(ch: char) -> char {
+    data::SIMPLE_CASE_FOLDING
+        .find(ch)
+        .map_or(ch, |mapping| mapping[0])
+}
+
+/// Apply full case folding to the string, as per Default Case Conversion rule `toCasefold(X)`.
+///
+/// ```rust
+/// # use unic_case::str_to_casefold;
+/// assert_eq!(str_to_casefold("Straße"), "strasse");
+/// assert_eq!(str_to_casefold("ὈΔΥΣΣΕΎΣ"), "ὀδυσσεύσ");
+/// ```
+pub fn str_to_casefold(s: &str) -> String {
+    s.chars().flat_map(to_casefold).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{str_to_casefold, to_casefold, to_simple_casefold};
+
+    #[test]
+    fn test_to_casefold() {
+        assert_eq!(to_casefold('a').collect::<String>(), "a");
+        assert_eq!(to_casefold('\u{130}').collect::<String>(), "i\u{307}");
+        assert_eq!(to_casefold('\u{3C2}').collect::<String>(), "\u{3C3}");
+        assert_eq!(to_casefold('\u{FB03}').collect::<String>(), "ffi");
+        assert_eq!(to_casefold('\u{13F8}').collect::<String>(), "\u{13F0}");
+        assert_eq!(to_casefold('\u{AB70}').collect::<String>(), "\u{13A0}");
+    }
+
+    #[test]
+    fn test_to_simple_casefold() {
+        assert_eq!(to_simple_casefold('a'), 'a');
+        assert_eq!(to_simple_casefold('\u{130}'), '\u{130}');
+        assert_eq!(to_simple_casefold('\u{1F88}'), '\u{1F80}');
+        assert_eq!(to_simple_casefold('\u{10400}'), '\u{10428}');
+    }
+
+    #[test]
+    fn test_str_to_casefold() {
+        assert_eq!(str_to_casefold(""), "");
+        assert_eq!(str_to_casefold("ǅ ﬀ"), "ǆ ff");
+    }
+}
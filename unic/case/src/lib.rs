@@ -0,0 +1,67 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![warn(
+    bad_style,
+    missing_debug_implementations,
+    missing_docs,
+    unconditional_recursion
+)]
+#![forbid(unsafe_code)]
+
+//! # UNIC — Unicode Case Mapping and Folding Algorithms
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! This UNIC component implements the Default Case Conversion algorithms from [The Unicode
+//! Standard, Section 3.13 - Default Case
+//! Algorithms](https://www.unicode.org/versions/Unicode10.0.0/ch03.pdf#G33992), using the full
+//! case mappings from `UnicodeData.txt` and `SpecialCasing.txt`.
+//!
+//! It also implements case folding from `CaseFolding.txt`, and the default, canonical and
+//! compatibility caseless matching definitions built on it.
+//!
+//! Language-sensitive mappings, such as the ones for Lithuanian and Turkish, are not applied.
+//!
+//! ```rust
+//! use unic_case::{canonical_caseless_match, str_to_casefold};
+//! use unic_case::{str_to_lowercase, str_to_titlecase, str_to_uppercase, to_uppercase};
+//!
+//! assert_eq!(to_uppercase('ß').collect::<String>(), "SS");
+//!
+//! assert_eq!(str_to_uppercase("Straße"), "STRASSE");
+//! assert_eq!(str_to_lowercase("ὈΔΥΣΣΕΎΣ"), "ὀδυσσεύς");
+//! assert_eq!(str_to_titlecase("the ﬁrst STEP"), "The First Step");
+//!
+//! assert_eq!(str_to_casefold("Straße"), "strasse");
+//! assert!(canonical_caseless_match("Å", "a\u{30A}"));
+//! ```
+
+mod mapping;
+pub use crate::mapping::{to_lowercase, to_titlecase, to_uppercase, CaseMapping};
+
+mod string;
+pub use crate::string::{str_to_lowercase, str_to_titlecase, str_to_uppercase};
+
+mod folding;
+pub use crate::folding::{str_to_casefold, to_casefold, to_simple_casefold};
+
+mod caseless;
+pub use crate::caseless::{
+    canonical_caseless_match, compatibility_caseless_match, default_caseless_match, CaselessStr,
+};
+
+use unic_ucd_version::UnicodeVersion;
+
+mod pkg_info;
+pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
+
+/// The [Unicode version](https://www.unicode.org/versions/) of data
+pub const UNICODE_VERSION: UnicodeVersion = include!("../tables/unicode_version.rsv");
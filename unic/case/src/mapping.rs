@@ -0,0 +1,187 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Full case mappings of characters.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr44/#Casemapping>
+//! * <https://www.unicode.org/versions/Unicode10.0.0/ch03.pdf#G33992>
+
+use std::fmt;
+use std::slice;
+
+use unic_char_property::tables::CharDataTable;
+
+mod data {
+    use unic_char_property::tables::CharDataTable;
+
+    pub const LOWERCASE_MAPPING: CharDataTable<&[char]> =
+        include!("../tables/lowercase_mapping.rsv");
+
+    pub const UPPERCASE_MAPPING: CharDataTable<&[char]> =
+        include!("../tables/uppercase_mapping.rsv");
+
+    pub const TITLECASE_MAPPING: CharDataTable<&[char]> =
+        include!("../tables/titlecase_mapping.rsv");
+}
+
+/// An iterator over the characters resulting from the case mapping of a character.
+///
+/// A full case mapping can result in up to three characters.
+#[derive(Clone, Debug)]
+pub struct CaseMapping {
+    inner: CaseMappingInner,
+}
+
+#[derive(Clone, Debug)]
+enum CaseMappingInner {
+    Char(Option<char>),
+    Chars(slice::Iter<'static, char>),
+}
+
+impl CaseMapping {
+    pub(crate) fn new(table: &CharDataTable<&'static [char]>, ch: char) -> CaseMapping {
+        let inner = match table.find(ch) {
+            Some(mapping) => CaseMappingInner::Chars(mapping.iter()),
+            None => CaseMappingInner::Char(Some(ch)),
+        };
+        CaseMapping { inner }
+    }
+}
+
+impl Iterator for CaseMapping {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        match self.inner {
+            CaseMappingInner::Char(ref mut ch) => ch.take(),
+            CaseMappingInner::Chars(ref mut chars) => chars.next().cloned(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self.inner {
+            CaseMappingInner::Char(ch) => ch.iter().len(),
+            CaseMappingInner::Chars(ref chars) => chars.len(),
+        };
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for CaseMapping {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        match self.inner {
+            CaseMappingInner::Char(ref mut ch) => ch.take(),
+            CaseMappingInner::Chars(ref mut chars) => chars.next_back().cloned(),
+        }
+    }
+}
+
+impl ExactSizeIterator for CaseMapping {}
+
+impl fmt::Display for CaseMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ch in self.clone() {
+            write!(f, "{}", ch)?;
+        }
+        Ok(())
+    }
+}
+
+/// Map the character to its full `Lowercase_Mapping`.
+///
+/// This does not apply the context-sensitive mapping of *GREEK CAPITAL LETTER SIGMA* at the end of
+/// words; see [`str_to_lowercase()`](fn.str_to_lowercase.html) for that.
+///
+/// ```rust
+/// # use unic_case::to_lowercase;
+/// assert_eq!(to_lowercase('A').collect::<String>(), "a");
+/// assert_eq!(to_lowercase('\u{130}').collect::<String>(), "i\u{307}");
+/// ```
+pub fn to_lowercase(ch: char) -> CaseMapping {
+    CaseMapping::new(&data::LOWERCASE_MAPPING, ch)
+}
+
+/// Map the character to its full `Uppercase_Mapping`.
+///
+/// ```rust
+/// # use unic_case::to_uppercase;
+/// assert_eq!(to_uppercase('a').collect::<String>(), "A");
+/// assert_eq!(to_uppercase('ß').collect::<String>(), "SS");
+/// ```
+pub fn to_uppercase(ch: char) -> CaseMapping {
+    CaseMapping::new(&data::UPPERCASE_MAPPING, ch)
+}
+
+/// Map the character to its full `Titlecase_Mapping`.
+///
+/// ```rust
+/// # use unic_case::to_titlecase;
+/// assert_eq!(to_titlecase('ß').collect::<String>(), "Ss");
+/// assert_eq!(to_titlecase('\u{1C6}').collect::<String>(), "\u{1C5}");
+/// ```
+pub fn to_titlecase(ch: char) -> CaseMapping {
+    CaseMapping::new(&data::TITLECASE_MAPPING, ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_lowercase, to_titlecase, to_uppercase};
+
+    #[test]
+    fn test_identity() {
+        assert_eq!(to_lowercase('a').collect::<String>(), "a");
+        assert_eq!(to_uppercase('A').collect::<String>(), "A");
+        assert_eq!(to_titlecase('1').collect::<String>(), "1");
+        assert_eq!(to_uppercase('\u{10FFFF}').collect::<String>(), "\u{10FFFF}");
+    }
+
+    #[test]
+    fn test_simple_mappings() {
+        assert_eq!(to_uppercase('\u{3C3}').collect::<String>(), "\u{3A3}");
+        assert_eq!(to_lowercase('\u{3A3}').collect::<String>(), "\u{3C3}");
+        assert_eq!(to_titlecase('\u{1C4}').collect::<String>(), "\u{1C5}");
+        assert_eq!(to_uppercase('\u{1C5}').collect::<String>(), "\u{1C4}");
+        assert_eq!(to_lowercase('\u{1C5}').collect::<String>(), "\u{1C6}");
+        assert_eq!(to_uppercase('\u{10428}').collect::<String>(), "\u{10400}");
+    }
+
+    #[test]
+    fn test_special_casing() {
+        assert_eq!(to_uppercase('\u{FB00}').collect::<String>(), "FF");
+        assert_eq!(to_titlecase('\u{FB00}').collect::<String>(), "Ff");
+        assert_eq!(to_lowercase('\u{FB00}').collect::<String>(), "\u{FB00}");
+        assert_eq!(
+            to_uppercase('\u{390}').collect::<String>(),
+            "\u{399}\u{308}\u{301}"
+        );
+        assert_eq!(
+            to_uppercase('\u{1F80}').collect::<String>(),
+            "\u{1F08}\u{399}"
+        );
+        assert_eq!(to_titlecase('\u{1F80}').collect::<String>(), "\u{1F88}");
+    }
+
+    #[test]
+    fn test_exact_size() {
+        assert_eq!(to_uppercase('\u{390}').len(), 3);
+        assert_eq!(to_uppercase('a').len(), 1);
+        assert_eq!(to_uppercase('\u{390}').rev().next(), Some('\u{301}'));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", to_uppercase('ß')), "SS");
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Default Case Conversion of strings.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/versions/Unicode10.0.0/ch03.pdf#G34078>
+
+use unic_segment::WordBoundIndices;
+use unic_ucd_case::{is_case_ignorable, is_cased};
+
+use crate::mapping::{to_lowercase, to_titlecase, to_uppercase};
+
+const GREEK_CAPITAL_LETTER_SIGMA: char = '\u{3A3}';
+const GREEK_SMALL_LETTER_FINAL_SIGMA: char = '\u{3C2}';
+
+/// Convert the string to lowercase, as per Default Case Conversion rule `toLowercase(X)`.
+///
+/// *GREEK CAPITAL LETTER SIGMA* is mapped to *GREEK SMALL LETTER FINAL SIGMA* when in the
+/// `Final_Sigma` context.
+///
+/// ```rust
+/// # use unic_case::str_to_lowercase;
+/// assert_eq!(str_to_lowercase("HELLO, World!"), "hello, world!");
+/// assert_eq!(str_to_lowercase("ὈΔΥΣΣΕΎΣ"), "ὀδυσσεύς");
+/// ```
+pub fn str_to_lowercase(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    push_lowercase(&mut result, s, 0..s.len());
+    result
+}
+
+/// Convert the string to uppercase, as per Default Case Conversion rule `toUppercase(X)`.
+///
+/// ```rust
+/// # use unic_case::str_to_uppercase;
+/// assert_eq!(str_to_uppercase("Hello, world!"), "HELLO, WORLD!");
+/// assert_eq!(str_to_uppercase("Straße"), "STRASSE");
+/// ```
+pub fn str_to_uppercase(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    result.extend(s.chars().flat_map(to_uppercase));
+    result
+}
+
+/// Convert the string to titlecase, as per Default Case Conversion rule `toTitlecase(X)`.
+///
+/// For each [word](https://www.unicode.org/reports/tr29/#Word_Boundaries), the first cased
+/// character is mapped to titlecase, and the characters following it are mapped to lowercase.
+///
+/// ```rust
+/// # use unic_case::str_to_titlecase;
+/// assert_eq!(str_to_titlecase("hello, WORLD!"), "Hello, World!");
+/// assert_eq!(str_to_titlecase("ǆemal's ﬁx"), "ǅemal's Fix");
+/// ```
+pub fn str_to_titlecase(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for (start, word) in WordBoundIndices::new(s) {
+        let end = start + word.len();
+        match word.char_indices().find(|&(_, ch)| is_cased(ch)) {
+            Some((idx, ch)) => {
+                let first = start + idx;
+                let rest = first + ch.len_utf8();
+                result.push_str(&s[start..first]);
+                result.extend(to_titlecase(ch));
+                push_lowercase(&mut result, s, rest..end);
+            }
+            None => result.push_str(word),
+        }
+    }
+    result
+}
+
+/// Push the lowercase of the `range` of `s` to `result`, taking the rest of `s` as context.
+fn push_lowercase(result: &mut String, s: &str, range: std::ops::Range<usize>) {
+    let start = range.start;
+    for (idx, ch) in s[range].char_indices() {
+        if ch == GREEK_CAPITAL_LETTER_SIGMA && is_final_sigma(s, start + idx) {
+            result.push(GREEK_SMALL_LETTER_FINAL_SIGMA);
+        } else {
+            result.extend(to_lowercase(ch));
+        }
+    }
+}
+
+/// Whether the character at byte offset `idx` of `s` is in the `Final_Sigma` casing context.
+///
+/// The character must be preceded by a cased character and zero or more case-ignorable
+/// characters, and not be followed by zero or more case-ignorable characters and a cased
+/// character.
+fn is_final_sigma(s: &str, idx: usize) -> bool {
+    let (before, after) = s.split_at(idx);
+    let after = &after[GREEK_CAPITAL_LETTER_SIGMA.len_utf8()..];
+
+    is_preceded_by_cased(before.chars().rev()) && !is_preceded_by_cased(after.chars())
+}
+
+/// Whether the sequence starts with zero or more case-ignorable characters followed by a cased
+/// character.
+fn is_preceded_by_cased<I>(chars: I) -> bool
+where
+    I: Iterator<Item = char>,
+{
+    for ch in chars {
+        if is_cased(ch) {
+            return true;
+        }
+        if !is_case_ignorable(ch) {
+            return false;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{str_to_lowercase, str_to_titlecase, str_to_uppercase};
+
+    #[test]
+    fn test_final_sigma() {
+        assert_eq!(str_to_lowercase("Σ"), "σ");
+        assert_eq!(str_to_lowercase("ΑΣ"), "ας");
+        assert_eq!(str_to_lowercase("ΑΣ Β"), "ας β");
+        assert_eq!(str_to_lowercase("ΑΣΒ"), "ασβ");
+        assert_eq!(str_to_lowercase("ΑΣ'Β"), "ασ'β");
+        assert_eq!(str_to_lowercase("Α'Σ."), "α'ς.");
+        assert_eq!(str_to_lowercase(" Σ "), " σ ");
+    }
+
+    #[test]
+    fn test_special_casing() {
+        assert_eq!(str_to_lowercase("İ"), "i\u{307}");
+        assert_eq!(str_to_uppercase("ŉ"), "ʼN");
+        assert_eq!(str_to_uppercase("ﬃ"), "FFI");
+    }
+
+    #[test]
+    fn test_titlecase() {
+        assert_eq!(str_to_titlecase(""), "");
+        assert_eq!(str_to_titlecase("  "), "  ");
+        assert_eq!(str_to_titlecase("'twas the NIGHT"), "'Twas The Night");
+        assert_eq!(str_to_titlecase("ΟΔΟΣ ΚΑΙ"), "Οδος Και");
+        assert_eq!(str_to_titlecase("ǳ ǲ Ǳ"), "ǲ ǲ ǲ");
+    }
+}
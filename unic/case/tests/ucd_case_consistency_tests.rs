@@ -0,0 +1,59 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_case::{str_to_casefold, to_lowercase, to_titlecase, to_uppercase, CaseMapping};
+use unic_char_range::CharRange;
+use unic_normal::StrNormalForm;
+use unic_ucd_case::{
+    changes_when_casefolded, changes_when_lowercased, changes_when_titlecased,
+    changes_when_uppercased,
+};
+
+fn changes(ch: char, mapping: CaseMapping) -> bool {
+    mapping.collect::<String>() != ch.to_string()
+}
+
+#[test]
+fn test_changes_when_mapped() {
+    for ch in CharRange::all() {
+        assert_eq!(
+            changes(ch, to_lowercase(ch)),
+            changes_when_lowercased(ch),
+            "{:?}",
+            ch
+        );
+        assert_eq!(
+            changes(ch, to_uppercase(ch)),
+            changes_when_uppercased(ch),
+            "{:?}",
+            ch
+        );
+        assert_eq!(
+            changes(ch, to_titlecase(ch)),
+            changes_when_titlecased(ch),
+            "{:?}",
+            ch
+        );
+    }
+}
+
+/// `Changes_When_Casefolded` is defined on the canonical decomposition of the character.
+#[test]
+fn test_changes_when_casefolded() {
+    for ch in CharRange::all() {
+        let nfd = ch.to_string().nfd().collect::<String>();
+        assert_eq!(
+            str_to_casefold(&nfd) != nfd,
+            changes_when_casefolded(ch),
+            "{:?}",
+            ch
+        );
+    }
+}
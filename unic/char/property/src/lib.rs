@@ -37,8 +37,11 @@ extern crate unic_char_range;
 mod pkg_info;
 pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
 
+mod loose_matching;
+pub use crate::loose_matching::loose_eq;
+
 mod property;
-pub use self::property::{CharProperty, PartialCharProperty, TotalCharProperty};
+pub use self::property::{CharProperty, PartialCharProperty, TableCharProperty, TotalCharProperty};
 
 mod range_types;
 pub use crate::range_types::{
@@ -0,0 +1,43 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Loose matching of property names and property value names.
+
+/// Whether two property names, or two property value names, are equal under
+/// [UAX44-LM3](https://www.unicode.org/reports/tr44/#UAX44-LM3).
+///
+/// Case, whitespace, underscores and hyphens are ignored, and so is any initial prefix `is`.
+///
+/// ```
+/// use unic_char_property::loose_eq;
+///
+/// assert!(loose_eq("Uppercase_Letter", "uppercase letter"));
+/// assert!(loose_eq("Lu", "isLu"));
+/// assert!(loose_eq("Line_Break", "LINE-BREAK"));
+/// assert!(!loose_eq("Lu", "Ll"));
+/// ```
+pub fn loose_eq(lhs: &str, rhs: &str) -> bool {
+    loose_key(lhs).eq(loose_key(rhs))
+}
+
+fn loose_key(name: &str) -> impl Iterator<Item = char> + Clone + '_ {
+    let key = name
+        .chars()
+        .filter(|&ch| !(ch.is_whitespace() || ch == '_' || ch == '-'))
+        .map(|ch| ch.to_ascii_lowercase());
+
+    // Keep the prefix when nothing follows it, so that `is` does not match the empty name.
+    let mut rest = key.clone();
+    if rest.next() == Some('i') && rest.next() == Some('s') && rest.clone().next().is_some() {
+        rest
+    } else {
+        key
+    }
+}
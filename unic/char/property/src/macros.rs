@@ -30,6 +30,14 @@
 ///             long => Long_Name,
 ///             human => "&'static str that is a nicer presentation of the name",
 ///         }
+///
+///         /// Values can also list other aliases, besides their abbr and long names.
+///         OtherRustName {
+///             abbr => OtherAbbrName,
+///             long => Other_Long_Name,
+///             other_aliases => ["other", "aliases"],
+///             human => "Other Human-Readable Name",
+///         }
 ///     }
 ///
 ///     /// Module aliasing property value abbreviated names.
@@ -75,7 +83,9 @@
 /// # Effect
 ///
 /// - Implements the `CharProperty` trait and appropriate range trait
-/// - Implements `FromStr` accepting either the abbr or long name, ascii case insensitive
+/// - Implements the `TableCharProperty` trait (Binary properties only)
+/// - Implements `FromStr` accepting the abbr name, long name or any other alias, under UAX44-LM3
+///   loose matching
 /// - Implements `Display` using the `human` string
 /// - Populates the module `abbr_names` with `pub use` bindings of variants to their abbr names
 ///   (Enumerated properties only)
@@ -94,6 +104,7 @@ macro_rules! char_property {
         pub enum $prop_name:ident {
             abbr => $prop_abbr:expr;
             long => $prop_long:expr;
+            $( other_aliases => [ $( $prop_other:expr ),* ]; )?
             human => $prop_human:expr;
 
             $(
@@ -101,6 +112,7 @@ macro_rules! char_property {
                 $variant_name:ident {
                     abbr => $variant_abbr:ident,
                     long => $variant_long:ident,
+                    $( other_aliases => [ $( $variant_other:expr ),* ], )?
                     human => $variant_human:expr,
                 }
             )*
@@ -133,17 +145,14 @@ macro_rules! char_property {
         }
 
         char_property! {
-            __impl FromStr for $prop_name;
-            $(
-                stringify!($variant_abbr) => $prop_name::$variant_name;
-                stringify!($variant_long) => $prop_name::$variant_name;
-            )*
+            __impl FromStr for $prop_name by EnumeratedCharProperty
         }
 
         char_property! {
             __impl CharProperty for $prop_name;
             $prop_abbr;
             $prop_long;
+            [ $( $( $prop_other, )* )? ];
             $prop_human;
         }
 
@@ -173,6 +182,11 @@ macro_rules! char_property {
                     $( $prop_name::$variant_name => $variant_human, )*
                 }
             }
+            fn other_aliases(&self) -> &'static [&'static str] {
+                match *self {
+                    $( $prop_name::$variant_name => &[ $( $( $variant_other, )* )? ], )*
+                }
+            }
         }
     };
 
@@ -183,6 +197,7 @@ macro_rules! char_property {
         pub struct $prop_name:ident(bool) {
             abbr => $prop_abbr:expr;
             long => $prop_long:expr;
+            $( other_aliases => [ $( $prop_other:expr ),* ]; )?
             human => $prop_human:expr;
 
             data_table_path => $data_path:expr;
@@ -204,9 +219,7 @@ macro_rules! char_property {
         impl $prop_name {
             /// Get (struct) property value of the character.
             pub fn of(ch: char) -> Self {
-                use $crate::tables::CharDataTable;
-                const TABLE: CharDataTable<()> = include!($data_path);
-                $prop_name(TABLE.contains(ch))
+                $prop_name(<Self as $crate::TableCharProperty>::table().contains(ch))
             }
 
             /// Get boolean property value of the character.
@@ -214,23 +227,14 @@ macro_rules! char_property {
         }
 
         char_property! {
-            __impl FromStr for $prop_name;
-            // Yes
-            "y" => $prop_name(true);
-            "yes" => $prop_name(true);
-            "t" => $prop_name(true);
-            "true" => $prop_name(true);
-            // No
-            "n" => $prop_name(false);
-            "no" => $prop_name(false);
-            "f" => $prop_name(false);
-            "false" => $prop_name(false);
+            __impl FromStr for $prop_name by BinaryCharProperty
         }
 
         char_property! {
             __impl CharProperty for $prop_name;
             $prop_abbr;
             $prop_long;
+            [ $( $( $prop_other, )* )? ];
             $prop_human;
         }
 
@@ -242,10 +246,23 @@ macro_rules! char_property {
             fn as_bool(&self) -> bool { self.as_bool() }
         }
 
+        impl $crate::TableCharProperty for $prop_name {
+            type TableValue = ();
+            fn table() -> $crate::tables::CharDataTable<()> {
+                use $crate::tables::CharDataTable;
+                const TABLE: CharDataTable<()> = include!($data_path);
+                TABLE
+            }
+        }
+
         impl From<$prop_name> for bool {
             fn from(prop: $prop_name) -> bool { prop.as_bool() }
         }
 
+        impl From<bool> for $prop_name {
+            fn from(value: bool) -> $prop_name { $prop_name(value) }
+        }
+
         char_property! {
             __impl Display for $prop_name by BinaryCharProperty
         }
@@ -257,28 +274,22 @@ macro_rules! char_property {
         __impl CharProperty for $prop_name:ident;
         $prop_abbr:expr;
         $prop_long:expr;
+        [ $( $prop_other:expr, )* ];
         $prop_human:expr;
     ) => {
         impl $crate::CharProperty for $prop_name {
             fn prop_abbr_name() -> &'static str { $prop_abbr }
             fn prop_long_name() -> &'static str { $prop_long }
             fn prop_human_name() -> &'static str { $prop_human }
+            fn prop_other_aliases() -> &'static [&'static str] { &[ $( $prop_other, )* ] }
         }
     };
 
-    (
-        __impl FromStr for $prop_name:ident;
-        $( $id:expr => $value:expr; )*
-    ) => {
-        #[allow(unreachable_patterns)]
+    ( __impl FromStr for $prop_name:ident by $trait:ident ) => {
         impl $crate::__str::FromStr for $prop_name {
             type Err = ();
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s {
-                    $( $id => Ok($value), )*
-                    $( s if s.eq_ignore_ascii_case($id) => Ok($value), )*
-                    _ => Err(()),
-                }
+                <$prop_name as $crate::$trait>::from_name(s).ok_or(())
             }
         }
     };
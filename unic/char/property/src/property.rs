@@ -13,6 +13,9 @@
 use core::fmt::Debug;
 use core::hash::Hash;
 
+use crate::loose_matching::loose_eq;
+use crate::tables::CharDataTable;
+
 /// A Character Property, defined for some or all Unicode characters.
 pub trait CharProperty: PartialCharProperty + Debug + Eq + Hash {
     /// The *abbreviated name* of the property.
@@ -23,6 +26,22 @@ pub trait CharProperty: PartialCharProperty + Debug + Eq + Hash {
 
     /// The *human-readable* name of the property.
     fn prop_human_name() -> &'static str;
+
+    /// Other aliases of the property, listed in *PropertyAliases.txt* besides the abbreviated
+    /// and long names.
+    fn prop_other_aliases() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Whether the given name is a name of the property: its abbreviated name, long name or
+    /// any other alias, under UAX44-LM3 loose matching.
+    fn is_prop_name(name: &str) -> bool {
+        loose_eq(Self::prop_abbr_name(), name)
+            || loose_eq(Self::prop_long_name(), name)
+            || Self::prop_other_aliases()
+                .iter()
+                .any(|alias| loose_eq(alias, name))
+    }
 }
 
 /// A Character Property defined for some characters.
@@ -46,3 +65,16 @@ impl<T: TotalCharProperty> PartialCharProperty for T {
         Some(<Self as TotalCharProperty>::of(ch))
     }
 }
+
+/// A Character Property with its values stored in a `CharDataTable`, which allows listing the
+/// characters having each value.
+///
+/// Characters missing from the table have no value, or the default value of a total property.
+pub trait TableCharProperty: CharProperty {
+    /// The type of values stored in the table: the property itself, or `()` for a binary property
+    /// with a table of the characters having the property.
+    type TableValue: Copy + 'static;
+
+    /// The data table of the property.
+    fn table() -> CharDataTable<Self::TableValue>;
+}
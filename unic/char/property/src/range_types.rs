@@ -14,6 +14,7 @@
 //! types and enforce their implementation from `CharProperty`.  We need to fix this whenever the
 //! compiler becomes able to do to so.
 
+use super::loose_matching::loose_eq;
 use super::property::CharProperty;
 
 // == Enumerated/Catalog Types ==
@@ -37,6 +38,31 @@ pub trait EnumeratedCharProperty: Sized + CharProperty {
 
     /// The *human-readable name* of the property value.
     fn human_name(&self) -> &'static str;
+
+    /// Other aliases of the property value, listed in *PropertyValueAliases.txt* besides the
+    /// abbreviated and long names.
+    fn other_aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Find the property value with the given name: its abbreviated name, long name or any other
+    /// alias, under UAX44-LM3 loose matching.
+    fn from_name(name: &str) -> Option<Self>
+    where
+        Self: 'static,
+    {
+        Self::all_values()
+            .iter()
+            .find(|value| {
+                loose_eq(value.abbr_name(), name)
+                    || loose_eq(value.long_name(), name)
+                    || value
+                        .other_aliases()
+                        .iter()
+                        .any(|alias| loose_eq(alias, name))
+            })
+            .cloned()
+    }
 }
 
 // == Binary Types ==
@@ -74,14 +100,40 @@ pub trait BinaryCharProperty: CharProperty {
             "No"
         }
     }
+
+    /// Find the property value with the given name, one of `Y`, `Yes`, `T` and `True`, or `N`,
+    /// `No`, `F` and `False`, under UAX44-LM3 loose matching.
+    fn from_name(name: &str) -> Option<Self>
+    where
+        Self: From<bool>,
+    {
+        let is_any = |aliases: &[&str]| aliases.iter().any(|alias| loose_eq(alias, name));
+        if is_any(&["Y", "Yes", "T", "True"]) {
+            Some(Self::from(true))
+        } else if is_any(&["N", "No", "F", "False"]) {
+            Some(Self::from(false))
+        } else {
+            None
+        }
+    }
 }
 
 // == Numeric Types ==
 
 /// Marker for numeric types accepted by `NumericCharProperty`.
+///
+/// Implemented for the primitive integer types. Property crates can implement it for their own
+/// value types, such as exact rational numbers for `Numeric_Value`.
 pub trait NumericCharPropertyValue {}
 
 impl NumericCharPropertyValue for u8 {}
+impl NumericCharPropertyValue for u16 {}
+impl NumericCharPropertyValue for u32 {}
+impl NumericCharPropertyValue for u64 {}
+impl NumericCharPropertyValue for i8 {}
+impl NumericCharPropertyValue for i16 {}
+impl NumericCharPropertyValue for i32 {}
+impl NumericCharPropertyValue for i64 {}
 
 /// A Character Property with numeric values.
 ///
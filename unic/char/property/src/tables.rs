@@ -107,6 +107,11 @@ impl<'a, V: Copy> Iterator for CharDataTableIter<'a, V> {
             }
         }
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.1 = self.1.saturating_add(n);
+        self.next()
+    }
 }
 
 impl<V> CharDataTable<V> {
@@ -58,6 +58,22 @@ fn test_from_str() {
     assert_eq!("NO".parse(), Ok(MyProp(false)));
     assert_eq!("F".parse(), Ok(MyProp(false)));
     assert_eq!("FALSE".parse(), Ok(MyProp(false)));
+
+    assert_eq!(" Is_True ".parse(), Ok(MyProp(true)));
+    assert_eq!("maybe".parse::<MyProp>(), Err(()));
+}
+
+#[test]
+fn test_from_name() {
+    use unic_char_property::{BinaryCharProperty, CharProperty};
+
+    assert_eq!(MyProp::from_name("Yes"), Some(MyProp(true)));
+    assert_eq!(MyProp::from_name("f"), Some(MyProp(false)));
+    assert_eq!(MyProp::from_name(""), None);
+
+    assert!(MyProp::is_prop_name("mp"));
+    assert!(MyProp::is_prop_name("isMyProp"));
+    assert!(!MyProp::is_prop_name("My Property"));
 }
 
 #[test]
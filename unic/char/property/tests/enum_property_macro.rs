@@ -17,6 +17,7 @@ char_property! {
     pub enum MyProp {
         abbr => "mp";
         long => "My_Prop";
+        other_aliases => ["my_property"];
         human => "My Property";
 
         /// Variants can have multi-line documentations,
@@ -37,6 +38,7 @@ char_property! {
         Variant3 {
             abbr => V3,
             long => Variant_3,
+            other_aliases => ["three", "Third_Variant"],
             human => "Variant 3",
         }
     }
@@ -85,3 +87,30 @@ fn test_fromstr_ignores_case() {
     assert_eq!("vArIaNt_1".parse(), Ok(V1));
     assert_eq!("VARIANT_1".parse(), Ok(V1));
 }
+
+#[test]
+fn test_from_name() {
+    use unic_char_property::{CharProperty, EnumeratedCharProperty};
+
+    assert_eq!(MyProp::from_name("V1"), Some(MyProp::Variant1));
+    assert_eq!(MyProp::from_name("variant 2"), Some(MyProp::Variant2));
+    assert_eq!(MyProp::from_name("Is-Variant-2"), Some(MyProp::Variant2));
+    assert_eq!(MyProp::from_name("THREE"), Some(MyProp::Variant3));
+    assert_eq!(MyProp::from_name("third_variant"), Some(MyProp::Variant3));
+    assert_eq!(MyProp::from_name("Variant_4"), None);
+    assert_eq!(MyProp::from_name(""), None);
+
+    assert_eq!(MyProp::Variant1.other_aliases(), &[] as &[&str]);
+    assert_eq!(
+        MyProp::Variant3.other_aliases(),
+        &["three", "Third_Variant"]
+    );
+
+    assert_eq!("is three".parse(), Ok(MyProp::Variant3));
+    assert_eq!("V 4".parse::<MyProp>(), Err(()));
+
+    assert!(MyProp::is_prop_name("MP"));
+    assert!(MyProp::is_prop_name("my prop"));
+    assert!(MyProp::is_prop_name("MyProperty"));
+    assert!(!MyProp::is_prop_name("Variant_1"));
+}
@@ -0,0 +1,66 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Extended_Pictographic` Character Property.
+
+char_property! {
+    /// Represents values of the Unicode character property
+    /// [`Extended_Pictographic`](https://www.unicode.org/reports/tr51/#Emoji_Properties).
+    ///
+    /// The value is `true` for pictographic symbols, and reserved code points in the ranges of
+    /// emoji, used to future-proof segmentation; `false` otherwise.
+    pub struct ExtendedPictographic(bool) {
+        abbr => "ExtPict";
+        long => "Extended_Pictographic";
+        human => "Extended Pictographic";
+
+        data_table_path => "../tables/extended_pictographic.rsv";
+    }
+
+    /// The value is `true` for pictographic symbols, and reserved code points in the ranges of
+    /// emoji, used to future-proof segmentation; `false` otherwise.
+    pub fn is_extended_pictographic(char) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_values() {
+        use super::is_extended_pictographic;
+
+        // ASCII
+        assert_eq!(is_extended_pictographic('\u{0000}'), false);
+        assert_eq!(is_extended_pictographic('\u{0023}'), false);
+        assert_eq!(is_extended_pictographic('\u{0031}'), false);
+        assert_eq!(is_extended_pictographic('\u{007a}'), false);
+
+        // Other BMP
+        assert_eq!(is_extended_pictographic('\u{00a9}'), true);
+        assert_eq!(is_extended_pictographic('\u{00aa}'), false);
+        assert_eq!(is_extended_pictographic('\u{200d}'), false);
+        assert_eq!(is_extended_pictographic('\u{2640}'), true);
+        assert_eq!(is_extended_pictographic('\u{2764}'), true);
+
+        // Other Planes
+        assert_eq!(is_extended_pictographic('\u{10000}'), false);
+        assert_eq!(is_extended_pictographic('\u{1f1e6}'), false);
+        assert_eq!(is_extended_pictographic('\u{1f3fb}'), false);
+        assert_eq!(is_extended_pictographic('\u{1f600}'), true);
+        assert_eq!(is_extended_pictographic('\u{1f6d1}'), true);
+
+        // Reserved code points
+        assert_eq!(is_extended_pictographic('\u{1fa00}'), true);
+        assert_eq!(is_extended_pictographic('\u{1fffd}'), true);
+
+        assert_eq!(is_extended_pictographic('\u{20000}'), false);
+        assert_eq!(is_extended_pictographic('\u{e0000}'), false);
+        assert_eq!(is_extended_pictographic('\u{10ffff}'), false);
+    }
+}
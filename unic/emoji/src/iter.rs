@@ -0,0 +1,251 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Splitting text into emoji and non-emoji spans.
+//!
+//! <https://www.unicode.org/reports/tr51/#EBNF_and_Regex>
+
+use crate::char::{is_emoji, is_emoji_modifier, is_emoji_presentation};
+use crate::sequence::is_regional_indicator;
+
+const ZWJ: char = '\u{200D}';
+const VARIATION_SELECTOR_15: char = '\u{FE0E}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+const COMBINING_ENCLOSING_KEYCAP: char = '\u{20E3}';
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// A span of text, from [`EmojiIter`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum EmojiSpan<'a> {
+    /// A single emoji, which may be a sequence of several characters.
+    Emoji(&'a str),
+
+    /// Text between emoji, with no emoji in it.
+    Text(&'a str),
+}
+
+impl<'a> EmojiSpan<'a> {
+    /// The text of the span.
+    pub fn as_str(&self) -> &'a str {
+        match *self {
+            EmojiSpan::Emoji(s) | EmojiSpan::Text(s) => s,
+        }
+    }
+
+    /// Whether the span is an emoji.
+    pub fn is_emoji(&self) -> bool {
+        matches!(*self, EmojiSpan::Emoji(_))
+    }
+}
+
+/// External iterator over the emoji and non-emoji spans of a string.
+///
+/// Emoji are recognized with the `possible_emoji` grammar of UTS #51, and so include sequences
+/// that are well-formed but not RGI; see [`is_rgi_emoji`](crate::is_rgi_emoji) to check them.
+/// A single character with default text presentation is only an emoji when modified, for example
+/// with VARIATION SELECTOR-16, or joined with ZERO WIDTH JOINER.
+///
+/// ```text
+/// possible_emoji     := zwj_element (\x{200D} zwj_element)*
+/// zwj_element        := \p{RI} \p{RI} | \p{Emoji} emoji_modification?
+/// emoji_modification := \p{EMod} | \x{FE0F} \x{20E3}? | tag_modifier
+/// tag_modifier       := [\x{E0020}-\x{E007E}]+ \x{E007F}
+/// ```
+///
+/// Each emoji is a span of its own, and the text between them is a single span.
+#[derive(Clone, Debug)]
+pub struct EmojiIter<'a> {
+    rest: &'a str,
+}
+
+impl<'a> EmojiIter<'a> {
+    /// Create a new iterator over the spans of a string.
+    pub fn new(s: &'a str) -> EmojiIter<'a> {
+        EmojiIter { rest: s }
+    }
+}
+
+impl<'a> Iterator for EmojiIter<'a> {
+    type Item = EmojiSpan<'a>;
+
+    fn next(&mut self) -> Option<EmojiSpan<'a>> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let mut end = 0;
+        while end < self.rest.len() {
+            match possible_emoji(&self.rest[end..]) {
+                Some((len, true)) => {
+                    if end == 0 {
+                        return Some(EmojiSpan::Emoji(self.advance(len)));
+                    }
+                    break;
+                }
+                Some((len, false)) => end += len,
+                None => end += self.rest[end..].chars().next().unwrap().len_utf8(),
+            }
+        }
+        Some(EmojiSpan::Text(self.advance(end)))
+    }
+}
+
+impl<'a> EmojiIter<'a> {
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (span, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        span
+    }
+}
+
+// Match `possible_emoji` at the start of the string, returning its length and whether it is
+// presented as an emoji.
+fn possible_emoji(s: &str) -> Option<(usize, bool)> {
+    let (mut len, mut presented) = zwj_element(s)?;
+    while s[len..].starts_with(ZWJ) {
+        let start = len + ZWJ.len_utf8();
+        match zwj_element(&s[start..]) {
+            Some((element_len, _)) => {
+                len = start + element_len;
+                presented = true;
+            }
+            None => break,
+        }
+    }
+    Some((len, presented))
+}
+
+fn zwj_element(s: &str) -> Option<(usize, bool)> {
+    let mut chars = s.chars();
+    let first = chars.next().filter(|&ch| is_emoji(ch))?;
+    let mut len = first.len_utf8();
+
+    let next = chars.next();
+    if is_regional_indicator(first) {
+        return match next {
+            Some(second) if is_regional_indicator(second) => Some((len + second.len_utf8(), true)),
+            _ => Some((len, false)),
+        };
+    }
+
+    match next {
+        Some(ch) if is_emoji_modifier(ch) => Some((len + ch.len_utf8(), true)),
+        Some(VARIATION_SELECTOR_16) => {
+            len += VARIATION_SELECTOR_16.len_utf8();
+            if chars.next() == Some(COMBINING_ENCLOSING_KEYCAP) {
+                len += COMBINING_ENCLOSING_KEYCAP.len_utf8();
+            }
+            Some((len, true))
+        }
+        Some(VARIATION_SELECTOR_15) => Some((len + VARIATION_SELECTOR_15.len_utf8(), false)),
+        Some(ch) if is_tag_spec(ch) => {
+            let tags = s[len..]
+                .find(|ch| !is_tag_spec(ch))
+                .unwrap_or(s.len() - len);
+            if s[len + tags..].starts_with(CANCEL_TAG) {
+                Some((len + tags + CANCEL_TAG.len_utf8(), true))
+            } else {
+                Some((len, is_emoji_presentation(first)))
+            }
+        }
+        _ => Some((len, is_emoji_presentation(first))),
+    }
+}
+
+fn is_tag_spec(ch: char) -> bool {
+    matches!(ch, '\u{E0020}'..='\u{E007E}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmojiIter;
+    use super::EmojiSpan::{self, *};
+
+    fn spans(s: &str) -> Vec<EmojiSpan<'_>> {
+        EmojiIter::new(s).collect()
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(spans(""), vec![]);
+        assert_eq!(spans("abc 123"), vec![Text("abc 123")]);
+
+        // Default text presentation
+        assert_eq!(spans("\u{263A} \u{00A9}"), vec![Text("\u{263A} \u{00A9}")]);
+
+        // Explicit text presentation
+        assert_eq!(spans("\u{1F600}\u{FE0E}"), vec![Text("\u{1F600}\u{FE0E}")]);
+
+        // Single regional indicator
+        assert_eq!(spans("\u{1F1FA}!"), vec![Text("\u{1F1FA}!")]);
+    }
+
+    #[test]
+    fn test_emoji() {
+        assert_eq!(
+            spans("hi \u{1F600}\u{1F600}!"),
+            vec![
+                Text("hi "),
+                Emoji("\u{1F600}"),
+                Emoji("\u{1F600}"),
+                Text("!"),
+            ]
+        );
+        assert_eq!(
+            spans("\u{263A}\u{FE0F}1\u{FE0F}\u{20E3}"),
+            vec![Emoji("\u{263A}\u{FE0F}"), Emoji("1\u{FE0F}\u{20E3}")]
+        );
+    }
+
+    #[test]
+    fn test_sequences() {
+        // Flags, pairwise
+        assert_eq!(
+            spans("\u{1F1FA}\u{1F1F8}\u{1F1E8}\u{1F1E6}\u{1F1EB}"),
+            vec![
+                Emoji("\u{1F1FA}\u{1F1F8}"),
+                Emoji("\u{1F1E8}\u{1F1E6}"),
+                Text("\u{1F1EB}"),
+            ]
+        );
+
+        // Modifier
+        assert_eq!(
+            spans("\u{1F44D}\u{1F3FD}x"),
+            vec![Emoji("\u{1F44D}\u{1F3FD}"), Text("x")]
+        );
+
+        // Tag
+        let scotland = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}";
+        assert_eq!(spans(scotland), vec![Emoji(scotland)]);
+
+        // ZWJ, with a text presentation character
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let woman_running = "\u{1F3C3}\u{200D}\u{2640}\u{FE0F}";
+        assert_eq!(
+            spans(&format!("{}{}", family, woman_running)),
+            vec![Emoji(family), Emoji(woman_running)]
+        );
+
+        // Dangling ZWJ
+        assert_eq!(
+            spans("\u{1F468}\u{200D}a"),
+            vec![Emoji("\u{1F468}"), Text("\u{200D}a")]
+        );
+    }
+
+    #[test]
+    fn test_span() {
+        let span = Emoji("\u{1F600}");
+        assert!(span.is_emoji());
+        assert_eq!(span.as_str(), "\u{1F600}");
+        assert!(!Text("a").is_emoji());
+    }
+}
@@ -29,3 +29,12 @@ mod pkg_info;
 pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
 
 pub use crate::char::EMOJI_VERSION;
+
+mod iter;
+pub use crate::iter::{EmojiIter, EmojiSpan};
+
+mod qualification;
+pub use crate::qualification::{to_fully_qualified, EmojiInfo, EmojiStatus};
+
+mod sequence;
+pub use crate::sequence::{is_rgi_emoji, EmojiSequenceType};
@@ -0,0 +1,206 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Qualification status, names, groups and subgroups of emoji, from `emoji-test.txt`.
+//!
+//! <https://www.unicode.org/reports/tr51/#Emoji_Variation_Sequences>
+
+use std::fmt;
+
+/// The qualification status of an emoji, that is, whether it has all the emoji presentation
+/// selectors it should have.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum EmojiStatus {
+    /// An emoji component, such as a skin tone modifier, listed for display on its own.
+    Component,
+
+    /// A fully-qualified emoji, with all its emoji presentation selectors.
+    ///
+    /// <https://www.unicode.org/reports/tr51/#def_fully_qualified_emoji>
+    FullyQualified,
+
+    /// A minimally-qualified emoji, with the emoji presentation selector of its first character,
+    /// but missing others.
+    ///
+    /// <https://www.unicode.org/reports/tr51/#def_minimally_qualified_emoji>
+    MinimallyQualified,
+
+    /// An unqualified emoji, missing the emoji presentation selector of its first character.
+    ///
+    /// <https://www.unicode.org/reports/tr51/#def_unqualified_emoji>
+    Unqualified,
+}
+
+impl EmojiStatus {
+    /// Find the qualification status of an emoji, or `None` if it is not listed.
+    pub fn of(s: &str) -> Option<EmojiStatus> {
+        EmojiInfo::of(s).map(|info| info.status)
+    }
+}
+
+impl fmt::Display for EmojiStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            EmojiStatus::Component => "component",
+            EmojiStatus::FullyQualified => "fully-qualified",
+            EmojiStatus::MinimallyQualified => "minimally-qualified",
+            EmojiStatus::Unqualified => "unqualified",
+        })
+    }
+}
+
+/// An emoji listed in `emoji-test.txt`, with its qualification status, CLDR short name, group
+/// and subgroup.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct EmojiInfo {
+    /// The emoji.
+    pub sequence: &'static str,
+
+    /// The qualification status.
+    pub status: EmojiStatus,
+
+    /// The CLDR short name, such as `grinning face`.
+    pub name: &'static str,
+
+    /// The group, such as `Smileys & Emotion`.
+    pub group: &'static str,
+
+    /// The subgroup, such as `face-smiling`.
+    pub subgroup: &'static str,
+}
+
+mod data {
+    use super::EmojiStatus::{self, *};
+
+    pub const EMOJI_TEST: &[(&str, EmojiStatus, &str, &str, &str)] =
+        include!("../tables/emoji_test.rsv");
+
+    pub const EMOJI_FULLY_QUALIFIED: &[(&str, &str)] =
+        include!("../tables/emoji_fully_qualified.rsv");
+}
+
+impl EmojiInfo {
+    /// Find the information about an emoji, or `None` if it is not listed.
+    pub fn of(s: &str) -> Option<EmojiInfo> {
+        let idx = data::EMOJI_TEST
+            .binary_search_by(|&(sequence, ..)| sequence.cmp(s))
+            .ok()?;
+        let (sequence, status, name, group, subgroup) = data::EMOJI_TEST[idx];
+        Some(EmojiInfo {
+            sequence,
+            status,
+            name,
+            group,
+            subgroup,
+        })
+    }
+}
+
+/// Find the fully-qualified form of an emoji, adding the emoji presentation selectors
+/// (VARIATION SELECTOR-16) it is missing.
+///
+/// Returns the emoji itself if it is fully-qualified, and `None` for components and emoji that
+/// are not listed.
+pub fn to_fully_qualified(s: &str) -> Option<&'static str> {
+    match EmojiStatus::of(s)? {
+        EmojiStatus::FullyQualified => EmojiInfo::of(s).map(|info| info.sequence),
+        EmojiStatus::Component => None,
+        EmojiStatus::MinimallyQualified | EmojiStatus::Unqualified => {
+            let idx = data::EMOJI_FULLY_QUALIFIED
+                .binary_search_by(|&(sequence, _)| sequence.cmp(s))
+                .ok()?;
+            Some(data::EMOJI_FULLY_QUALIFIED[idx].1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmojiStatus::*;
+    use super::{data, to_fully_qualified, EmojiInfo, EmojiStatus};
+    use crate::sequence::is_rgi_emoji;
+
+    #[test]
+    fn test_data_sorted() {
+        assert!(data::EMOJI_TEST
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+        assert!(data::EMOJI_FULLY_QUALIFIED
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_fully_qualified_are_rgi() {
+        // Skin tone components are basic emoji
+        for &(sequence, status, ..) in data::EMOJI_TEST {
+            assert_eq!(
+                status == FullyQualified || status == Component,
+                is_rgi_emoji(sequence),
+                "{:?}",
+                sequence
+            );
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(EmojiStatus::of("\u{1F600}"), Some(FullyQualified));
+        assert_eq!(EmojiStatus::of("\u{263A}\u{FE0F}"), Some(FullyQualified));
+        assert_eq!(EmojiStatus::of("\u{263A}"), Some(Unqualified));
+        assert_eq!(EmojiStatus::of("1\u{20E3}"), Some(Unqualified));
+        assert_eq!(EmojiStatus::of("\u{1F3FB}"), Some(Component));
+        assert_eq!(EmojiStatus::of("a"), None);
+
+        // Man detective, without the selector after the detective
+        assert_eq!(
+            EmojiStatus::of("\u{1F575}\u{FE0F}\u{200D}\u{2642}"),
+            Some(MinimallyQualified)
+        );
+    }
+
+    #[test]
+    fn test_info() {
+        assert_eq!(
+            EmojiInfo::of("\u{1F600}"),
+            Some(EmojiInfo {
+                sequence: "\u{1F600}",
+                status: FullyQualified,
+                name: "grinning face",
+                group: "Smileys & Emotion",
+                subgroup: "face-smiling",
+            })
+        );
+        assert_eq!(
+            EmojiInfo::of("\u{1F1FA}\u{1F1F8}").map(|info| info.name),
+            Some("flag: United States")
+        );
+        assert_eq!(EmojiInfo::of(""), None);
+    }
+
+    #[test]
+    fn test_to_fully_qualified() {
+        assert_eq!(to_fully_qualified("\u{1F600}"), Some("\u{1F600}"));
+        assert_eq!(to_fully_qualified("\u{263A}"), Some("\u{263A}\u{FE0F}"));
+        assert_eq!(to_fully_qualified("1\u{20E3}"), Some("1\u{FE0F}\u{20E3}"));
+        assert_eq!(
+            to_fully_qualified("\u{1F575}\u{200D}\u{2642}"),
+            Some("\u{1F575}\u{FE0F}\u{200D}\u{2642}\u{FE0F}")
+        );
+        assert_eq!(to_fully_qualified("\u{1F3FB}"), None);
+        assert_eq!(to_fully_qualified("a"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(FullyQualified.to_string(), "fully-qualified");
+        assert_eq!(MinimallyQualified.to_string(), "minimally-qualified");
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Types of emoji sequences, and the recommended emoji for general interchange (RGI).
+//!
+//! <https://www.unicode.org/reports/tr51/#Emoji_Sequences>
+
+use std::fmt;
+
+use crate::char::{is_emoji, is_emoji_component, is_emoji_presentation};
+
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+
+/// The type of an emoji, as listed in the emoji data files.
+///
+/// <https://www.unicode.org/reports/tr51/#Data_Files>
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum EmojiSequenceType {
+    /// A single emoji character with default emoji presentation, or a single emoji character with
+    /// default text presentation followed by VARIATION SELECTOR-16.
+    BasicEmoji,
+
+    /// A keycap base, followed by VARIATION SELECTOR-16 and COMBINING ENCLOSING KEYCAP.
+    ///
+    /// <https://www.unicode.org/reports/tr51/#def_emoji_keycap_sequence>
+    KeycapSequence,
+
+    /// A pair of regional indicator symbols.
+    ///
+    /// <https://www.unicode.org/reports/tr51/#def_emoji_flag_sequence>
+    FlagSequence,
+
+    /// An emoji character followed by tag characters, such as a subdivision flag.
+    ///
+    /// <https://www.unicode.org/reports/tr51/#def_emoji_tag_sequence>
+    TagSequence,
+
+    /// An emoji modifier base followed by an emoji modifier (skin tone).
+    ///
+    /// <https://www.unicode.org/reports/tr51/#def_emoji_modifier_sequence>
+    ModifierSequence,
+
+    /// Emoji joined with ZERO WIDTH JOINER.
+    ///
+    /// <https://www.unicode.org/reports/tr51/#def_emoji_zwj_sequence>
+    ZwjSequence,
+}
+
+mod data {
+    use super::EmojiSequenceType::{self, *};
+
+    pub const EMOJI_SEQUENCES: &[(&str, EmojiSequenceType)] =
+        include!("../tables/emoji_sequences.rsv");
+}
+
+impl EmojiSequenceType {
+    /// Find the type of an RGI emoji, or `None` if the string is not an RGI emoji.
+    pub fn of(s: &str) -> Option<EmojiSequenceType> {
+        if let Ok(idx) = data::EMOJI_SEQUENCES.binary_search_by(|&(sequence, _)| sequence.cmp(s)) {
+            return Some(data::EMOJI_SEQUENCES[idx].1);
+        }
+
+        if is_basic_emoji(s) {
+            Some(EmojiSequenceType::BasicEmoji)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for EmojiSequenceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            EmojiSequenceType::BasicEmoji => "Basic_Emoji",
+            EmojiSequenceType::KeycapSequence => "Emoji_Keycap_Sequence",
+            EmojiSequenceType::FlagSequence => "Emoji_Flag_Sequence",
+            EmojiSequenceType::TagSequence => "Emoji_Tag_Sequence",
+            EmojiSequenceType::ModifierSequence => "Emoji_Modifier_Sequence",
+            EmojiSequenceType::ZwjSequence => "Emoji_ZWJ_Sequence",
+        })
+    }
+}
+
+/// Whether a string is exactly one emoji of the recommended set for general interchange (RGI),
+/// in its fully-qualified form.
+///
+/// <https://www.unicode.org/reports/tr51/#def_rgi_set>
+pub fn is_rgi_emoji(s: &str) -> bool {
+    EmojiSequenceType::of(s).is_some()
+}
+
+pub(crate) fn is_regional_indicator(ch: char) -> bool {
+    matches!(ch, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+// Single regional indicators, and the components of keycap sequences, are not basic emoji.
+fn is_basic_emoji(s: &str) -> bool {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(ch), None, None) => is_emoji_presentation(ch) && !is_regional_indicator(ch),
+        (Some(ch), Some(VARIATION_SELECTOR_16), None) => {
+            is_emoji(ch) && !is_emoji_presentation(ch) && !is_emoji_component(ch)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmojiSequenceType::{self, *};
+    use super::{data, is_rgi_emoji};
+
+    #[test]
+    fn test_data_version() {
+        use crate::char::UnicodeVersion;
+
+        let version: UnicodeVersion = include!("../tables/emoji_version.rsv");
+        assert_eq!(version, crate::EMOJI_VERSION);
+    }
+
+    #[test]
+    fn test_data_sorted() {
+        assert!(data::EMOJI_SEQUENCES
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_sequence_type() {
+        // Basic emoji
+        assert_eq!(EmojiSequenceType::of("\u{1F600}"), Some(BasicEmoji));
+        assert_eq!(EmojiSequenceType::of("\u{263A}\u{FE0F}"), Some(BasicEmoji));
+        assert_eq!(EmojiSequenceType::of("\u{263A}"), None);
+        assert_eq!(EmojiSequenceType::of("\u{1F600}\u{FE0F}"), None);
+        assert_eq!(EmojiSequenceType::of("\u{1F1FA}"), None);
+        assert_eq!(EmojiSequenceType::of("1"), None);
+        assert_eq!(EmojiSequenceType::of("1\u{FE0F}"), None);
+
+        // Keycap sequences
+        assert_eq!(
+            EmojiSequenceType::of("1\u{FE0F}\u{20E3}"),
+            Some(KeycapSequence)
+        );
+        assert_eq!(EmojiSequenceType::of("1\u{20E3}"), None);
+
+        // Flag sequences
+        assert_eq!(
+            EmojiSequenceType::of("\u{1F1FA}\u{1F1F8}"),
+            Some(FlagSequence)
+        );
+        assert_eq!(EmojiSequenceType::of("\u{1F1FA}\u{1F1FA}"), None);
+
+        // Tag sequences: Scotland
+        assert_eq!(
+            EmojiSequenceType::of(
+                "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}"
+            ),
+            Some(TagSequence)
+        );
+
+        // Modifier sequences
+        assert_eq!(
+            EmojiSequenceType::of("\u{1F44D}\u{1F3FD}"),
+            Some(ModifierSequence)
+        );
+        assert_eq!(EmojiSequenceType::of("\u{1F600}\u{1F3FD}"), None);
+
+        // ZWJ sequences: family, and woman technologist
+        assert_eq!(
+            EmojiSequenceType::of("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+            Some(ZwjSequence)
+        );
+        assert_eq!(
+            EmojiSequenceType::of("\u{1F469}\u{200D}\u{1F4BB}"),
+            Some(ZwjSequence)
+        );
+        assert_eq!(EmojiSequenceType::of("\u{1F600}\u{200D}\u{1F600}"), None);
+    }
+
+    #[test]
+    fn test_is_rgi_emoji() {
+        assert!(is_rgi_emoji("\u{1F44D}"));
+        assert!(is_rgi_emoji("\u{1F44D}\u{1F3FB}"));
+        assert!(!is_rgi_emoji(""));
+        assert!(!is_rgi_emoji("a"));
+        assert!(!is_rgi_emoji("\u{1F44D}\u{1F44D}"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BasicEmoji.to_string(), "Basic_Emoji");
+        assert_eq!(ZwjSequence.to_string(), "Emoji_ZWJ_Sequence");
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Errors of identifier validation.
+
+use std::error;
+use std::fmt;
+
+/// Kinds of errors of identifier validation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ErrorKind {
+    /// An empty string.
+    Empty,
+
+    /// A first character not allowed at the start of an identifier.
+    InvalidStart,
+
+    /// A character not allowed in an identifier.
+    InvalidCharacter,
+
+    /// A medial character not between two continue characters: at the end of the identifier, or
+    /// followed by another medial character.
+    MisplacedMedial,
+
+    /// ZERO WIDTH NON-JOINER or ZERO WIDTH JOINER outside of the contexts where it is allowed.
+    ///
+    /// <https://www.unicode.org/reports/tr31/#R1a>
+    FormatControl,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            ErrorKind::Empty => "empty identifier",
+            ErrorKind::InvalidStart => "character not allowed at the start of an identifier",
+            ErrorKind::InvalidCharacter => "character not allowed in an identifier",
+            ErrorKind::MisplacedMedial => "medial character not between continue characters",
+            ErrorKind::FormatControl => "joiner not allowed in this context",
+        })
+    }
+}
+
+/// An error of identifier validation, located at the first offending code point.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Error {
+    kind: ErrorKind,
+    offset: usize,
+    code_point: Option<char>,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind, offset: usize, code_point: Option<char>) -> Error {
+        Error {
+            kind,
+            offset,
+            code_point,
+        }
+    }
+
+    /// The kind of the error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The byte offset of the offending code point in the string, or `0` for an empty string.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The offending code point, or `None` for an empty string.
+    pub fn code_point(&self) -> Option<char> {
+        self.code_point
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(ch) = self.code_point {
+            write!(f, " U+{:04X} at offset {}", ch as u32, self.offset)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Error {}
@@ -0,0 +1,105 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hashtag identifiers.
+//!
+//! <https://www.unicode.org/reports/tr31/#hashtag_identifiers>
+
+use unic_emoji_char::{is_emoji, is_emoji_component};
+use unic_ucd_ident::is_xid_continue;
+
+use crate::error::{Error, ErrorKind};
+
+/// Validate a string as a hashtag identifier (UAX31-R8):
+///
+/// ```text
+/// <Hashtag-Identifier> := <Start> <Continue>*
+/// <Start>    := [#﹟＃]
+/// <Continue> := [\p{XID_Continue}\p{Emoji}\p{Emoji_Component}[-+_]-[#﹟＃]]
+/// ```
+///
+/// ZERO WIDTH JOINER, COMBINING ENCLOSING KEYCAP and the tag characters are also allowed in
+/// `Continue`, so that emoji sequences can be used in hashtags.
+///
+/// On failure, the error points at the first offending code point.
+///
+/// <https://www.unicode.org/reports/tr31/#R8>
+pub fn validate_hashtag_identifier(s: &str) -> Result<(), Error> {
+    let mut chars = s.char_indices();
+    match chars.next() {
+        None => return Err(Error::new(ErrorKind::Empty, 0, None)),
+        Some((_, ch)) if is_hashtag_start(ch) => {}
+        Some((i, ch)) => return Err(Error::new(ErrorKind::InvalidStart, i, Some(ch))),
+    }
+
+    for (i, ch) in chars {
+        if !is_hashtag_continue(ch) {
+            return Err(Error::new(ErrorKind::InvalidCharacter, i, Some(ch)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a string is a hashtag identifier (UAX31-R8).
+pub fn is_hashtag_identifier(s: &str) -> bool {
+    validate_hashtag_identifier(s).is_ok()
+}
+
+fn is_hashtag_start(ch: char) -> bool {
+    matches!(ch, '#' | '\u{FE5F}' | '\u{FF03}')
+}
+
+fn is_hashtag_continue(ch: char) -> bool {
+    !is_hashtag_start(ch)
+        && (is_xid_continue(ch)
+            || is_emoji(ch)
+            || is_emoji_component(ch)
+            || matches!(ch, '-' | '+' | '_')
+            || is_emoji_sequence_component(ch))
+}
+
+// Components of emoji sequences, not `Emoji_Component` in the emoji data of this version.
+fn is_emoji_sequence_component(ch: char) -> bool {
+    matches!(ch, '\u{200D}' | '\u{20E3}' | '\u{E0020}'..='\u{E007F}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hashtag_identifier() {
+        assert!(is_hashtag_identifier("#unicode"));
+        assert!(is_hashtag_identifier("#2018"));
+        assert!(is_hashtag_identifier("#rust-lang_2018"));
+        assert!(is_hashtag_identifier("\u{FF03}\u{65E5}\u{672C}"));
+        assert!(is_hashtag_identifier("#I\u{2764}\u{FE0F}NY"));
+        assert!(is_hashtag_identifier("#\u{1F468}\u{200D}\u{1F4BB}"));
+        assert!(is_hashtag_identifier("#1\u{FE0F}\u{20E3}"));
+        assert!(is_hashtag_identifier("#"));
+
+        assert!(!is_hashtag_identifier(""));
+        assert!(!is_hashtag_identifier("unicode"));
+        assert!(!is_hashtag_identifier("#a#b"));
+        assert!(!is_hashtag_identifier("#a b"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = validate_hashtag_identifier("tag").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidStart);
+        assert_eq!((error.offset(), error.code_point()), (0, Some('t')));
+
+        let error = validate_hashtag_identifier("#a.b").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidCharacter);
+        assert_eq!((error.offset(), error.code_point()), (2, Some('.')));
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![warn(
+    bad_style,
+    missing_debug_implementations,
+    missing_docs,
+    unconditional_recursion
+)]
+#![forbid(unsafe_code)]
+
+//! # UNIC — Unicode Identifier and Pattern Syntax
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! This UNIC component implements algorithms from [Unicode Standard Annex #31 - Unicode
+//! Identifier and Pattern Syntax](https://www.unicode.org/reports/tr31/), on top of the character
+//! properties of `unic-ucd-ident`.
+//!
+//! - [`Profile`] validates whole strings with the default identifier syntax (UAX31-R1), with
+//!   optional medial characters and handling of ZERO WIDTH NON-JOINER and ZERO WIDTH JOINER
+//!   (UAX31-R1a), and normalizes them (UAX31-R4, UAX31-R5).
+//!
+//! - [`validate_hashtag_identifier`] validates hashtags (UAX31-R8).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unic_ident::{is_default_identifier, ErrorKind, Profile, MEDIAL_CHARACTERS};
+//!
+//! assert!(is_default_identifier("caf\u{e9}"));
+//! assert!(!is_default_identifier("caf\u{e9}-au-lait"));
+//!
+//! let profile = Profile {
+//!     medial: MEDIAL_CHARACTERS,
+//!     ..Profile::default()
+//! };
+//! assert!(profile.is_identifier("caf\u{e9}-au-lait"));
+//!
+//! let error = profile.validate("caf\u{e9}-").unwrap_err();
+//! assert_eq!(error.kind(), ErrorKind::MisplacedMedial);
+//! assert_eq!(error.offset(), 5);
+//! ```
+
+#[macro_use]
+extern crate matches;
+
+mod pkg_info;
+pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
+
+pub use unic_ucd_ident::UNICODE_VERSION;
+
+mod error;
+pub use crate::error::{Error, ErrorKind};
+
+mod normalize;
+pub use crate::normalize::Normalization;
+
+mod profile;
+pub use crate::profile::{is_default_identifier, FormatControls, Profile, MEDIAL_CHARACTERS};
+
+mod hashtag;
+pub use crate::hashtag::{is_hashtag_identifier, validate_hashtag_identifier};
@@ -0,0 +1,69 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Normalization of identifiers.
+//!
+//! <https://www.unicode.org/reports/tr31/#normalization_and_case>
+
+use std::iter;
+
+use unic_case::str_to_casefold;
+use unic_normal::StrNormalForm;
+use unic_ucd_common::is_default_ignorable_code_point;
+
+/// Normalization of identifiers, for comparison of equivalent identifiers.
+///
+/// <https://www.unicode.org/reports/tr31/#normalization_and_case>
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Normalization {
+    /// Normalization Form C (UAX31-R4).
+    Nfc,
+
+    /// Normalization Form KC (UAX31-R4).
+    Nfkc,
+
+    /// `toNFKC_Casefold`, for case-insensitive identifiers (UAX31-R5).
+    NfkcCasefold,
+}
+
+pub(crate) fn normalize(s: &str, normalization: Normalization) -> String {
+    match normalization {
+        Normalization::Nfc => s.nfc().collect(),
+        Normalization::Nfkc => s.nfkc().collect(),
+        Normalization::NfkcCasefold => {
+            // Map each character to its NFKC_Casefold value, then normalize to NFC.
+            let mut mapped = String::with_capacity(s.len());
+            for ch in s.chars() {
+                if is_default_ignorable_code_point(ch) {
+                    continue;
+                }
+                let nfkc: String = iter::once(ch).nfkc().collect();
+                mapped.extend(str_to_casefold(&nfkc).nfkc());
+            }
+            mapped.nfc().collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize, Normalization::*};
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("e\u{0301}", Nfc), "\u{00E9}");
+        assert_eq!(normalize("\u{FB01}x", Nfc), "\u{FB01}x");
+        assert_eq!(normalize("\u{FB01}x", Nfkc), "fix");
+        assert_eq!(normalize("Stra\u{00DF}e", Nfkc), "Stra\u{00DF}e");
+        assert_eq!(normalize("Stra\u{00DF}e", NfkcCasefold), "strasse");
+        assert_eq!(normalize("\u{FB01}X", NfkcCasefold), "fix");
+        assert_eq!(normalize("a\u{00AD}b", NfkcCasefold), "ab");
+    }
+}
@@ -0,0 +1,353 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Default identifiers and their profiles.
+//!
+//! <https://www.unicode.org/reports/tr31/#Default_Identifier_Syntax>
+
+use unic_ucd_category::GeneralCategory;
+use unic_ucd_ident::{is_xid_continue, is_xid_start};
+use unic_ucd_joining::JoiningType;
+use unic_ucd_normal::CanonicalCombiningClass;
+
+use crate::error::{Error, ErrorKind};
+use crate::normalize::{normalize, Normalization};
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// Optional characters for `Medial`, from Table 3 of UAX #31.
+///
+/// <https://www.unicode.org/reports/tr31/#Table_Optional_Medial>
+pub const MEDIAL_CHARACTERS: &[char] = &[
+    '\u{0027}', '\u{002D}', '\u{002E}', '\u{003A}', '\u{00B7}', '\u{058A}', '\u{05F4}', '\u{0F0B}',
+    '\u{200C}', '\u{2010}', '\u{2019}', '\u{2027}', '\u{30A0}', '\u{30FB}',
+];
+
+/// Handling of ZERO WIDTH NON-JOINER and ZERO WIDTH JOINER in identifiers.
+///
+/// <https://www.unicode.org/reports/tr31/#Layout_and_Format_Control_Characters>
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum FormatControls {
+    /// Not allowed, as in the default identifier syntax.
+    Disallowed,
+
+    /// Allowed as continue characters, only in the contexts of UAX31-R1a.
+    ///
+    /// <https://www.unicode.org/reports/tr31/#R1a>
+    Restricted,
+
+    /// Allowed as continue characters anywhere.
+    Allowed,
+}
+
+impl Default for FormatControls {
+    fn default() -> Self {
+        FormatControls::Disallowed
+    }
+}
+
+/// A profile of the default identifier syntax (UAX31-R1), with the `XID_Start` and
+/// `XID_Continue` properties:
+///
+/// ```text
+/// <Identifier> := <Start> <Continue>* (<Medial> <Continue>+)*
+/// ```
+///
+/// The default profile uses no medial characters, and disallows ZERO WIDTH NON-JOINER and ZERO
+/// WIDTH JOINER.
+///
+/// <https://www.unicode.org/reports/tr31/#R1>
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Profile {
+    /// Characters allowed in `Start`, besides `XID_Start`.
+    pub additional_start: &'static [char],
+
+    /// Characters allowed in `Continue`, besides `XID_Continue`.
+    pub additional_continue: &'static [char],
+
+    /// Characters allowed in `Medial`, such as [`MEDIAL_CHARACTERS`].
+    pub medial: &'static [char],
+
+    /// Handling of ZERO WIDTH NON-JOINER and ZERO WIDTH JOINER.
+    pub format_controls: FormatControls,
+}
+
+impl Profile {
+    /// Validate a string as an identifier of this profile.
+    ///
+    /// On failure, the error points at the first offending code point.
+    pub fn validate(&self, s: &str) -> Result<(), Error> {
+        let mut chars = s.char_indices();
+        match chars.next() {
+            None => return Err(Error::new(ErrorKind::Empty, 0, None)),
+            Some((_, ch)) if self.is_start(ch) => {}
+            Some((i, ch)) => return Err(Error::new(ErrorKind::InvalidStart, i, Some(ch))),
+        }
+
+        let mut pending_medial = None;
+        for (i, ch) in chars {
+            if (ch == ZWNJ || ch == ZWJ) && self.format_controls != FormatControls::Disallowed {
+                if self.format_controls == FormatControls::Restricted
+                    && !is_format_control_allowed(&s[..i], ch, &s[i + ch.len_utf8()..])
+                {
+                    return Err(Error::new(ErrorKind::FormatControl, i, Some(ch)));
+                }
+                pending_medial = None;
+            } else if self.is_continue(ch) {
+                pending_medial = None;
+            } else if self.medial.contains(&ch) {
+                if pending_medial.is_some() {
+                    return Err(Error::new(ErrorKind::MisplacedMedial, i, Some(ch)));
+                }
+                pending_medial = Some((i, ch));
+            } else {
+                return Err(Error::new(ErrorKind::InvalidCharacter, i, Some(ch)));
+            }
+        }
+
+        match pending_medial {
+            Some((i, ch)) => Err(Error::new(ErrorKind::MisplacedMedial, i, Some(ch))),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether a string is an identifier of this profile.
+    pub fn is_identifier(&self, s: &str) -> bool {
+        self.validate(s).is_ok()
+    }
+
+    /// Normalize an identifier of this profile, for comparison of equivalent identifiers
+    /// (UAX31-R4 and UAX31-R5).
+    ///
+    /// Both the string and its normalized form are validated. On failure, the error points into
+    /// the string given: if only the normalized form is invalid, at the character whose
+    /// normalization reaches the offending code point.
+    ///
+    /// <https://www.unicode.org/reports/tr31/#R4>
+    pub fn normalize(&self, s: &str, normalization: Normalization) -> Result<String, Error> {
+        self.validate(s)?;
+        let normalized = normalize(s, normalization);
+        match self.validate(&normalized) {
+            Ok(()) => Ok(normalized),
+            Err(error) => Err(locate_in_source(s, normalization, error)),
+        }
+    }
+
+    fn is_start(&self, ch: char) -> bool {
+        is_xid_start(ch) || self.additional_start.contains(&ch)
+    }
+
+    fn is_continue(&self, ch: char) -> bool {
+        is_xid_continue(ch) || self.additional_continue.contains(&ch)
+    }
+}
+
+/// Whether a string is an identifier of the default profile.
+pub fn is_default_identifier(s: &str) -> bool {
+    Profile::default().is_identifier(s)
+}
+
+/// Move an error of the normalized form of `s` to the first character of `s` whose normalization,
+/// with the characters before it, goes past the offending code point.
+fn locate_in_source(s: &str, normalization: Normalization, error: Error) -> Error {
+    if error.code_point().is_none() {
+        return error;
+    }
+    s.char_indices()
+        .find(|&(i, ch)| normalize(&s[..i + ch.len_utf8()], normalization).len() > error.offset())
+        .map_or(error, |(i, ch)| Error::new(error.kind(), i, Some(ch)))
+}
+
+// https://www.unicode.org/reports/tr31/#R1a
+//
+// A1. /$LJ $T* ZWNJ $T* $RJ/
+// A2. /$L $M* $V $M₁* ZWNJ/
+// B.  /$L $M* $V $M₁* ZWJ/
+fn is_format_control_allowed(before: &str, ch: char, after: &str) -> bool {
+    use unic_ucd_joining::joining_type::abbr_names::*;
+
+    if follows_virama(before) {
+        return true;
+    }
+
+    if ch == ZWNJ {
+        let before = before
+            .chars()
+            .rev()
+            .map(JoiningType::of)
+            .find(|&jt| jt != T);
+        let after = after.chars().map(JoiningType::of).find(|&jt| jt != T);
+        return matches!(before, Some(L) | Some(D)) && matches!(after, Some(R) | Some(D));
+    }
+
+    false
+}
+
+// Whether the string ends with `$L $M* $V $M₁*`, where `$M` is `General_Category=Mn`, `$V` is
+// `Canonical_Combining_Class=Virama` and `$M₁` is `$M` with a non-zero combining class.
+fn follows_virama(before: &str) -> bool {
+    let is_nonspacing_mark = |ch| GeneralCategory::of(ch) == GeneralCategory::NonspacingMark;
+
+    let mut chars = before.chars().rev();
+    while let Some(ch) = chars.next() {
+        let ccc = CanonicalCombiningClass::of(ch);
+        if ccc == CanonicalCombiningClass::Virama {
+            let base = chars.clone().find(|&ch| !is_nonspacing_mark(ch));
+            if matches!(base.map(GeneralCategory::of), Some(gc) if gc.is_letter()) {
+                return true;
+            }
+        }
+        if !is_nonspacing_mark(ch) || ccc == CanonicalCombiningClass::NotReordered {
+            return false;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_profile() {
+        assert!(is_default_identifier("a"));
+        assert!(is_default_identifier("abc_123"));
+        assert!(is_default_identifier("\u{00E9}t\u{00E9}"));
+        assert!(is_default_identifier("\u{0430}\u{0431}\u{0432}"));
+        assert!(is_default_identifier("\u{4E00}\u{4E8C}"));
+
+        assert!(!is_default_identifier(""));
+        assert!(!is_default_identifier("1abc"));
+        assert!(!is_default_identifier("_abc"));
+        assert!(!is_default_identifier("a-b"));
+        assert!(!is_default_identifier("a b"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let profile = Profile::default();
+
+        let error = profile.validate("").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Empty);
+        assert_eq!(error.code_point(), None);
+
+        let error = profile.validate("1a").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidStart);
+        assert_eq!((error.offset(), error.code_point()), (0, Some('1')));
+
+        let error = profile.validate("\u{00E9}t\u{00E9} ok").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidCharacter);
+        assert_eq!((error.offset(), error.code_point()), (5, Some(' ')));
+    }
+
+    #[test]
+    fn test_additional_characters() {
+        let profile = Profile {
+            additional_start: &['_', '$'],
+            additional_continue: &['$'],
+            ..Profile::default()
+        };
+        assert!(profile.is_identifier("_abc"));
+        assert!(profile.is_identifier("$a$"));
+        assert!(!profile.is_identifier("a-b"));
+    }
+
+    #[test]
+    fn test_medial() {
+        let profile = Profile {
+            medial: MEDIAL_CHARACTERS,
+            ..Profile::default()
+        };
+        assert!(profile.is_identifier("a-b"));
+        assert!(profile.is_identifier("don\u{2019}t"));
+        assert!(profile.is_identifier("a.b:c"));
+
+        let error = profile.validate("-a").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidStart);
+
+        let error = profile.validate("a-").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::MisplacedMedial);
+        assert_eq!((error.offset(), error.code_point()), (1, Some('-')));
+
+        let error = profile.validate("a--b").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::MisplacedMedial);
+        assert_eq!((error.offset(), error.code_point()), (2, Some('-')));
+    }
+
+    #[test]
+    fn test_format_controls() {
+        // Persian, with ZWNJ between a dual-joining and a right-joining letter
+        let persian = "\u{0645}\u{06CC}\u{200C}\u{0631}\u{0648}\u{062F}";
+        // Devanagari, with ZWJ after a virama
+        let devanagari = "\u{0915}\u{094D}\u{200D}\u{0937}";
+
+        assert!(!is_default_identifier(persian));
+        assert!(!is_default_identifier(devanagari));
+
+        let restricted = Profile {
+            format_controls: FormatControls::Restricted,
+            ..Profile::default()
+        };
+        assert!(restricted.is_identifier(persian));
+        assert!(restricted.is_identifier(devanagari));
+
+        let error = restricted.validate("ab\u{200D}c").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::FormatControl);
+        assert_eq!((error.offset(), error.code_point()), (2, Some('\u{200D}')));
+
+        let allowed = Profile {
+            format_controls: FormatControls::Allowed,
+            ..Profile::default()
+        };
+        assert!(allowed.is_identifier("ab\u{200D}c"));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let profile = Profile::default();
+        assert_eq!(
+            profile.normalize("cafe\u{0301}", Normalization::Nfc),
+            Ok(String::from("caf\u{00E9}"))
+        );
+        assert_eq!(
+            profile.normalize("\u{FB01}X", Normalization::NfkcCasefold),
+            Ok(String::from("fix"))
+        );
+
+        let error = profile
+            .normalize("a\u{00B2}", Normalization::Nfkc)
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidCharacter);
+        assert_eq!((error.offset(), error.code_point()), (1, Some('\u{00B2}')));
+
+        // Only the normalized form is invalid: the error points into the string given
+        let profile = Profile {
+            additional_continue: &['\u{FE63}'],
+            ..Profile::default()
+        };
+        let error = profile
+            .normalize("ab\u{FE63}c", Normalization::Nfkc)
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidCharacter);
+        assert_eq!((error.offset(), error.code_point()), (2, Some('\u{FE63}')));
+
+        let profile = Profile {
+            medial: MEDIAL_CHARACTERS,
+            format_controls: FormatControls::Allowed,
+            ..Profile::default()
+        };
+        let error = profile
+            .normalize("a-\u{200D}-b", Normalization::NfkcCasefold)
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::MisplacedMedial);
+        assert_eq!((error.offset(), error.code_point()), (5, Some('-')));
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! IDNA2008 derived property values of code points.
+//!
+//! <https://tools.ietf.org/html/rfc5892#section-3>
+
+use std::fmt;
+use std::iter;
+
+use unic_case::str_to_casefold;
+use unic_char_basics::is_noncharacter;
+use unic_normal::StrNormalForm;
+use unic_ucd_block::Block;
+use unic_ucd_category::GeneralCategory;
+use unic_ucd_common::{is_default_ignorable_code_point, is_white_space};
+use unic_ucd_hangul::HangulSyllableType;
+
+/// The IDNA2008 derived property value of a code point.
+///
+/// <https://tools.ietf.org/html/rfc5892#section-2>
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum DerivedProperty {
+    /// Allowed in labels, with no further restriction.
+    Pvalid,
+
+    /// Allowed in labels only where its ContextJ rule, for join controls, is satisfied.
+    ContextJ,
+
+    /// Allowed in labels only where its ContextO rule is satisfied.
+    ContextO,
+
+    /// Not allowed in labels.
+    Disallowed,
+
+    /// Not assigned in the version of Unicode of the data, and not allowed in labels.
+    Unassigned,
+}
+
+impl DerivedProperty {
+    /// Find the derived property value of a code point.
+    ///
+    /// <https://tools.ietf.org/html/rfc5892#section-3>
+    pub fn of(ch: char) -> DerivedProperty {
+        if let Some(value) = exceptions(ch) {
+            value
+        } else if is_unassigned(ch) {
+            DerivedProperty::Unassigned
+        } else if is_ldh(ch) {
+            DerivedProperty::Pvalid
+        } else if is_join_control(ch) {
+            DerivedProperty::ContextJ
+        } else if is_unstable(ch)
+            || is_ignorable_property(ch)
+            || is_ignorable_block(ch)
+            || is_old_hangul_jamo(ch)
+        {
+            DerivedProperty::Disallowed
+        } else if is_letter_digit(ch) {
+            DerivedProperty::Pvalid
+        } else {
+            DerivedProperty::Disallowed
+        }
+    }
+}
+
+impl fmt::Display for DerivedProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            DerivedProperty::Pvalid => "PVALID",
+            DerivedProperty::ContextJ => "CONTEXTJ",
+            DerivedProperty::ContextO => "CONTEXTO",
+            DerivedProperty::Disallowed => "DISALLOWED",
+            DerivedProperty::Unassigned => "UNASSIGNED",
+        })
+    }
+}
+
+// F: Exceptions
+//
+// <https://tools.ietf.org/html/rfc5892#section-2.6>
+fn exceptions(ch: char) -> Option<DerivedProperty> {
+    match ch {
+        '\u{00DF}' | '\u{03C2}' | '\u{06FD}' | '\u{06FE}' | '\u{0F0B}' | '\u{3007}' => {
+            Some(DerivedProperty::Pvalid)
+        }
+        '\u{00B7}' | '\u{0375}' | '\u{05F3}' | '\u{05F4}' | '\u{30FB}' => {
+            Some(DerivedProperty::ContextO)
+        }
+        '\u{0660}'..='\u{0669}' | '\u{06F0}'..='\u{06F9}' => Some(DerivedProperty::ContextO),
+        '\u{0640}'
+        | '\u{07FA}'
+        | '\u{302E}'
+        | '\u{302F}'
+        | '\u{3031}'..='\u{3035}'
+        | '\u{303B}' => Some(DerivedProperty::Disallowed),
+        _ => None,
+    }
+}
+
+// J: Unassigned
+fn is_unassigned(ch: char) -> bool {
+    GeneralCategory::of(ch) == GeneralCategory::Unassigned && !is_noncharacter(ch)
+}
+
+// K: LDH
+fn is_ldh(ch: char) -> bool {
+    matches!(ch, '-' | '0'..='9' | 'a'..='z')
+}
+
+// H: JoinControl
+fn is_join_control(ch: char) -> bool {
+    matches!(ch, '\u{200C}' | '\u{200D}')
+}
+
+// B: Unstable, if `toNFKC(toCaseFold(toNFKC(cp))) != cp`
+fn is_unstable(ch: char) -> bool {
+    let nfkc: String = iter::once(ch).nfkc().collect();
+    let folded = str_to_casefold(&nfkc);
+    let mut stable = folded.nfkc();
+    !(stable.next() == Some(ch) && stable.next().is_none())
+}
+
+// C: IgnorableProperties
+fn is_ignorable_property(ch: char) -> bool {
+    is_default_ignorable_code_point(ch) || is_white_space(ch) || is_noncharacter(ch)
+}
+
+// D: IgnorableBlocks
+fn is_ignorable_block(ch: char) -> bool {
+    matches!(
+        Block::of(ch).map(|block| block.name),
+        Some("Combining Diacritical Marks for Symbols")
+            | Some("Musical Symbols")
+            | Some("Ancient Greek Musical Notation")
+    )
+}
+
+// I: OldHangulJamo, with `Hangul_Syllable_Type` of `L`, `V` or `T`
+fn is_old_hangul_jamo(ch: char) -> bool {
+    HangulSyllableType::of(ch).is_jamo()
+}
+
+// A: LetterDigits
+fn is_letter_digit(ch: char) -> bool {
+    use unic_ucd_category::GeneralCategory::*;
+
+    matches!(
+        GeneralCategory::of(ch),
+        LowercaseLetter
+            | UppercaseLetter
+            | OtherLetter
+            | DecimalNumber
+            | ModifierLetter
+            | NonspacingMark
+            | SpacingMark
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DerivedProperty::{self, *};
+
+    #[test]
+    fn test_values() {
+        // LDH
+        assert_eq!(DerivedProperty::of('a'), Pvalid);
+        assert_eq!(DerivedProperty::of('0'), Pvalid);
+        assert_eq!(DerivedProperty::of('-'), Pvalid);
+        assert_eq!(DerivedProperty::of('A'), Disallowed);
+        assert_eq!(DerivedProperty::of('_'), Disallowed);
+        assert_eq!(DerivedProperty::of('.'), Disallowed);
+
+        // Exceptions
+        assert_eq!(DerivedProperty::of('\u{00DF}'), Pvalid);
+        assert_eq!(DerivedProperty::of('\u{03C2}'), Pvalid);
+        assert_eq!(DerivedProperty::of('\u{00B7}'), ContextO);
+        assert_eq!(DerivedProperty::of('\u{0663}'), ContextO);
+        assert_eq!(DerivedProperty::of('\u{0640}'), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{3007}'), Pvalid);
+
+        // JoinControl
+        assert_eq!(DerivedProperty::of('\u{200C}'), ContextJ);
+        assert_eq!(DerivedProperty::of('\u{200D}'), ContextJ);
+
+        // Unstable
+        assert_eq!(DerivedProperty::of('\u{00C0}'), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{FB01}'), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{2126}'), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{0345}'), Disallowed);
+
+        // IgnorableProperties
+        assert_eq!(DerivedProperty::of(' '), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{00AD}'), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{FFFF}'), Disallowed);
+
+        // IgnorableBlocks
+        assert_eq!(DerivedProperty::of('\u{20D0}'), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{1D165}'), Disallowed);
+
+        // OldHangulJamo
+        assert_eq!(DerivedProperty::of('\u{1100}'), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{D7B0}'), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{AC00}'), Pvalid);
+
+        // LetterDigits
+        assert_eq!(DerivedProperty::of('\u{00E0}'), Pvalid);
+        assert_eq!(DerivedProperty::of('\u{0300}'), Pvalid);
+        assert_eq!(DerivedProperty::of('\u{05D0}'), Pvalid);
+        assert_eq!(DerivedProperty::of('\u{0967}'), Pvalid);
+        assert_eq!(DerivedProperty::of('\u{4E00}'), Pvalid);
+        assert_eq!(DerivedProperty::of('\u{2160}'), Disallowed);
+        assert_eq!(DerivedProperty::of('\u{2665}'), Disallowed);
+
+        // Unassigned
+        assert_eq!(DerivedProperty::of('\u{0378}'), Unassigned);
+        assert_eq!(DerivedProperty::of('\u{E0FFF}'), Unassigned);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Pvalid.to_string(), "PVALID");
+        assert_eq!(ContextJ.to_string(), "CONTEXTJ");
+        assert_eq!(Unassigned.to_string(), "UNASSIGNED");
+    }
+}
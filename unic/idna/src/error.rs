@@ -0,0 +1,232 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Errors recorded during UTS #46 processing.
+
+use std::error;
+use std::fmt;
+use std::slice;
+use std::vec;
+
+/// Kinds of errors recorded during UTS #46 processing.
+///
+/// More kinds may be added in future versions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A character *disallowed* by the mapping table.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Processing> (Step 1)
+    DisallowedCharacter,
+
+    /// A character *disallowed_STD3_valid*, with *UseSTD3ASCIIRules* set.
+    DisallowedByStd3AsciiRules,
+
+    /// A character *disallowed_STD3_mapped*, with *UseSTD3ASCIIRules* set.
+    DisallowedMappedInStd3,
+
+    /// A label starting with `xn--` that is not valid Punycode.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Processing> (Step 4)
+    PunycodeDecode,
+
+    /// A label that cannot be encoded to Punycode.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#ToASCII> (Step 3)
+    PunycodeEncode,
+
+    /// A label not in Normalization Form C.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V1)
+    NotNfc,
+
+    /// A label with U+002D HYPHEN-MINUS in both the third and fourth positions, with
+    /// *CheckHyphens* set, or beginning with `xn--` after Punycode decoding otherwise.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V2)
+    HyphensInThirdAndFourthPositions,
+
+    /// A label beginning or ending with U+002D HYPHEN-MINUS, with *CheckHyphens* set.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V3)
+    LeadingOrTrailingHyphen,
+
+    /// A label beginning with a combining mark.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V5)
+    LeadingCombiningMark,
+
+    /// A label with a character not *valid* for the type of processing, or not *PVALID*,
+    /// *CONTEXTJ* or *CONTEXTO* in IDNA2008.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V6)
+    InvalidCharacter,
+
+    /// A label with ZERO WIDTH NON-JOINER or ZERO WIDTH JOINER not allowed by the ContextJ
+    /// rules of RFC 5892, with *CheckJoiners* set.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V7)
+    ContextJ,
+
+    /// A label with a *CONTEXTO* character not allowed by its rule of RFC 5892, in IDNA2008.
+    ///
+    /// <https://tools.ietf.org/html/rfc5892#appendix-A>
+    ContextO,
+
+    /// A label of a Bidi Domain Name violating the Bidi Rule of RFC 5893, with *CheckBidi* set.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V8)
+    BidiRule,
+
+    /// A domain name or a label too long for DNS.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#ToASCII> (Step 4)
+    TooLongForDns,
+
+    /// An empty domain name or label.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#ToASCII> (Step 4)
+    TooShortForDns,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            ErrorKind::DisallowedCharacter => "disallowed character",
+            ErrorKind::DisallowedByStd3AsciiRules => "character disallowed by STD3 ASCII rules",
+            ErrorKind::DisallowedMappedInStd3 => "character mapped to STD3 disallowed characters",
+            ErrorKind::PunycodeDecode => "invalid Punycode",
+            ErrorKind::PunycodeEncode => "label cannot be encoded to Punycode",
+            ErrorKind::NotNfc => "label not in Normalization Form C",
+            ErrorKind::HyphensInThirdAndFourthPositions => {
+                "label has hyphen-minus in both the third and fourth positions"
+            }
+            ErrorKind::LeadingOrTrailingHyphen => "label begins or ends with a hyphen-minus",
+            ErrorKind::LeadingCombiningMark => "label begins with a combining mark",
+            ErrorKind::InvalidCharacter => "invalid character",
+            ErrorKind::ContextJ => "joiner not allowed in this context",
+            ErrorKind::ContextO => "character not allowed in this context",
+            ErrorKind::BidiRule => "label violates the Bidi Rule",
+            ErrorKind::TooLongForDns => "too long for DNS",
+            ErrorKind::TooShortForDns => "too short for DNS",
+        })
+    }
+}
+
+/// An error recorded during UTS #46 processing.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Error {
+    kind: ErrorKind,
+    label_index: Option<usize>,
+    code_point: Option<(usize, char)>,
+}
+
+impl Error {
+    pub(crate) fn new(
+        kind: ErrorKind,
+        label_index: Option<usize>,
+        code_point: Option<(usize, char)>,
+    ) -> Error {
+        Error {
+            kind,
+            label_index,
+            code_point,
+        }
+    }
+
+    /// The kind of the error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The index of the label in which the error was found, if it concerns a single label.
+    pub fn label_index(&self) -> Option<usize> {
+        self.label_index
+    }
+
+    /// The offending code point, if the error concerns a single character.
+    pub fn code_point(&self) -> Option<char> {
+        self.code_point.map(|(_, ch)| ch)
+    }
+
+    /// The byte offset of the offending code point within its label, if the error concerns a
+    /// single character.
+    ///
+    /// Errors found while mapping are located in the labels of the input. Errors found while
+    /// validating are located in the labels after mapping, normalization and Punycode decoding.
+    pub fn offset(&self) -> Option<usize> {
+        self.code_point.map(|(offset, _)| offset)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some((offset, ch)) = self.code_point {
+            write!(f, " U+{:04X} at offset {}", ch as u32, offset)?;
+        }
+        if let Some(label_index) = self.label_index {
+            write!(f, " in label {}", label_index)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Error {}
+
+/// Errors recorded during UTS #46 processing.
+///
+/// Contains at least one error.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Errors(pub(crate) Vec<Error>);
+
+impl Errors {
+    /// Iterate over the errors, in the order they were recorded.
+    pub fn iter(&self) -> slice::Iter<'_, Error> {
+        self.0.iter()
+    }
+
+    /// Whether an error of the given kind was recorded.
+    pub fn contains(&self, kind: ErrorKind) -> bool {
+        self.0.iter().any(|error| error.kind == kind)
+    }
+}
+
+impl IntoIterator for Errors {
+    type Item = Error;
+    type IntoIter = vec::IntoIter<Error>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Errors {
+    type Item = &'a Error;
+    type IntoIter = slice::Iter<'a, Error>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for Errors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Errors {}
@@ -43,6 +43,9 @@
 //! > This document specifies a mechanism
 //! > that minimizes the impact of this transition for client software,
 //! > allowing client software to access domains that are valid under either system.
+//!
+//! For strict IDNA2008 validation, as needed by registries, see [`to_ascii_strict`] and the
+//! [`DerivedProperty`] of code points from [RFC 5892](https://tools.ietf.org/html/rfc5892).
 
 #[macro_use]
 extern crate matches;
@@ -55,7 +58,13 @@ pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
 
 pub use crate::mapping::UNICODE_VERSION;
 
+mod error;
+pub use crate::error::{Error, ErrorKind, Errors};
+
+mod derived_property;
+pub use crate::derived_property::DerivedProperty;
+
 mod process;
+pub use crate::process::Flags;
 pub use crate::process::PUNYCODE_PREFIX;
-pub use crate::process::{to_ascii, to_unicode};
-pub use crate::process::{Errors, Flags};
+pub use crate::process::{to_ascii, to_ascii_strict, to_unicode};
@@ -9,17 +9,22 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::iter;
+
 use unic_normal::StrNormalForm;
 use unic_ucd_bidi::{bidi_class, BidiClass};
-use unic_ucd_normal::is_combining_mark;
+use unic_ucd_joining::JoiningType;
+use unic_ucd_normal::{is_combining_mark, CanonicalCombiningClass};
 
+use crate::derived_property::DerivedProperty;
+use crate::error::{Error, ErrorKind, Errors};
 use crate::mapping::Mapping;
 use crate::punycode;
 
 /// Prefix used in Punycode encoding.
 pub static PUNYCODE_PREFIX: &str = "xn--";
 
-fn map_char(codepoint: char, flags: Flags, output: &mut String, errors: &mut Vec<Error>) {
+fn map_char(codepoint: char, flags: Flags, output: &mut String) -> Option<ErrorKind> {
     match Mapping::of(codepoint) {
         Mapping::Valid => output.push(codepoint),
         Mapping::Ignored => {}
@@ -32,192 +37,392 @@ fn map_char(codepoint: char, flags: Flags, output: &mut String, errors: &mut Vec
             }
         }
         Mapping::Disallowed => {
-            errors.push(Error::DissallowedCharacter);
             output.push(codepoint);
+            return Some(ErrorKind::DisallowedCharacter);
         }
         Mapping::DisallowedStd3Valid => {
+            output.push(codepoint);
             if flags.use_std3_ascii_rules {
-                errors.push(Error::DissallowedByStd3AsciiRules);
+                return Some(ErrorKind::DisallowedByStd3AsciiRules);
             }
-            output.push(codepoint)
         }
         Mapping::DisallowedStd3Mapped(slice) => {
+            output.push_str(slice);
             if flags.use_std3_ascii_rules {
-                errors.push(Error::DissallowedMappedInStd3);
+                return Some(ErrorKind::DisallowedMappedInStd3);
             }
-            output.push_str(slice)
         }
     }
+    None
 }
 
 // http://tools.ietf.org/html/rfc5893#section-2
-fn passes_bidi(label: &str, is_bidi_domain: bool) -> bool {
+//
+// On failure, returns the offset and the code point of the character violating the rules.
+fn check_bidi(label: &str, is_bidi_domain: bool) -> Result<(), (usize, char)> {
     use self::bidi_class::abbr_names::*;
 
     // Rule 0: Bidi Rules apply to Bidi Domain Names: a name with at least one RTL label.  A label
     // is RTL if it contains at least one character of bidi class R, AL or AN.
     if !is_bidi_domain {
-        return true;
+        return Ok(());
     }
 
-    let mut chars = label.chars();
+    let mut chars = label.char_indices();
     let first_char_class = match chars.next() {
-        Some(c) => BidiClass::of(c),
-        None => return true, // empty string
+        Some((_, c)) => BidiClass::of(c),
+        None => return Ok(()), // empty string
     };
 
+    // The last character, ignoring trailing NSM characters.
+    let last_non_nsm = label
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| BidiClass::of(c) != NSM);
+
     match first_char_class {
         // LTR label
         L => {
             // Rule 5
-            while let Some(c) = chars.next() {
-                if !matches!(BidiClass::of(c), L | EN | ES | CS | ET | ON | BN | NSM) {
-                    return false;
-                }
+            if let Some(invalid) = chars
+                .find(|&(_, c)| !matches!(BidiClass::of(c), L | EN | ES | CS | ET | ON | BN | NSM))
+            {
+                return Err(invalid);
             }
 
             // Rule 6
             // must end in L or EN followed by 0 or more NSM
-            let mut rev_chars = label.chars().rev();
-            let mut last_non_nsm = rev_chars.next();
-            loop {
-                match last_non_nsm {
-                    Some(c) if BidiClass::of(c) == NSM => {
-                        last_non_nsm = rev_chars.next();
-                        continue;
-                    }
-                    _ => {
-                        break;
-                    }
-                }
-            }
             match last_non_nsm {
-                Some(c) if BidiClass::of(c) == L || BidiClass::of(c) == EN => {}
-                Some(_) => {
-                    return false;
+                Some((_, c)) if matches!(BidiClass::of(c), L | EN) => {}
+                Some(invalid) => {
+                    return Err(invalid);
                 }
-                _ => {}
+                None => {}
             }
         }
 
         // RTL label
         R | AL => {
-            let mut found_en = false;
-            let mut found_an = false;
+            let mut found_en = None;
+            let mut found_an = None;
 
             // Rule 2
-            for c in chars {
+            for (i, c) in chars {
                 let char_class = BidiClass::of(c);
 
                 if char_class == EN {
-                    found_en = true;
+                    found_en = found_en.or(Some((i, c)));
                 }
                 if char_class == AN {
-                    found_an = true;
+                    found_an = found_an.or(Some((i, c)));
                 }
 
                 if !matches!(char_class, R | AL | AN | EN | ES | CS | ET | ON | BN | NSM) {
-                    return false;
+                    return Err((i, c));
                 }
             }
 
             // Rule 3
-            let mut rev_chars = label.chars().rev();
-            let mut last = rev_chars.next();
-            loop {
-                // must end in L or EN followed by 0 or more NSM
-                match last {
-                    Some(c) if BidiClass::of(c) == NSM => {
-                        last = rev_chars.next();
-                        continue;
-                    }
-                    _ => {
-                        break;
-                    }
-                }
-            }
-            match last {
-                Some(c) if matches!(BidiClass::of(c), R | AL | EN | AN) => {}
-                _ => {
-                    return false;
+            // must end in R, AL, EN or AN followed by 0 or more NSM
+            match last_non_nsm {
+                Some((_, c)) if matches!(BidiClass::of(c), R | AL | EN | AN) => {}
+                Some(invalid) => {
+                    return Err(invalid);
                 }
+                None => {}
             }
 
             // Rule 4
-            if found_an && found_en {
-                return false;
+            if let (Some(en), Some(an)) = (found_en, found_an) {
+                return Err(if en.0 > an.0 { en } else { an });
             }
         }
 
         // Rule 1: Should start with L or R/AL
         _ => {
-            return false;
+            return Err(label.char_indices().next().unwrap());
         }
     }
 
-    true
+    Ok(())
 }
 
-// https://www.unicode.org/reports/tr46/#Validity_Criteria
-#[allow(clippy::if_same_then_else)]
-fn validate(label: &str, is_bidi_domain: bool, flags: Flags, errors: &mut Vec<Error>) {
-    let first_char = label.chars().next();
+// https://tools.ietf.org/html/rfc5892#appendix-A.1
+// https://tools.ietf.org/html/rfc5892#appendix-A.2
+//
+// On failure, returns the offset and the code point of the joiner not allowed in its context.
+fn check_context_j(label: &str, check_joiners: bool) -> Result<(), (usize, char)> {
+    use unic_ucd_joining::joining_type::abbr_names::*;
 
-    if first_char == None {
-        // Empty string, pass
+    const ZWNJ: char = '\u{200C}';
+    const ZWJ: char = '\u{200D}';
+
+    if !check_joiners {
+        return Ok(());
     }
+
+    for (i, c) in label.char_indices() {
+        if c != ZWNJ && c != ZWJ {
+            continue;
+        }
+
+        // If Canonical_Combining_Class(Before(cp)) .eq. Virama Then True;
+        let before = label[..i].chars().next_back();
+        if before.map(CanonicalCombiningClass::of) == Some(CanonicalCombiningClass::Virama) {
+            continue;
+        }
+
+        // If RegExpMatch((Joining_Type:{L,D})(Joining_Type:T)*\u200C
+        //                (Joining_Type:T)*(Joining_Type:{R,D})) Then True;
+        if c == ZWNJ {
+            let joins_before = label[..i]
+                .chars()
+                .rev()
+                .map(JoiningType::of)
+                .find(|&jt| jt != T);
+            let joins_before = matches!(joins_before, Some(L) | Some(D));
+            let joins_after = label[i + c.len_utf8()..]
+                .chars()
+                .map(JoiningType::of)
+                .find(|&jt| jt != T);
+            let joins_after = matches!(joins_after, Some(R) | Some(D));
+            if joins_before && joins_after {
+                continue;
+            }
+        }
+
+        return Err((i, c));
+    }
+
+    Ok(())
+}
+
+// https://tools.ietf.org/html/rfc5892#appendix-A.3
+// ...
+// https://tools.ietf.org/html/rfc5892#appendix-A.9
+//
+// On failure, returns the offset and the code point not allowed in its context.
+fn check_context_o(label: &str) -> Result<(), (usize, char)> {
+    use unic_ucd_script::Script;
+
+    for (i, c) in label.char_indices() {
+        let before = label[..i].chars().next_back();
+        let after = label[i + c.len_utf8()..].chars().next();
+
+        let valid = match c {
+            // MIDDLE DOT: Between two U+006C LATIN SMALL LETTER L.
+            '\u{00B7}' => before == Some('l') && after == Some('l'),
+
+            // GREEK LOWER NUMERAL SIGN (KERAIA): Followed by a Greek character.
+            '\u{0375}' => after.map(Script::of) == Some(Script::Greek),
+
+            // HEBREW PUNCTUATION GERESH and GERSHAYIM: Preceded by a Hebrew character.
+            '\u{05F3}' | '\u{05F4}' => before.map(Script::of) == Some(Script::Hebrew),
+
+            // KATAKANA MIDDLE DOT: With a Hiragana, Katakana or Han character in the label.
+            '\u{30FB}' => label.chars().any(|l| {
+                matches!(
+                    Script::of(l),
+                    Script::Hiragana | Script::Katakana | Script::Han
+                )
+            }),
+
+            // ARABIC-INDIC DIGITS: Not mixed with EXTENDED ARABIC-INDIC DIGITS.
+            '\u{0660}'..='\u{0669}' => !label.chars().any(|l| matches!(l, '\u{06F0}'..='\u{06F9}')),
+
+            // EXTENDED ARABIC-INDIC DIGITS: Not mixed with ARABIC-INDIC DIGITS.
+            '\u{06F0}'..='\u{06F9}' => !label.chars().any(|l| matches!(l, '\u{0660}'..='\u{0669}')),
+
+            _ => true,
+        };
+        if !valid {
+            return Err((i, c));
+        }
+    }
+
+    Ok(())
+}
+
+// https://www.unicode.org/reports/tr46/#Validity_Criteria
+//
+// Each criterion is checked on its own, recording an error for every one the label violates.
+fn validate(
+    label: &str,
+    label_index: usize,
+    is_bidi_domain: bool,
+    flags: Flags,
+    errors: &mut Vec<Error>,
+) {
+    let first_char = match label.chars().next() {
+        Some(first_char) => first_char,
+        // Empty string, pass
+        None => return,
+    };
+    let error = |kind, code_point| Error::new(kind, Some(label_index), code_point);
+
     // V1: Must be in NFC form.
-    else if label.nfc().ne(label.chars()) {
-        errors.push(Error::ValidityCriteria);
+    if label.nfc().ne(label.chars()) {
+        errors.push(error(ErrorKind::NotNfc, first_non_nfc(label)));
     }
-    // V2: No U+002D HYPHEN-MINUS in both third and fourth positions.
-    //
-    // NOTE: Spec says that the label must not contain a HYPHEN-MINUS character in both the
-    // third and fourth positions. But nobody follows this criteria. See the spec issue below:
-    // https://github.com/whatwg/url/issues/53
+
+    // V2: If CheckHyphens, no U+002D HYPHEN-MINUS in both third and fourth positions.
     //
-    // TODO: Add *CheckHyphens* flag.
+    // If not CheckHyphens, the label must not begin with "xn--".
+    if flags.check_hyphens {
+        if label.chars().skip(2).take(2).eq("--".chars()) {
+            errors.push(error(
+                ErrorKind::HyphensInThirdAndFourthPositions,
+                label.char_indices().nth(2),
+            ));
+        }
+    } else if label.starts_with(PUNYCODE_PREFIX) {
+        errors.push(error(
+            ErrorKind::HyphensInThirdAndFourthPositions,
+            Some((2, '-')),
+        ));
+    }
 
-    // V3: neither begin nor end with a U+002D HYPHEN-MINUS
-    else if label.starts_with('-') || label.ends_with('-') {
-        errors.push(Error::ValidityCriteria);
+    // V3: If CheckHyphens, neither begin nor end with a U+002D HYPHEN-MINUS
+    if flags.check_hyphens {
+        if label.starts_with('-') {
+            errors.push(error(ErrorKind::LeadingOrTrailingHyphen, Some((0, '-'))));
+        } else if label.ends_with('-') {
+            errors.push(error(
+                ErrorKind::LeadingOrTrailingHyphen,
+                Some((label.len() - 1, '-')),
+            ));
+        }
     }
+
     // V4: not contain a U+002E FULL STOP
     //
     // Here, label can't contain '.' since the input is from .split('.')
 
     // V5: not begin with a GC=Mark
-    else if is_combining_mark(first_char.unwrap()) {
-        errors.push(Error::ValidityCriteria);
+    if is_combining_mark(first_char) {
+        errors.push(error(
+            ErrorKind::LeadingCombiningMark,
+            Some((0, first_char)),
+        ));
     }
+
     // V6: Check against Mapping Table
-    else if label.chars().any(|c| match Mapping::of(c) {
+    if let Some(code_point) = label.char_indices().find(|&(_, c)| match Mapping::of(c) {
         Mapping::Valid => false,
         Mapping::Deviation(_) => flags.transitional_processing,
         Mapping::DisallowedStd3Valid => flags.use_std3_ascii_rules,
         _ => true,
     }) {
-        errors.push(Error::ValidityCriteria);
+        errors.push(error(ErrorKind::InvalidCharacter, Some(code_point)));
     }
-    // V7: ContextJ rules
-    //
-    // TODO: Implement rules and add *CheckJoiners* flag.
 
-    // V8: Bidi rules
-    //
-    // TODO: Add *CheckBidi* flag
-    else if !passes_bidi(label, is_bidi_domain) {
-        errors.push(Error::ValidityCriteria);
+    // V7: If CheckJoiners, ContextJ rules
+    if let Err(code_point) = check_context_j(label, flags.check_joiners) {
+        errors.push(error(ErrorKind::ContextJ, Some(code_point)));
+    }
+
+    // V8: If CheckBidi, Bidi rules
+    if let Err(code_point) = check_bidi(label, flags.check_bidi && is_bidi_domain) {
+        errors.push(error(ErrorKind::BidiRule, Some(code_point)));
     }
 }
 
+// https://tools.ietf.org/html/rfc5891#section-4.2
+//
+// As in `validate`, each requirement is checked on its own.
+fn validate_strict(label: &str, label_index: usize, is_bidi_domain: bool, errors: &mut Vec<Error>) {
+    let first_char = match label.chars().next() {
+        Some(first_char) => first_char,
+        // Empty string, pass
+        None => return,
+    };
+    let error = |kind, code_point| Error::new(kind, Some(label_index), code_point);
+
+    // Section 5.3: Must be in NFC form.
+    if label.nfc().ne(label.chars()) {
+        errors.push(error(ErrorKind::NotNfc, first_non_nfc(label)));
+    }
+
+    // Section 4.2.3.1: No U+002D HYPHEN-MINUS in both third and fourth positions, and neither
+    // begin nor end with a U+002D HYPHEN-MINUS.
+    if label.chars().skip(2).take(2).eq("--".chars()) {
+        errors.push(error(
+            ErrorKind::HyphensInThirdAndFourthPositions,
+            label.char_indices().nth(2),
+        ));
+    }
+    if label.starts_with('-') {
+        errors.push(error(ErrorKind::LeadingOrTrailingHyphen, Some((0, '-'))));
+    } else if label.ends_with('-') {
+        errors.push(error(
+            ErrorKind::LeadingOrTrailingHyphen,
+            Some((label.len() - 1, '-')),
+        ));
+    }
+
+    // Section 4.2.3.2: not begin with a GC=Mark
+    if is_combining_mark(first_char) {
+        errors.push(error(
+            ErrorKind::LeadingCombiningMark,
+            Some((0, first_char)),
+        ));
+    }
+
+    // Section 4.2.2: Only PVALID, CONTEXTJ and CONTEXTO code points
+    if let Some(code_point) = label.char_indices().find(|&(_, c)| {
+        !matches!(
+            DerivedProperty::of(c),
+            DerivedProperty::Pvalid | DerivedProperty::ContextJ | DerivedProperty::ContextO
+        )
+    }) {
+        errors.push(error(ErrorKind::InvalidCharacter, Some(code_point)));
+    }
+
+    // Section 4.2.3.3: Contextual rules
+    if let Err(code_point) = check_context_j(label, true) {
+        errors.push(error(ErrorKind::ContextJ, Some(code_point)));
+    }
+    if let Err(code_point) = check_context_o(label) {
+        errors.push(error(ErrorKind::ContextO, Some(code_point)));
+    }
+
+    // Section 4.2.3.4: Bidi rules
+    if let Err(code_point) = check_bidi(label, is_bidi_domain) {
+        errors.push(error(ErrorKind::BidiRule, Some(code_point)));
+    }
+}
+
+/// The first character of a label that differs from its normalized form.
+fn first_non_nfc(label: &str) -> Option<(usize, char)> {
+    label
+        .char_indices()
+        .zip(label.nfc().map(Some).chain(iter::repeat(None)))
+        .find(|&((_, c), normalized)| normalized != Some(c))
+        .map(|(code_point, _)| code_point)
+}
+
 // https://www.unicode.org/reports/tr46/#Processing
 fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> String {
     use self::bidi_class::abbr_names::*;
 
     let mut mapped = String::new();
-    for c in domain.chars() {
-        map_char(c, flags, &mut mapped, errors)
+    // Index and start offset of the current label of the input.
+    let mut label_index = 0;
+    let mut label_start = 0;
+    for (offset, c) in domain.char_indices() {
+        let mapped_start = mapped.len();
+        if let Some(kind) = map_char(c, flags, &mut mapped) {
+            errors.push(Error::new(
+                kind,
+                Some(label_index),
+                Some((offset - label_start, c)),
+            ));
+        }
+        let separators = mapped[mapped_start..].matches('.').count();
+        if separators > 0 {
+            label_index += separators;
+            label_start = offset + c.len_utf8();
+        }
     }
     let normalized: String = mapped.nfc().collect();
 
@@ -230,8 +435,8 @@ fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> String {
     if !is_bidi_domain {
         // Then check for punycode-encoded bidi chars
         for label in normalized.split('.') {
-            if label.starts_with(PUNYCODE_PREFIX) {
-                match punycode::decode_to_string(&label[PUNYCODE_PREFIX.len()..]) {
+            if let Some(encoded_label) = label.strip_prefix(PUNYCODE_PREFIX) {
+                match punycode::decode_to_string(encoded_label) {
                     Some(decoded_label) => {
                         if decoded_label
                             .chars()
@@ -249,26 +454,32 @@ fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> String {
     }
 
     let mut validated = String::new();
-    let mut first = true;
-    for label in normalized.split('.') {
-        if !first {
+    for (label_index, label) in normalized.split('.').enumerate() {
+        if label_index > 0 {
             validated.push('.');
         }
-        first = false;
-        if label.starts_with(PUNYCODE_PREFIX) {
-            match punycode::decode_to_string(&label[PUNYCODE_PREFIX.len()..]) {
+        if let Some(encoded_label) = label.strip_prefix(PUNYCODE_PREFIX) {
+            match punycode::decode_to_string(encoded_label) {
                 Some(decoded_label) => {
                     let flags = Flags {
                         transitional_processing: false,
                         ..flags
                     };
-                    validate(&decoded_label, is_bidi_domain, flags, errors);
+                    validate(&decoded_label, label_index, is_bidi_domain, flags, errors);
                     validated.push_str(&decoded_label)
                 }
-                None => errors.push(Error::PunycodeError),
+                None => {
+                    // Keep the label as is, so that it is not reported as empty.
+                    errors.push(Error::new(
+                        ErrorKind::PunycodeDecode,
+                        Some(label_index),
+                        None,
+                    ));
+                    validated.push_str(label)
+                }
             }
         } else {
-            validate(label, is_bidi_domain, flags, errors);
+            validate(label, label_index, is_bidi_domain, flags, errors);
             validated.push_str(label)
         }
     }
@@ -293,38 +504,47 @@ pub struct Flags {
     ///
     /// <https://www.unicode.org/reports/tr46/#ToASCII>
     pub verify_dns_length: bool,
-}
 
-/// Error types recorded during UTS #46 processing.
-#[allow(clippy::enum_variant_names)]
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-enum Error {
-    PunycodeError,
-    ValidityCriteria,
-    DissallowedByStd3AsciiRules,
-    DissallowedMappedInStd3,
-    DissallowedCharacter,
-    TooLongForDns,
-    TooShortForDns,
+    /// *CheckHyphens* flag, to verify the position of U+002D HYPHEN-MINUS characters in labels.
+    ///
+    /// When not set, only labels beginning with `xn--` are rejected, after Punycode decoding.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V2, V3)
+    pub check_hyphens: bool,
+
+    /// *CheckJoiners* flag, to verify the ContextJ rules of RFC 5892, Appendix A.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V7)
+    pub check_joiners: bool,
+
+    /// *CheckBidi* flag, to verify the Bidi Rule of RFC 5893, Section 2.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria> (V8)
+    pub check_bidi: bool,
 }
 
-/// Errors recorded during UTS #46 processing.
-///
-/// This is opaque for now, only indicating the presence of at least one error.
-/// More details may be exposed in the future.
-#[derive(Debug, Eq, PartialEq)]
-pub struct Errors(Vec<Error>);
+impl Default for Flags {
+    /// The flags recommended for registration and lookup: nontransitional processing, with all
+    /// the checks enabled.
+    fn default() -> Flags {
+        Flags {
+            use_std3_ascii_rules: true,
+            transitional_processing: false,
+            verify_dns_length: true,
+            check_hyphens: true,
+            check_joiners: true,
+            check_bidi: true,
+        }
+    }
+}
 
-/// <https://www.unicode.org/reports/tr46/#ToASCII>
-pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
-    let mut errors = Vec::new();
+// Convert the non-ASCII labels of the domain to Punycode.
+fn encode(domain: &str, errors: &mut Vec<Error>) -> String {
     let mut result = String::new();
-    let mut first = true;
-    for label in processing(domain, flags, &mut errors).split('.') {
-        if !first {
+    for (label_index, label) in domain.split('.').enumerate() {
+        if label_index > 0 {
             result.push('.');
         }
-        first = false;
         if label.is_ascii() {
             result.push_str(label);
         } else {
@@ -333,24 +553,111 @@ pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
                     result.push_str(PUNYCODE_PREFIX);
                     result.push_str(&x);
                 }
-                None => errors.push(Error::PunycodeError),
+                None => errors.push(Error::new(
+                    ErrorKind::PunycodeEncode,
+                    Some(label_index),
+                    None,
+                )),
             }
         }
     }
+    result
+}
 
+// https://www.unicode.org/reports/tr46/#ToASCII (Step 4)
+fn verify_dns_length(domain: &str, errors: &mut Vec<Error>) {
+    let domain = domain.strip_suffix('.').unwrap_or(domain);
+    if domain.is_empty() {
+        errors.push(Error::new(ErrorKind::TooShortForDns, None, None))
+    } else if let Some(label_index) = domain.split('.').position(|label| label.is_empty()) {
+        errors.push(Error::new(
+            ErrorKind::TooShortForDns,
+            Some(label_index),
+            None,
+        ))
+    }
+    if let Some(label_index) = domain.split('.').position(|label| label.len() > 63) {
+        errors.push(Error::new(
+            ErrorKind::TooLongForDns,
+            Some(label_index),
+            None,
+        ))
+    } else if domain.len() > 253 {
+        errors.push(Error::new(ErrorKind::TooLongForDns, None, None))
+    }
+}
+
+/// <https://www.unicode.org/reports/tr46/#ToASCII>
+pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
+    let mut errors = Vec::new();
+    let result = encode(&processing(domain, flags, &mut errors), &mut errors);
     if flags.verify_dns_length {
-        let domain = if result.ends_with('.') {
-            &result[..result.len() - 1]
-        } else {
-            &*result
-        };
-        if domain.is_empty() || domain.split('.').any(|label| label.is_empty()) {
-            errors.push(Error::TooShortForDns)
+        verify_dns_length(&result, &mut errors);
+    }
+    if errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(Errors(errors))
+    }
+}
+
+/// Strict IDNA2008 conversion of a domain name to ASCII.
+///
+/// Unlike [`to_ascii`], no mapping is applied to the domain name: labels are separated by
+/// U+002E FULL STOP only, and every code point must be *PVALID*, or *CONTEXTJ* or *CONTEXTO*
+/// with its contextual rule satisfied. Labels beginning with `xn--` are decoded and validated
+/// likewise. The Bidi Rule and DNS length restrictions are always verified.
+///
+/// * <https://tools.ietf.org/html/rfc5891#section-4>
+/// * <https://tools.ietf.org/html/rfc5892>
+pub fn to_ascii_strict(domain: &str) -> Result<String, Errors> {
+    use self::bidi_class::abbr_names::*;
+
+    let mut errors = Vec::new();
+
+    // Labels as U-labels, or `None` for the labels that are not valid Punycode.
+    let labels: Vec<Option<String>> = domain
+        .split('.')
+        .enumerate()
+        .map(|(label_index, label)| {
+            if let Some(encoded_label) = label.strip_prefix(PUNYCODE_PREFIX) {
+                let decoded_label = punycode::decode_to_string(encoded_label);
+                if decoded_label.is_none() {
+                    errors.push(Error::new(
+                        ErrorKind::PunycodeDecode,
+                        Some(label_index),
+                        None,
+                    ));
+                }
+                decoded_label
+            } else {
+                Some(label.to_owned())
+            }
+        })
+        .collect();
+
+    let is_bidi_domain = labels.iter().flatten().any(|label| {
+        label
+            .chars()
+            .any(|c| matches!(BidiClass::of(c), R | AL | AN))
+    });
+
+    let mut validated = String::new();
+    for (label_index, (label, original)) in labels.iter().zip(domain.split('.')).enumerate() {
+        if label_index > 0 {
+            validated.push('.');
         }
-        if domain.len() > 253 || domain.split('.').any(|label| label.len() > 63) {
-            errors.push(Error::TooLongForDns)
+        match *label {
+            Some(ref label) => {
+                validate_strict(label, label_index, is_bidi_domain, &mut errors);
+                validated.push_str(label);
+            }
+            None => validated.push_str(original),
         }
     }
+
+    let result = encode(&validated, &mut errors);
+    verify_dns_length(&result, &mut errors);
     if errors.is_empty() {
         Ok(result)
     } else {
@@ -360,7 +667,7 @@ pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
 
 /// <https://www.unicode.org/reports/tr46/#ToUnicode>
 ///
-/// Only `use_std3_ascii_rules` is used in `flags`.
+/// `transitional_processing` and `verify_dns_length` are not used in `flags`.
 pub fn to_unicode(domain: &str, mut flags: Flags) -> (String, Result<(), Errors>) {
     flags.transitional_processing = false;
     let mut errors = Vec::new();
@@ -381,14 +688,7 @@ mod tests {
     #[test]
     fn test_punycode_prefix_with_length_check() {
         fn _to_ascii(domain: &str) -> Result<String, Errors> {
-            to_ascii(
-                domain,
-                Flags {
-                    transitional_processing: false,
-                    use_std3_ascii_rules: true,
-                    verify_dns_length: true,
-                },
-            )
+            to_ascii(domain, Flags::default())
         }
 
         assert!(_to_ascii("xn--").is_err());
@@ -409,9 +709,8 @@ mod tests {
             to_ascii(
                 domain,
                 Flags {
-                    transitional_processing: false,
-                    use_std3_ascii_rules: true,
                     verify_dns_length: false,
+                    ..Flags::default()
                 },
             )
         }
@@ -430,14 +729,7 @@ mod tests {
     #[test]
     fn test_v5() {
         fn _to_ascii(domain: &str) -> Result<String, Errors> {
-            to_ascii(
-                domain,
-                Flags {
-                    transitional_processing: false,
-                    use_std3_ascii_rules: true,
-                    verify_dns_length: true,
-                },
-            )
+            to_ascii(domain, Flags::default())
         }
 
         // IdnaTest:784 蔏｡𑰺
@@ -450,14 +742,7 @@ mod tests {
     #[test]
     fn test_v8_bidi_rules() {
         fn _to_ascii(domain: &str) -> Result<String, Errors> {
-            to_ascii(
-                domain,
-                Flags {
-                    transitional_processing: false,
-                    use_std3_ascii_rules: true,
-                    verify_dns_length: true,
-                },
-            )
+            to_ascii(domain, Flags::default())
         }
 
         assert_eq!(_to_ascii("abc"), Ok("abc".to_owned()));
@@ -474,4 +759,319 @@ mod tests {
         // Bidi chars may be punycode-encoded
         assert!(_to_ascii("xn--0ca24w").is_err());
     }
+
+    #[test]
+    fn test_v2_v3_check_hyphens() {
+        fn _to_ascii(domain: &str, check_hyphens: bool) -> Result<String, Errors> {
+            to_ascii(
+                domain,
+                Flags {
+                    check_hyphens,
+                    ..Flags::default()
+                },
+            )
+        }
+
+        assert!(_to_ascii("ab--c", true).is_err());
+        assert!(_to_ascii("-abc", true).is_err());
+        assert!(_to_ascii("abc-", true).is_err());
+        assert_eq!(_to_ascii("a-b-c", true), Ok("a-b-c".to_owned()));
+
+        assert_eq!(_to_ascii("ab--c", false), Ok("ab--c".to_owned()));
+        assert_eq!(_to_ascii("-abc", false), Ok("-abc".to_owned()));
+        assert_eq!(_to_ascii("abc-", false), Ok("abc-".to_owned()));
+
+        // Labels beginning with `xn--` after Punycode decoding
+        for &check_hyphens in &[false, true] {
+            assert!(_to_ascii("xn--xn--a-fsa", check_hyphens)
+                .unwrap_err()
+                .contains(ErrorKind::HyphensInThirdAndFourthPositions));
+        }
+    }
+
+    #[test]
+    fn test_v7_check_joiners() {
+        fn _to_unicode(domain: &str, check_joiners: bool) -> Result<(), Errors> {
+            let flags = Flags {
+                check_joiners,
+                ..Flags::default()
+            };
+            to_unicode(domain, flags).1
+        }
+
+        // Not in a joining context
+        assert!(_to_unicode("a\u{200C}b", true).is_err());
+        assert!(_to_unicode("a\u{200D}b", true).is_err());
+        assert!(_to_unicode("\u{200C}", true).is_err());
+        assert_eq!(_to_unicode("a\u{200C}b", false), Ok(()));
+        assert_eq!(_to_unicode("a\u{200D}b", false), Ok(()));
+
+        // After a Virama
+        assert_eq!(_to_unicode("\u{0915}\u{094D}\u{200C}", true), Ok(()));
+        assert_eq!(
+            _to_unicode("\u{0915}\u{094D}\u{200D}\u{0937}", true),
+            Ok(())
+        );
+
+        // Between Dual_Joining characters, possibly with Transparent characters in between
+        assert_eq!(_to_unicode("\u{0628}\u{200C}\u{0628}", true), Ok(()));
+        assert_eq!(
+            _to_unicode("\u{0628}\u{064E}\u{200C}\u{064E}\u{0627}", true),
+            Ok(())
+        );
+        assert!(_to_unicode("\u{0628}\u{200D}\u{0628}", true).is_err());
+        assert!(_to_unicode("\u{0627}\u{200C}\u{0628}", true).is_err());
+        assert!(_to_unicode("\u{0628}\u{200C}", true).is_err());
+    }
+
+    #[test]
+    fn test_v8_check_bidi() {
+        let flags = Flags {
+            check_bidi: false,
+            ..Flags::default()
+        };
+
+        assert_eq!(to_ascii("0a.\u{05D0}", flags), Ok("0a.xn--4db".to_owned()));
+    }
+
+    #[test]
+    fn test_context_o() {
+        assert_eq!(check_context_o("l\u{00B7}l"), Ok(()));
+        assert_eq!(check_context_o("a\u{00B7}l"), Err((1, '\u{00B7}')));
+        assert_eq!(check_context_o("l\u{00B7}"), Err((1, '\u{00B7}')));
+
+        assert_eq!(check_context_o("\u{0375}\u{03B1}"), Ok(()));
+        assert_eq!(check_context_o("\u{0375}a"), Err((0, '\u{0375}')));
+
+        assert_eq!(check_context_o("\u{05D0}\u{05F3}"), Ok(()));
+        assert_eq!(check_context_o("\u{05D0}\u{05F4}"), Ok(()));
+        assert_eq!(check_context_o("a\u{05F3}"), Err((1, '\u{05F3}')));
+        assert_eq!(check_context_o("\u{05F4}"), Err((0, '\u{05F4}')));
+
+        assert_eq!(check_context_o("\u{30FB}\u{30A2}"), Ok(()));
+        assert_eq!(check_context_o("\u{4E00}\u{30FB}"), Ok(()));
+        assert_eq!(check_context_o("a\u{30FB}b"), Err((1, '\u{30FB}')));
+
+        assert_eq!(check_context_o("\u{0661}\u{0662}"), Ok(()));
+        assert_eq!(check_context_o("\u{06F1}\u{06F2}"), Ok(()));
+        assert_eq!(check_context_o("\u{0661}\u{06F2}"), Err((0, '\u{0661}')));
+    }
+
+    #[test]
+    fn test_to_ascii_strict() {
+        fn _to_ascii_strict(domain: &str) -> Result<String, Vec<ErrorKind>> {
+            to_ascii_strict(domain).map_err(|errors| errors.iter().map(|e| e.kind()).collect())
+        }
+
+        assert_eq!(
+            _to_ascii_strict("example.com"),
+            Ok("example.com".to_owned())
+        );
+        assert_eq!(
+            _to_ascii_strict("example.com."),
+            Ok("example.com.".to_owned())
+        );
+        assert_eq!(
+            _to_ascii_strict("b\u{00FC}cher.de"),
+            Ok("xn--bcher-kva.de".to_owned())
+        );
+        assert_eq!(
+            _to_ascii_strict("xn--bcher-kva.de"),
+            Ok("xn--bcher-kva.de".to_owned())
+        );
+        assert_eq!(
+            _to_ascii_strict("fa\u{00DF}.de"),
+            Ok("xn--fa-hia.de".to_owned())
+        );
+
+        // No mapping
+        assert_eq!(
+            _to_ascii_strict("Example.com"),
+            Err(vec![ErrorKind::InvalidCharacter])
+        );
+        assert_eq!(
+            _to_ascii_strict("example\u{3002}com"),
+            Err(vec![ErrorKind::InvalidCharacter])
+        );
+        assert_eq!(
+            _to_ascii_strict("b\u{00FC}cher.de".nfd().collect::<String>().as_str()),
+            Err(vec![ErrorKind::NotNfc])
+        );
+
+        // Not PVALID
+        assert_eq!(
+            _to_ascii_strict("\u{2665}.com"),
+            Err(vec![ErrorKind::InvalidCharacter])
+        );
+        assert_eq!(
+            _to_ascii_strict("a\u{0640}b"),
+            Err(vec![ErrorKind::InvalidCharacter, ErrorKind::BidiRule])
+        );
+        assert_eq!(
+            _to_ascii_strict("a\u{0378}b"),
+            Err(vec![ErrorKind::InvalidCharacter])
+        );
+
+        // Hyphens, leading combining marks, and contextual rules
+        assert_eq!(
+            _to_ascii_strict("ab--c"),
+            Err(vec![ErrorKind::HyphensInThirdAndFourthPositions])
+        );
+        assert_eq!(
+            _to_ascii_strict("-abc"),
+            Err(vec![ErrorKind::LeadingOrTrailingHyphen])
+        );
+        assert_eq!(
+            _to_ascii_strict("\u{0300}a"),
+            Err(vec![ErrorKind::LeadingCombiningMark])
+        );
+        assert_eq!(
+            _to_ascii_strict("a\u{200C}b"),
+            Err(vec![ErrorKind::ContextJ])
+        );
+        assert_eq!(
+            _to_ascii_strict("a\u{00B7}b"),
+            Err(vec![ErrorKind::ContextO])
+        );
+        assert!(_to_ascii_strict("l\u{00B7}l").is_ok());
+
+        // Bidi
+        assert_eq!(
+            _to_ascii_strict("0a.\u{05D0}"),
+            Err(vec![ErrorKind::BidiRule])
+        );
+
+        // Punycode and DNS length
+        assert_eq!(
+            _to_ascii_strict("xn---"),
+            Err(vec![ErrorKind::PunycodeDecode])
+        );
+        assert_eq!(
+            _to_ascii_strict("a..b"),
+            Err(vec![ErrorKind::TooShortForDns])
+        );
+        assert_eq!(_to_ascii_strict(""), Err(vec![ErrorKind::TooShortForDns]));
+    }
+
+    #[test]
+    fn test_errors() {
+        // Kind, label index, code point and offset of each error
+        type ErrorFields = (ErrorKind, Option<usize>, Option<char>, Option<usize>);
+
+        fn _to_ascii(domain: &str) -> Vec<ErrorFields> {
+            let flags = Flags::default();
+            match to_ascii(domain, flags) {
+                Ok(_) => vec![],
+                Err(errors) => errors
+                    .iter()
+                    .map(|e| (e.kind(), e.label_index(), e.code_point(), e.offset()))
+                    .collect(),
+            }
+        }
+
+        assert_eq!(_to_ascii("abc.def"), vec![]);
+
+        // Disallowed characters are reported while mapping, and while validating
+        assert_eq!(
+            _to_ascii("ab.c\u{FFFF}d"),
+            vec![
+                (
+                    ErrorKind::DisallowedCharacter,
+                    Some(1),
+                    Some('\u{FFFF}'),
+                    Some(1)
+                ),
+                (
+                    ErrorKind::InvalidCharacter,
+                    Some(1),
+                    Some('\u{FFFF}'),
+                    Some(1)
+                ),
+            ]
+        );
+
+        // Mapped label separators
+        assert_eq!(
+            _to_ascii("a\u{3002}b\u{FF0E}c\u{FFFF}")[0],
+            (
+                ErrorKind::DisallowedCharacter,
+                Some(2),
+                Some('\u{FFFF}'),
+                Some(1)
+            ),
+        );
+
+        assert_eq!(
+            _to_ascii("-abc"),
+            vec![(
+                ErrorKind::LeadingOrTrailingHyphen,
+                Some(0),
+                Some('-'),
+                Some(0)
+            )]
+        );
+        assert_eq!(
+            _to_ascii("abc.def-"),
+            vec![(
+                ErrorKind::LeadingOrTrailingHyphen,
+                Some(1),
+                Some('-'),
+                Some(3)
+            )]
+        );
+        assert_eq!(
+            _to_ascii("a.\u{0300}b"),
+            vec![(
+                ErrorKind::LeadingCombiningMark,
+                Some(1),
+                Some('\u{0300}'),
+                Some(0)
+            )]
+        );
+        assert_eq!(
+            _to_ascii("0a.\u{05D0}"),
+            vec![(ErrorKind::BidiRule, Some(0), Some('0'), Some(0))]
+        );
+        assert_eq!(
+            _to_ascii("\u{05D0}a.b"),
+            vec![(ErrorKind::BidiRule, Some(0), Some('a'), Some(2))]
+        );
+        assert_eq!(
+            _to_ascii("\u{00E9}b--c"),
+            vec![(
+                ErrorKind::HyphensInThirdAndFourthPositions,
+                Some(0),
+                Some('-'),
+                Some(3)
+            )]
+        );
+        assert_eq!(
+            _to_ascii("a.b\u{200C}c"),
+            vec![(ErrorKind::ContextJ, Some(1), Some('\u{200C}'), Some(1))]
+        );
+        assert_eq!(
+            _to_ascii("a..b"),
+            vec![(ErrorKind::TooShortForDns, Some(1), None, None)]
+        );
+        assert_eq!(
+            _to_ascii(&format!("a.{}", "b".repeat(64))),
+            vec![(ErrorKind::TooLongForDns, Some(1), None, None)]
+        );
+        assert_eq!(
+            _to_ascii(&"a.".repeat(128)),
+            vec![(ErrorKind::TooLongForDns, None, None, None)]
+        );
+    }
+
+    #[test]
+    fn test_errors_display() {
+        let flags = Flags::default();
+        let errors = to_ascii("ab.-c", flags).unwrap_err();
+        assert!(errors.contains(ErrorKind::LeadingOrTrailingHyphen));
+        assert!(!errors.contains(ErrorKind::BidiRule));
+        assert_eq!(
+            errors.to_string(),
+            "label begins or ends with a hyphen-minus U+002D at offset 0 in label 1"
+        );
+    }
 }
@@ -55,9 +55,8 @@ fn test_idn_test_data() {
         let result = unic_idna::to_ascii(
             &source,
             unic_idna::Flags {
-                use_std3_ascii_rules: true,
                 transitional_processing: test_type == "T",
-                verify_dns_length: true,
+                ..unic_idna::Flags::default()
             },
         );
 
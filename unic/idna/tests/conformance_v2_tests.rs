@@ -0,0 +1,283 @@
+// Copyright 2013-2014 The rust-url developers.
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_idna::{self, ErrorKind, Errors, Flags};
+use unic_ucd_age::Age;
+
+use std::char;
+
+#[test]
+fn test_idna_test_v2_data() {
+    let mut skipped_num = 0;
+
+    // Source: https://www.unicode.org/Public/idna/latest/IdnaTestV2.txt
+    for (line_idx, line) in include_str!("../../../external/unicode/idna/data/IdnaTestV2.txt")
+        .lines()
+        .enumerate()
+    {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // Remove comments
+        let line = match line.find('#') {
+            Some(index) => &line[0..index],
+            None => line,
+        };
+
+        let mut pieces = line.split(';').map(|x| x.trim()).collect::<Vec<&str>>();
+
+        let source = unescape(pieces.remove(0));
+
+        // ToUnicode
+        let to_unicode = match unescape(pieces.remove(0)) {
+            ref value if value.is_empty() => source.clone(),
+            value => value,
+        };
+        let mut to_unicode_status = status(pieces.remove(0));
+
+        // ToAsciiN
+        let to_ascii_n = match unescape(pieces.remove(0)) {
+            ref value if value.is_empty() => to_unicode.clone(),
+            value => value,
+        };
+        let mut to_ascii_n_status = match pieces.remove(0) {
+            "" => to_unicode_status.clone(),
+            value => status(value),
+        };
+
+        // ToAsciiT
+        let to_ascii_t = match unescape(pieces.remove(0)) {
+            ref value if value.is_empty() => to_ascii_n.clone(),
+            value => value,
+        };
+        let mut to_ascii_t_status = match pieces.remove(0) {
+            "" => to_ascii_n_status.clone(),
+            value => status(value),
+        };
+
+        // Characters assigned after the version of the mapping data are disallowed, so the cases
+        // expecting them to be valid cannot pass, and the other cases report them as disallowed
+        // besides the expected errors.
+        let has_unassigned = source
+            .chars()
+            .chain(to_unicode.chars())
+            .any(|c| Age::of(c).is_none());
+        if has_unassigned {
+            if to_unicode_status.is_empty() {
+                skipped_num += 1;
+                continue;
+            }
+            for status in &mut [
+                &mut to_unicode_status,
+                &mut to_ascii_n_status,
+                &mut to_ascii_t_status,
+            ] {
+                status.push("P1");
+            }
+        }
+
+        let test_name = format!("IdnaTestV2:{}", line_idx + 1);
+
+        let flags = Flags::default();
+
+        // "The special error codes X3 and X4_2 are now returned where a toASCII error code was
+        // formerly being generated in toUnicode due to an empty label."
+        //
+        // ToUnicode does not verify DNS length, so these are not errors here.
+        let to_unicode_status: Vec<&str> = to_unicode_status
+            .into_iter()
+            .filter(|&code| code != "X3" && code != "X4_2")
+            .collect();
+        let (value, result) = unic_idna::to_unicode(&source, flags);
+        check(
+            &test_name,
+            "ToUnicode",
+            &source,
+            has_unassigned,
+            (&to_unicode, &to_unicode_status),
+            result.map(|()| value),
+        );
+
+        let result = unic_idna::to_ascii(&source, flags);
+        check(
+            &test_name,
+            "ToAsciiN",
+            &source,
+            has_unassigned,
+            (&to_ascii_n, &to_ascii_n_status),
+            result,
+        );
+
+        let flags = Flags {
+            transitional_processing: true,
+            ..flags
+        };
+        let result = unic_idna::to_ascii(&source, flags);
+        check(
+            &test_name,
+            "ToAsciiT",
+            &source,
+            has_unassigned,
+            (&to_ascii_t, &to_ascii_t_status),
+            result,
+        );
+    }
+
+    assert_eq!(
+        skipped_num, 0,
+        "Test cases skipped for characters unassigned in this version of Unicode"
+    );
+}
+
+fn check(
+    test_name: &str,
+    operation: &str,
+    source: &str,
+    has_unassigned: bool,
+    expected: (&str, &[&str]),
+    actual: Result<String, Errors>,
+) {
+    // The Bidi_Class of characters assigned after the version of the character data may have
+    // changed from the default value of their block, so the Bidi Rule may be violated in one
+    // version and not in the other.
+    let is_bidi_rule_unsure =
+        |kinds: &[ErrorKind]| has_unassigned && kinds.contains(&ErrorKind::BidiRule);
+
+    if !expected.1.is_empty() {
+        let errors = match actual {
+            Ok(value) => panic!(
+                "{}: {}: Expected errors {:?} for source `{}`. result: `{}`",
+                test_name, operation, expected.1, source, value
+            ),
+            Err(errors) => errors,
+        };
+        for error in &errors {
+            assert!(
+                is_bidi_rule_unsure(&[error.kind()])
+                    || expected
+                        .1
+                        .iter()
+                        .any(|&code| error_kinds(code).contains(&error.kind())),
+                "{}: {}: Unexpected error `{}` for source `{}`. expected errors: {:?}",
+                test_name,
+                operation,
+                error,
+                source,
+                expected.1
+            );
+        }
+        for &code in expected.1 {
+            assert!(
+                is_bidi_rule_unsure(error_kinds(code))
+                    || errors
+                        .iter()
+                        .any(|error| error_kinds(code).contains(&error.kind())),
+                "{}: {}: Missing error for status `{}` for source `{}`. errors: `{}`",
+                test_name,
+                operation,
+                code,
+                source,
+                errors
+            );
+        }
+    } else {
+        match actual {
+            Ok(value) => assert_eq!(
+                value, expected.0,
+                "{}: {}: Incorrect result for source `{}`",
+                test_name, operation, source
+            ),
+            Err(errors) => panic!(
+                "{}: {}: Couldn't parse source `{}`. errors: `{}`",
+                test_name, operation, source, errors
+            ),
+        }
+    }
+}
+
+fn unescape(input: &str) -> String {
+    let mut output = String::new();
+    let mut chars = input.chars();
+    loop {
+        match chars.next() {
+            None => return output,
+            Some(c) => {
+                if c == '\\' {
+                    match chars.next().unwrap() {
+                        '\\' => output.push('\\'),
+                        'u' => {
+                            let c1 = chars.next().unwrap().to_digit(16).unwrap();
+                            let c2 = chars.next().unwrap().to_digit(16).unwrap();
+                            let c3 = chars.next().unwrap().to_digit(16).unwrap();
+                            let c4 = chars.next().unwrap().to_digit(16).unwrap();
+                            match char::from_u32(((c1 * 16 + c2) * 16 + c3) * 16 + c4) {
+                                Some(c) => output.push(c),
+                                None => {
+                                    output
+                                        .push_str(&format!("\\u{:X}{:X}{:X}{:X}", c1, c2, c3, c4));
+                                }
+                            };
+                        }
+                        _ => panic!("Invalid test data input"),
+                    }
+                } else {
+                    output.push(c);
+                }
+            }
+        }
+    }
+}
+
+/// The kinds of errors reported for a status code.
+///
+/// Each reported error must match one of the expected status codes, and each expected status code
+/// must have a matching error.
+fn error_kinds(code: &str) -> &'static [ErrorKind] {
+    match code {
+        // Disallowed characters are reported while mapping, and again while validating.
+        "P1" => &[
+            ErrorKind::DisallowedCharacter,
+            ErrorKind::DisallowedByStd3AsciiRules,
+            ErrorKind::DisallowedMappedInStd3,
+            ErrorKind::InvalidCharacter,
+        ],
+        "P4" => &[ErrorKind::PunycodeDecode],
+        "V1" => &[ErrorKind::NotNfc],
+        "V2" => &[ErrorKind::HyphensInThirdAndFourthPositions],
+        "V3" => &[ErrorKind::LeadingOrTrailingHyphen],
+        "V5" => &[ErrorKind::LeadingCombiningMark],
+        "V6" => &[ErrorKind::InvalidCharacter],
+        // The labels with a U+003F QUESTION MARK are not converted to Punycode in the data, but
+        // they are here, and the question mark is reported as disallowed.
+        "A3" => &[
+            ErrorKind::PunycodeEncode,
+            ErrorKind::DisallowedByStd3AsciiRules,
+        ],
+        // Domain name too long, and label empty or too long.
+        "A4_1" => &[ErrorKind::TooLongForDns],
+        "A4_2" => &[ErrorKind::TooShortForDns, ErrorKind::TooLongForDns],
+        "X4_2" => &[ErrorKind::TooShortForDns],
+        "B1" | "B2" | "B3" | "B4" | "B5" | "B6" => &[ErrorKind::BidiRule],
+        "C1" | "C2" => &[ErrorKind::ContextJ],
+        _ => panic!("Unknown status code: {}", code),
+    }
+}
+
+/// Parse a status column, such as `[B5, B6]`, into its list of codes.
+fn status(status: &str) -> Vec<&str> {
+    if status.is_empty() || status == "[]" {
+        return Vec::new();
+    }
+
+    assert!(status.starts_with('[') && status.ends_with(']'));
+    status[1..status.len() - 1].split(", ").collect()
+}
@@ -0,0 +1,147 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Confusable detection.
+//!
+//! <https://www.unicode.org/reports/tr39/#Confusable_Detection>
+
+use unic_normal::StrNormalForm;
+
+use crate::script_set::{is_single_script, resolved_script_set};
+
+mod data {
+    use unic_char_property::tables::CharDataTable;
+    pub const CONFUSABLES: CharDataTable<&str> = include!("../tables/confusables.rsv");
+}
+
+/// Find the prototype a character is visually confusable with, from `confusables.txt`.
+///
+/// Returns `None` for characters that are their own prototype.
+pub fn confusable_prototype(ch: char) -> Option<&'static str> {
+    data::CONFUSABLES.find(ch)
+}
+
+/// Compute the skeleton of a string, by mapping each character of its NFD form to its prototype
+/// and converting the result to NFD again.
+///
+/// The skeleton is only meant for comparisons, and is not suitable for display.
+///
+/// <https://www.unicode.org/reports/tr39/#def-skeleton>
+pub fn skeleton(s: &str) -> String {
+    let mut mapped = String::with_capacity(s.len());
+    for ch in s.nfd() {
+        match confusable_prototype(ch) {
+            Some(prototype) => mapped.push_str(prototype),
+            None => mapped.push(ch),
+        }
+    }
+    mapped.nfd().collect()
+}
+
+/// Whether two strings are visually confusable, that is, have the same skeleton.
+///
+/// <https://www.unicode.org/reports/tr39/#def-confusable>
+pub fn are_confusable(a: &str, b: &str) -> bool {
+    skeleton(a) == skeleton(b)
+}
+
+/// Whether two strings are *single-script confusables*: confusable, with a script in the resolved
+/// script sets of both.
+///
+/// <https://www.unicode.org/reports/tr39/#def-single-script-confusables>
+pub fn are_single_script_confusable(a: &str, b: &str) -> bool {
+    are_confusable(a, b) && shares_script(a, b)
+}
+
+/// Whether two strings are *mixed-script confusables*: confusable, with no script in the resolved
+/// script sets of both.
+///
+/// <https://www.unicode.org/reports/tr39/#def-mixed-script-confusables>
+pub fn are_mixed_script_confusable(a: &str, b: &str) -> bool {
+    are_confusable(a, b) && !shares_script(a, b)
+}
+
+/// Whether two strings are *whole-script confusables*: mixed-script confusables, with each string
+/// single-script.
+///
+/// <https://www.unicode.org/reports/tr39/#def-whole-script-confusables>
+pub fn are_whole_script_confusable(a: &str, b: &str) -> bool {
+    are_mixed_script_confusable(a, b) && is_single_script(a) && is_single_script(b)
+}
+
+fn shares_script(a: &str, b: &str) -> bool {
+    !resolved_script_set(a)
+        .intersect(resolved_script_set(b))
+        .is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confusable_prototype() {
+        assert_eq!(confusable_prototype('0'), Some("O"));
+        assert_eq!(confusable_prototype('\u{0430}'), Some("a"));
+        assert_eq!(confusable_prototype('m'), Some("rn"));
+        assert_eq!(confusable_prototype('a'), None);
+    }
+
+    #[test]
+    fn test_skeleton() {
+        assert_eq!(skeleton("paypal"), "paypal");
+        assert_eq!(
+            skeleton("\u{0440}\u{0430}\u{0443}\u{0440}\u{0430}l"),
+            "paypal"
+        );
+        assert_eq!(skeleton("m"), "rn");
+        assert_eq!(skeleton(""), "");
+
+        // Decomposed before mapping
+        assert_eq!(skeleton("\u{00E9}"), skeleton("e\u{0301}"));
+    }
+
+    #[test]
+    fn test_are_confusable() {
+        assert!(are_confusable(
+            "paypal",
+            "\u{0440}\u{0430}\u{0443}\u{0440}\u{0430}l"
+        ));
+        assert!(are_confusable("rn", "m"));
+        assert!(are_confusable("g00gle", "gOOgle"));
+        assert!(!are_confusable("paypal", "paypa1x"));
+    }
+
+    #[test]
+    fn test_single_script_confusable() {
+        assert!(are_single_script_confusable("rn", "m"));
+        assert!(are_single_script_confusable("g00gle", "gOOgle"));
+        assert!(!are_mixed_script_confusable("rn", "m"));
+        assert!(!are_whole_script_confusable("rn", "m"));
+    }
+
+    #[test]
+    fn test_mixed_script_confusable() {
+        // Cyrillic with a Latin `l`
+        let spoof = "\u{0440}\u{0430}\u{0443}\u{0440}\u{0430}l";
+        assert!(are_mixed_script_confusable("paypal", spoof));
+        assert!(!are_single_script_confusable("paypal", spoof));
+        assert!(!are_whole_script_confusable("paypal", spoof));
+    }
+
+    #[test]
+    fn test_whole_script_confusable() {
+        // All Cyrillic
+        let spoof = "\u{0455}\u{0441}\u{043E}\u{0440}\u{0435}";
+        assert!(are_whole_script_confusable("scope", spoof));
+        assert!(are_mixed_script_confusable("scope", spoof));
+        assert!(!are_single_script_confusable("scope", spoof));
+    }
+}
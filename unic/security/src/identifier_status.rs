@@ -0,0 +1,113 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! UTS #39 `Identifier_Status` Character Property.
+
+use unic_char_property::TotalCharProperty;
+
+char_property! {
+    /// Represents the
+    /// [`Identifier_Status`](https://www.unicode.org/reports/tr39/#Identifier_Status_and_Type)
+    /// character property of UTS #39.
+    ///
+    /// The characters with `Identifier_Status=Allowed` make up the *General Security Profile* for
+    /// identifiers.
+    ///
+    /// * <https://www.unicode.org/Public/security/latest/IdentifierStatus.txt>
+    pub enum IdentifierStatus {
+        abbr => "Identifier_Status";
+        long => "Identifier_Status";
+        human => "Identifier Status";
+
+        /// Allowed in identifiers
+        Allowed {
+            abbr => Allowed,
+            long => Allowed,
+            human => "Allowed",
+        }
+
+        /// Not allowed in identifiers, for one of the reasons given by its `Identifier_Type`
+        Restricted {
+            abbr => Restricted,
+            long => Restricted,
+            human => "Restricted",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Identifier_Status`](https://www.unicode.org/reports/tr39/#Identifier_Status_and_Type)
+    /// property.
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Identifier_Status`](https://www.unicode.org/reports/tr39/#Identifier_Status_and_Type)
+    /// property.
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for IdentifierStatus {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+/// The value for code points not listed in `IdentifierStatus.txt`.
+impl Default for IdentifierStatus {
+    #[inline]
+    fn default() -> Self {
+        IdentifierStatus::Restricted
+    }
+}
+
+mod data {
+    use super::abbr_names::*;
+    use unic_char_property::tables::CharDataTable;
+    pub const IDENTIFIER_STATUS_TABLE: CharDataTable<super::IdentifierStatus> =
+        include!("../tables/identifier_status.rsv");
+}
+
+impl IdentifierStatus {
+    /// Find the character `Identifier_Status` property value.
+    pub fn of(ch: char) -> IdentifierStatus {
+        data::IDENTIFIER_STATUS_TABLE.find_or_default(ch)
+    }
+}
+
+/// Methods for `Identifier_Status` character property.
+pub trait CharIdentifierStatus {
+    /// Get `Identifier_Status` of the character.
+    fn identifier_status(self) -> IdentifierStatus;
+}
+
+impl CharIdentifierStatus for char {
+    #[inline]
+    fn identifier_status(self) -> IdentifierStatus {
+        IdentifierStatus::of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharIdentifierStatus, IdentifierStatus as IS};
+
+    #[test]
+    fn test_values() {
+        assert_eq!(IS::of('a'), IS::Allowed);
+        assert_eq!(IS::of('0'), IS::Allowed);
+        assert_eq!(IS::of('-'), IS::Allowed);
+        assert_eq!(IS::of('\u{00E9}'), IS::Allowed);
+        assert_eq!(IS::of('\u{0430}'), IS::Allowed);
+        assert_eq!(IS::of('\u{4E00}'), IS::Allowed);
+        assert_eq!(IS::of(' '), IS::Restricted);
+        assert_eq!(IS::of('!'), IS::Restricted);
+        assert_eq!(IS::of('\u{2160}'), IS::Restricted);
+        assert_eq!('\u{10FFFF}'.identifier_status(), IS::Restricted);
+    }
+}
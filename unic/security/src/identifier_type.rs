@@ -0,0 +1,192 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! UTS #39 `Identifier_Type` Character Property.
+
+use unic_char_property::TotalCharProperty;
+
+char_property! {
+    /// Represents the
+    /// [`Identifier_Type`](https://www.unicode.org/reports/tr39/#Identifier_Status_and_Type)
+    /// character property of UTS #39.
+    ///
+    /// Characters of type `Recommended` or `Inclusion` have `Identifier_Status=Allowed`, and all
+    /// others are restricted for the reason given by their type.
+    ///
+    /// * <https://www.unicode.org/Public/security/latest/IdentifierType.txt>
+    pub enum IdentifierType {
+        abbr => "Identifier_Type";
+        long => "Identifier_Type";
+        human => "Identifier Type";
+
+        /// Unassigned characters, private use characters, surrogates, non-whitespace control
+        /// characters
+        NotCharacter {
+            abbr => Not_Character,
+            long => Not_Character,
+            human => "Not Character",
+        }
+
+        /// Characters with the `Deprecated` property
+        Deprecated {
+            abbr => Deprecated,
+            long => Deprecated,
+            human => "Deprecated",
+        }
+
+        /// Characters with the `Default_Ignorable_Code_Point` property
+        DefaultIgnorable {
+            abbr => Default_Ignorable,
+            long => Default_Ignorable,
+            human => "Default Ignorable",
+        }
+
+        /// Characters that cannot occur in strings normalized to NFKC
+        NotNfkc {
+            abbr => Not_NFKC,
+            long => Not_NFKC,
+            human => "Not NFKC",
+        }
+
+        /// Characters that do not qualify as default Unicode identifiers
+        NotXid {
+            abbr => Not_XID,
+            long => Not_XID,
+            human => "Not XID",
+        }
+
+        /// Characters of Excluded Scripts of UAX #31
+        Exclusion {
+            abbr => Exclusion,
+            long => Exclusion,
+            human => "Exclusion",
+        }
+
+        /// Characters no longer in modern use, or not commonly used in modern text
+        Obsolete {
+            abbr => Obsolete,
+            long => Obsolete,
+            human => "Obsolete",
+        }
+
+        /// Specialized usage: technical, liturgical, etc.
+        Technical {
+            abbr => Technical,
+            long => Technical,
+            human => "Technical",
+        }
+
+        /// Characters that are uncommon, or only used in limited contexts
+        UncommonUse {
+            abbr => Uncommon_Use,
+            long => Uncommon_Use,
+            human => "Uncommon Use",
+        }
+
+        /// Characters of Limited Use Scripts of UAX #31
+        LimitedUse {
+            abbr => Limited_Use,
+            long => Limited_Use,
+            human => "Limited Use",
+        }
+
+        /// Exceptionally allowed characters, including some punctuation
+        Inclusion {
+            abbr => Inclusion,
+            long => Inclusion,
+            human => "Inclusion",
+        }
+
+        /// Characters of Recommended Scripts of UAX #31, not in another type
+        Recommended {
+            abbr => Recommended,
+            long => Recommended,
+            human => "Recommended",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Identifier_Type`](https://www.unicode.org/reports/tr39/#Identifier_Status_and_Type)
+    /// property.
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Identifier_Type`](https://www.unicode.org/reports/tr39/#Identifier_Status_and_Type)
+    /// property.
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for IdentifierType {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+/// The value for code points not listed in `IdentifierType.txt`.
+impl Default for IdentifierType {
+    #[inline]
+    fn default() -> Self {
+        IdentifierType::NotCharacter
+    }
+}
+
+mod data {
+    use super::abbr_names::*;
+    use unic_char_property::tables::CharDataTable;
+    pub const IDENTIFIER_TYPE_TABLE: CharDataTable<super::IdentifierType> =
+        include!("../tables/identifier_type.rsv");
+}
+
+impl IdentifierType {
+    /// Find the character `Identifier_Type` property value.
+    pub fn of(ch: char) -> IdentifierType {
+        data::IDENTIFIER_TYPE_TABLE.find_or_default(ch)
+    }
+}
+
+/// Methods for `Identifier_Type` character property.
+pub trait CharIdentifierType {
+    /// Get `Identifier_Type` of the character.
+    fn identifier_type(self) -> IdentifierType;
+}
+
+impl CharIdentifierType for char {
+    #[inline]
+    fn identifier_type(self) -> IdentifierType {
+        IdentifierType::of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharIdentifierType, IdentifierType as IT};
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(IT::of('a'), IT::Recommended);
+        assert_eq!(IT::of('\u{4E00}'), IT::Recommended);
+        assert_eq!(IT::of('-'), IT::Inclusion);
+        assert_eq!(IT::of('\u{00B7}'), IT::Inclusion);
+        assert_eq!(IT::of(' '), IT::NotXid);
+        assert_eq!(IT::of('\u{2160}'), IT::NotNfkc);
+        assert_eq!(IT::of('\u{00AD}'), IT::DefaultIgnorable);
+        assert_eq!(IT::of('\u{0149}'), IT::Deprecated);
+        assert_eq!(IT::of('\u{16A0}'), IT::Exclusion);
+        assert_eq!(IT::of('\u{0378}'), IT::NotCharacter);
+        assert_eq!('\u{10FFFF}'.identifier_type(), IT::NotCharacter);
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(IT::NotNfkc.long_name(), "Not_NFKC");
+        assert_eq!(IT::LimitedUse.long_name(), "Limited_Use");
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![warn(
+    bad_style,
+    missing_debug_implementations,
+    missing_docs,
+    unconditional_recursion
+)]
+#![forbid(unsafe_code)]
+
+//! # UNIC — Unicode Security Mechanisms
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! This UNIC component implements algorithms from [Unicode Technical Standard #39 - Unicode
+//! Security Mechanisms](https://www.unicode.org/reports/tr39/).
+//!
+//! Quoting from [UTS #39’s introduction](https://www.unicode.org/reports/tr39/#Introduction):
+//!
+//! > Because Unicode contains such a large number of characters and incorporates the varied
+//! > writing systems of the world, incorrect usage can expose programs or systems to possible
+//! > security attacks.
+//!
+//! The [`skeleton`] of a string maps visually similar characters to a common prototype, so that
+//! strings which look alike, such as usernames or domain names, can be detected with
+//! [`are_confusable`]. The [`AugmentedScriptSet`] of strings further tells *single-script*,
+//! *mixed-script* and *whole-script* confusables apart.
+//!
+//! For identifiers, [`restriction_level`] tells how many scripts are mixed in a string, and which,
+//! using the [`IdentifierStatus`] and [`IdentifierType`] character properties, which complement
+//! the default identifier syntax of `unic-ucd-ident`. [`has_mixed_numbers`] detects decimal digits
+//! from several decimal systems.
+
+#[macro_use]
+extern crate unic_char_property;
+
+#[macro_use]
+extern crate unic_char_range;
+
+mod pkg_info;
+pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
+
+pub mod confusable;
+pub use crate::confusable::{
+    are_confusable,
+    are_mixed_script_confusable,
+    are_single_script_confusable,
+    are_whole_script_confusable,
+    confusable_prototype,
+    skeleton,
+};
+
+pub mod identifier_status;
+pub use crate::identifier_status::{CharIdentifierStatus, IdentifierStatus};
+
+pub mod identifier_type;
+pub use crate::identifier_type::{CharIdentifierType, IdentifierType};
+
+pub mod mixed_number;
+pub use crate::mixed_number::{decimal_digit_zero, has_mixed_numbers};
+
+pub mod restriction_level;
+pub use crate::restriction_level::{restriction_level, RestrictionLevel};
+
+pub mod script_set;
+pub use crate::script_set::{is_single_script, resolved_script_set, AugmentedScriptSet};
+
+use unic_ucd_version::UnicodeVersion;
+
+/// The [Unicode version](https://www.unicode.org/versions/) of data
+pub const UNICODE_VERSION: UnicodeVersion = include!("../tables/unicode_version.rsv");
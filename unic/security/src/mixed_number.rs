@@ -0,0 +1,65 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Mixed-number detection.
+//!
+//! <https://www.unicode.org/reports/tr39/#Mixed_Number_Detection>
+
+mod data {
+    use unic_char_property::tables::CharDataTable;
+    pub const DECIMAL_DIGIT_ZERO: CharDataTable<char> =
+        include!("../tables/decimal_digit_zero.rsv");
+}
+
+/// Find the zero digit of the decimal system of a decimal digit (`General_Category=Nd`).
+///
+/// Returns `None` for characters that are not decimal digits.
+pub fn decimal_digit_zero(ch: char) -> Option<char> {
+    data::DECIMAL_DIGIT_ZERO.find(ch)
+}
+
+/// Whether a string has decimal digits from more than one decimal system, such as ASCII digits
+/// mixed with Bengali digits.
+pub fn has_mixed_numbers(s: &str) -> bool {
+    let mut zeros = s.chars().filter_map(decimal_digit_zero);
+    match zeros.next() {
+        Some(first) => zeros.any(|zero| zero != first),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_digit_zero() {
+        assert_eq!(decimal_digit_zero('0'), Some('0'));
+        assert_eq!(decimal_digit_zero('7'), Some('0'));
+        assert_eq!(decimal_digit_zero('\u{09EA}'), Some('\u{09E6}'));
+        assert_eq!(decimal_digit_zero('\u{1D7D9}'), Some('\u{1D7D8}'));
+        assert_eq!(decimal_digit_zero('\u{1D7E2}'), Some('\u{1D7E2}'));
+        assert_eq!(decimal_digit_zero('a'), None);
+        assert_eq!(decimal_digit_zero('\u{00B2}'), None);
+        assert_eq!(decimal_digit_zero('\u{2160}'), None);
+    }
+
+    #[test]
+    fn test_has_mixed_numbers() {
+        assert!(!has_mixed_numbers(""));
+        assert!(!has_mixed_numbers("abc"));
+        assert!(!has_mixed_numbers("a1b2c3"));
+        assert!(!has_mixed_numbers("\u{09E7}\u{09E8}\u{09E9}"));
+
+        // Bengali 4 looks like ASCII 8
+        assert!(has_mixed_numbers("\u{09EA}12"));
+        assert!(has_mixed_numbers("1\u{0661}"));
+    }
+}
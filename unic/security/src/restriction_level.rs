@@ -0,0 +1,182 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Restriction-level detection.
+//!
+//! <https://www.unicode.org/reports/tr39/#Restriction_Level_Detection>
+
+use std::fmt;
+
+use unic_ucd_script::Script;
+
+use crate::identifier_status::IdentifierStatus;
+use crate::script_set::AugmentedScriptSet;
+
+/// The restriction level of a string, from the most to the least restrictive.
+///
+/// Levels are ordered, so that a string *satisfies* a level if its own level is less than or equal
+/// to it.
+///
+/// <https://www.unicode.org/reports/tr39/#Restriction_Level_Detection>
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum RestrictionLevel {
+    /// Only characters of the ASCII range.
+    AsciiOnly,
+
+    /// All characters from a single script, or from the combinations of scripts making up
+    /// `Hanb`, `Jpan` or `Kore`.
+    SingleScript,
+
+    /// Only characters covered by Latin + Han + Hiragana + Katakana, Latin + Han + Bopomofo, or
+    /// Latin + Han + Hangul.
+    HighlyRestrictive,
+
+    /// Only characters covered by Latin and any one other Recommended script, except Cyrillic
+    /// and Greek.
+    ModeratelyRestrictive,
+
+    /// Any mix of characters from the identifier profile.
+    MinimallyRestrictive,
+
+    /// Characters outside the identifier profile.
+    Unrestricted,
+}
+
+impl fmt::Display for RestrictionLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            RestrictionLevel::AsciiOnly => "ASCII-Only",
+            RestrictionLevel::SingleScript => "Single Script",
+            RestrictionLevel::HighlyRestrictive => "Highly Restrictive",
+            RestrictionLevel::ModeratelyRestrictive => "Moderately Restrictive",
+            RestrictionLevel::MinimallyRestrictive => "Minimally Restrictive",
+            RestrictionLevel::Unrestricted => "Unrestricted",
+        })
+    }
+}
+
+/// Recommended Scripts of UAX #31, Table 5, allowed with Latin at the Moderately Restrictive
+/// level: all but Common, Inherited, Latin, Cyrillic and Greek.
+///
+/// <https://www.unicode.org/reports/tr31/#Table_Recommended_Scripts>
+const MODERATELY_RESTRICTIVE_SCRIPTS: &[Script] = &[
+    Script::Arabic,
+    Script::Armenian,
+    Script::Bengali,
+    Script::Bopomofo,
+    Script::Devanagari,
+    Script::Ethiopic,
+    Script::Georgian,
+    Script::Gujarati,
+    Script::Gurmukhi,
+    Script::Han,
+    Script::Hangul,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::Kannada,
+    Script::Katakana,
+    Script::Khmer,
+    Script::Lao,
+    Script::Malayalam,
+    Script::Myanmar,
+    Script::Oriya,
+    Script::Sinhala,
+    Script::Tamil,
+    Script::Telugu,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+];
+
+/// Find the restriction level of a string, with the General Security Profile, the characters
+/// with `Identifier_Status=Allowed`, as identifier profile.
+///
+/// The well-formedness of the string as an identifier is not checked.
+///
+/// <https://www.unicode.org/reports/tr39/#Restriction_Level_Detection>
+pub fn restriction_level(s: &str) -> RestrictionLevel {
+    if s.chars()
+        .any(|ch| IdentifierStatus::of(ch) != IdentifierStatus::Allowed)
+    {
+        return RestrictionLevel::Unrestricted;
+    }
+
+    if s.is_ascii() {
+        return RestrictionLevel::AsciiOnly;
+    }
+
+    let mut resolved = AugmentedScriptSet::all();
+    let mut resolved_without_latin = AugmentedScriptSet::all();
+    for ch in s.chars() {
+        let set = AugmentedScriptSet::for_char(ch);
+        resolved = resolved.intersect(set);
+        if !set.contains(Script::Latin) {
+            resolved_without_latin = resolved_without_latin.intersect(set);
+        }
+    }
+
+    if !resolved.is_empty() {
+        RestrictionLevel::SingleScript
+    } else if resolved_without_latin.contains_jpan()
+        || resolved_without_latin.contains_hanb()
+        || resolved_without_latin.contains_kore()
+    {
+        RestrictionLevel::HighlyRestrictive
+    } else if MODERATELY_RESTRICTIVE_SCRIPTS
+        .iter()
+        .any(|&script| resolved_without_latin.contains(script))
+    {
+        RestrictionLevel::ModeratelyRestrictive
+    } else {
+        RestrictionLevel::MinimallyRestrictive
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{restriction_level, RestrictionLevel::*};
+
+    #[test]
+    fn test_restriction_level() {
+        assert_eq!(restriction_level(""), AsciiOnly);
+        assert_eq!(restriction_level("paypal"), AsciiOnly);
+        assert_eq!(restriction_level("abc-123"), AsciiOnly);
+
+        assert_eq!(restriction_level("caf\u{00E9}"), SingleScript);
+        assert_eq!(restriction_level("\u{0440}\u{0430}\u{0443}"), SingleScript);
+        assert_eq!(restriction_level("\u{5B57}\u{304B}\u{30AB}"), SingleScript);
+
+        // Latin + Han + Hiragana
+        assert_eq!(restriction_level("abc\u{5B57}\u{304B}"), HighlyRestrictive);
+        // Latin + Hangul
+        assert_eq!(restriction_level("abc\u{AC00}"), HighlyRestrictive);
+
+        // Latin + Hebrew
+        assert_eq!(restriction_level("abc\u{05D0}"), ModeratelyRestrictive);
+
+        // Latin + Cyrillic
+        assert_eq!(
+            restriction_level("\u{0440}\u{0430}\u{0443}pal"),
+            MinimallyRestrictive
+        );
+        // Hebrew + Arabic
+        assert_eq!(restriction_level("\u{05D0}\u{0628}"), MinimallyRestrictive);
+
+        assert_eq!(restriction_level("a b"), Unrestricted);
+        assert_eq!(restriction_level("\u{2160}"), Unrestricted);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(AsciiOnly < SingleScript);
+        assert!(restriction_level("abc\u{05D0}") <= MinimallyRestrictive);
+        assert!(restriction_level("\u{05D0}\u{0628}") > ModeratelyRestrictive);
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Augmented and resolved script sets of characters and strings.
+//!
+//! <https://www.unicode.org/reports/tr39/#Mixed_Script_Detection>
+
+use unic_char_property::EnumeratedCharProperty;
+use unic_ucd_script::{Script, ScriptExtensions};
+
+const WORDS: usize = 3;
+
+/// The augmented script set of a character, or the resolved script set of a string.
+///
+/// Besides the values of `Script`, the set may contain the writing systems `Hanb` (Han with
+/// Bopomofo), `Jpan` (Japanese) and `Kore` (Korean), which augment the scripts they are written
+/// with. Characters with a `Script_Extensions` value of `Common` or `Inherited` have the set of
+/// *all* scripts.
+///
+/// <https://www.unicode.org/reports/tr39/#def-augmented-script-set>
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct AugmentedScriptSet {
+    scripts: [u64; WORDS],
+    hanb: bool,
+    jpan: bool,
+    kore: bool,
+}
+
+impl AugmentedScriptSet {
+    /// The set of all scripts.
+    pub fn all() -> AugmentedScriptSet {
+        let mut set = AugmentedScriptSet::empty();
+        for &script in Script::all_values() {
+            set.insert(script);
+        }
+        set.hanb = true;
+        set.jpan = true;
+        set.kore = true;
+        set
+    }
+
+    /// The empty set.
+    pub fn empty() -> AugmentedScriptSet {
+        AugmentedScriptSet {
+            scripts: [0; WORDS],
+            hanb: false,
+            jpan: false,
+            kore: false,
+        }
+    }
+
+    /// Find the augmented script set of a character, from its `Script_Extensions` value.
+    pub fn for_char(ch: char) -> AugmentedScriptSet {
+        let extensions = ScriptExtensions::of(ch);
+        if extensions.actual() == [Script::Common] || extensions.actual() == [Script::Inherited] {
+            return AugmentedScriptSet::all();
+        }
+
+        let mut set = AugmentedScriptSet::empty();
+        for &script in extensions.iter() {
+            set.insert(script);
+            match script {
+                Script::Han => {
+                    set.hanb = true;
+                    set.jpan = true;
+                    set.kore = true;
+                }
+                Script::Hiragana | Script::Katakana => set.jpan = true,
+                Script::Hangul => set.kore = true,
+                Script::Bopomofo => set.hanb = true,
+                _ => {}
+            }
+        }
+        set
+    }
+
+    /// Find the resolved script set of a string, the intersection of the augmented script sets
+    /// of its characters.
+    ///
+    /// The resolved script set of the empty string is the set of all scripts.
+    ///
+    /// <https://www.unicode.org/reports/tr39/#def-resolved-script-set>
+    pub fn for_str(s: &str) -> AugmentedScriptSet {
+        s.chars().fold(AugmentedScriptSet::all(), |set, ch| {
+            set.intersect(AugmentedScriptSet::for_char(ch))
+        })
+    }
+
+    /// The intersection of this set with another.
+    pub fn intersect(self, other: AugmentedScriptSet) -> AugmentedScriptSet {
+        let mut scripts = [0; WORDS];
+        for (idx, word) in scripts.iter_mut().enumerate() {
+            *word = self.scripts[idx] & other.scripts[idx];
+        }
+        AugmentedScriptSet {
+            scripts,
+            hanb: self.hanb && other.hanb,
+            jpan: self.jpan && other.jpan,
+            kore: self.kore && other.kore,
+        }
+    }
+
+    /// Whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        *self == AugmentedScriptSet::empty()
+    }
+
+    /// Whether the set contains all scripts.
+    pub fn is_all(&self) -> bool {
+        *self == AugmentedScriptSet::all()
+    }
+
+    /// Whether the set contains the given script.
+    pub fn contains(&self, script: Script) -> bool {
+        let idx = script as usize;
+        self.scripts[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    /// Whether the set contains `Hanb`, Han with Bopomofo.
+    pub fn contains_hanb(&self) -> bool {
+        self.hanb
+    }
+
+    /// Whether the set contains `Jpan`, Japanese.
+    pub fn contains_jpan(&self) -> bool {
+        self.jpan
+    }
+
+    /// Whether the set contains `Kore`, Korean.
+    pub fn contains_kore(&self) -> bool {
+        self.kore
+    }
+
+    fn insert(&mut self, script: Script) {
+        let idx = script as usize;
+        self.scripts[idx / 64] |= 1 << (idx % 64);
+    }
+}
+
+/// Find the resolved script set of a string.
+///
+/// <https://www.unicode.org/reports/tr39/#def-resolved-script-set>
+pub fn resolved_script_set(s: &str) -> AugmentedScriptSet {
+    AugmentedScriptSet::for_str(s)
+}
+
+/// Whether a string is *single-script*, that is, has a non-empty resolved script set.
+///
+/// <https://www.unicode.org/reports/tr39/#def-single-script>
+pub fn is_single_script(s: &str) -> bool {
+    !resolved_script_set(s).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use unic_char_property::EnumeratedCharProperty;
+    use unic_ucd_script::Script;
+
+    use super::{is_single_script, resolved_script_set, AugmentedScriptSet, WORDS};
+
+    #[test]
+    fn test_capacity() {
+        assert!(Script::all_values().len() <= WORDS * 64);
+    }
+
+    #[test]
+    fn test_for_char() {
+        let latin = AugmentedScriptSet::for_char('a');
+        assert!(latin.contains(Script::Latin));
+        assert!(!latin.contains(Script::Cyrillic));
+        assert!(!latin.contains_jpan());
+
+        let han = AugmentedScriptSet::for_char('\u{4E00}');
+        assert!(han.contains(Script::Han));
+        assert!(han.contains_hanb() && han.contains_jpan() && han.contains_kore());
+
+        let hiragana = AugmentedScriptSet::for_char('\u{3042}');
+        assert!(hiragana.contains_jpan());
+        assert!(!hiragana.contains_kore());
+
+        assert!(AugmentedScriptSet::for_char('1').is_all());
+        assert!(AugmentedScriptSet::for_char('\u{0300}').is_all());
+    }
+
+    #[test]
+    fn test_resolved_script_set() {
+        assert!(resolved_script_set("").is_all());
+        assert!(resolved_script_set("abc-123").contains(Script::Latin));
+        assert!(resolved_script_set("\u{5B57}\u{304B}\u{30AB}").contains_jpan());
+        assert!(!resolved_script_set("\u{5B57}\u{304B}\u{30AB}").contains(Script::Han));
+
+        assert!(is_single_script("paypal"));
+        assert!(is_single_script(
+            "\u{0440}\u{0430}\u{0443}\u{0440}\u{0430}\u{04CF}"
+        ));
+        assert!(!is_single_script(
+            "\u{0440}\u{0430}\u{0443}\u{0440}\u{0430}l"
+        ));
+        assert!(!is_single_script("\u{304B}\u{1100}"));
+    }
+}
@@ -17,7 +17,61 @@
 
 use std::cmp;
 
-use unic_ucd_segment::GraphemeClusterBreak as GCB;
+use unic_emoji_char::is_extended_pictographic;
+use unic_ucd_segment::GraphemeClusterBreak;
+
+/// Category of a codepoint for the current grapheme cluster boundary rules: its
+/// `Grapheme_Cluster_Break` value, with `E_Modifier` characters as `Extend`, and the other emoji
+/// values replaced by `Extended_Pictographic`.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum GCB {
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    ExtendedPictographic,
+    Other,
+}
+
+impl GCB {
+    fn of(ch: char) -> GCB {
+        match GraphemeClusterBreak::of(ch) {
+            GraphemeClusterBreak::CR => GCB::CR,
+            GraphemeClusterBreak::LF => GCB::LF,
+            GraphemeClusterBreak::Control => GCB::Control,
+            GraphemeClusterBreak::Extend | GraphemeClusterBreak::EModifier => GCB::Extend,
+            GraphemeClusterBreak::ZWJ => GCB::ZWJ,
+            GraphemeClusterBreak::RegionalIndicator => GCB::RegionalIndicator,
+            GraphemeClusterBreak::Prepend => GCB::Prepend,
+            GraphemeClusterBreak::SpacingMark => GCB::SpacingMark,
+            GraphemeClusterBreak::L => GCB::L,
+            GraphemeClusterBreak::V => GCB::V,
+            GraphemeClusterBreak::T => GCB::T,
+            GraphemeClusterBreak::LV => GCB::LV,
+            GraphemeClusterBreak::LVT => GCB::LVT,
+            GraphemeClusterBreak::EBase
+            | GraphemeClusterBreak::EBaseGAZ
+            | GraphemeClusterBreak::GlueAfterZwj
+            | GraphemeClusterBreak::Other => {
+                if is_extended_pictographic(ch) {
+                    GCB::ExtendedPictographic
+                } else {
+                    GCB::Other
+                }
+            }
+        }
+    }
+}
 
 /// External iterator for grapheme clusters and byte offsets.
 #[derive(Clone, Debug)]
@@ -187,8 +241,8 @@ enum GraphemeState {
     // The codepoint after is a Regional Indicator Symbol, so a boundary iff
     // it is preceded by an even number of RIS codepoints. (GB12, GB13)
     Regional,
-    // The codepoint after is in the E_Modifier category, so whether it's a boundary
-    // depends on pre-context according to GB10.
+    // The codepoint after is Extended_Pictographic,
+    // so whether it's a boundary depends on pre-context according to GB11.
     Emoji,
 }
 
@@ -266,7 +320,7 @@ enum PairResult {
     /// a break if preceded by an even number of Regional Indicators
     Regional,
 
-    /// a break if preceded by Emoji Base and (Extend)*
+    /// a break if not preceded by Extended_Pictographic, (Extend)* and ZWJ
     Emoji,
 }
 
@@ -305,12 +359,8 @@ fn check_pair(before: GCB, after: GCB) -> PairResult {
         (_, GCB::SpacingMark) => Extended, // GB9a
         (GCB::Prepend, _) => Extended,     // GB9b
 
-        // Do not break within Emoji Modifier Sequences or Emoji ZWJ Sequences.
-        (GCB::EBase, GCB::EModifier) => NotBreak,    // GB10
-        (GCB::EBaseGAZ, GCB::EModifier) => NotBreak, // GB10
-        (GCB::Extend, GCB::EModifier) => Emoji,      // GB10
-        (GCB::ZWJ, GCB::GlueAfterZwj) => NotBreak,   // GB11
-        (GCB::ZWJ, GCB::EBaseGAZ) => NotBreak,       // GB11
+        // Do not break within emoji modifier sequences or emoji zwj sequences.
+        (GCB::ZWJ, GCB::ExtendedPictographic) => Emoji, // GB11
 
         // Do not break within emoji flag sequences. That is, do not break between regional
         // indicator (RI) symbols if there is an odd number of RI characters before the break point.
@@ -510,10 +560,17 @@ impl GraphemeCursor {
     }
 
     fn handle_emoji(&mut self, chunk: &str, chunk_start: usize) {
-        for ch in chunk.chars().rev() {
+        let mut iter = chunk.chars().rev();
+        if let Some(ch) = iter.next() {
+            if GCB::of(ch) != GCB::ZWJ {
+                self.decide(true);
+                return;
+            }
+        }
+        for ch in iter {
             match GCB::of(ch) {
                 GCB::Extend => (),
-                GCB::EBase | GCB::EBaseGAZ => {
+                GCB::ExtendedPictographic => {
                     self.decide(false);
                     return;
                 }
@@ -585,7 +642,7 @@ impl GraphemeCursor {
             let mut need_pre_context = true;
             match self.cat_after.unwrap() {
                 GCB::RegionalIndicator => self.state = GraphemeState::Regional,
-                GCB::EModifier => self.state = GraphemeState::Emoji,
+                GCB::ExtendedPictographic => self.state = GraphemeState::Emoji,
                 _ => need_pre_context = self.cat_before.is_none(),
             }
             if need_pre_context {
@@ -24,12 +24,16 @@
 //! This UNIC component implements algorithms from [Unicode® Standard Annex #29 -
 //! Unicode Text Segmentation](http://unicode.org/reports/tr29/), used for detecting
 //! boundaries of text element boundaries, such as user-perceived characters (a.k.a.
-//! *Grapheme Clusters)*, *Words*, and *Sentences* (last one not implemented yet).
+//! *Grapheme Clusters)*, *Words*, and *Sentences*; and from [Unicode® Standard Annex #14 -
+//! Unicode Line Breaking Algorithm](http://unicode.org/reports/tr14/), used for detecting
+//! *Line Break Opportunities*.
 //!
 //! # Examples
 //!
 //! ```rust
 //! # use unic_segment::{GraphemeIndices, Graphemes, WordBoundIndices, WordBounds, Words};
+//! # use unic_segment::{SentenceBoundIndices, SentenceBounds, Sentences};
+//! # use unic_segment::{BreakOpportunity, LineBreaks};
 //! assert_eq!(
 //!     Graphemes::new("a\u{310}e\u{301}o\u{308}\u{332}").collect::<Vec<&str>>(),
 //!     &["a\u{310}", "e\u{301}", "o\u{308}\u{332}"]
@@ -76,6 +80,34 @@
 //!         (17, "!")
 //!     ]
 //! );
+//!
+//! assert_eq!(
+//!     Sentences::new(
+//!         "Mr. Fox jumped. [...] The dog was too lazy.",
+//!         has_alphanumeric,
+//!     ).collect::<Vec<&str>>(),
+//!     &["Mr. ", "Fox jumped. ", "The dog was too lazy."]
+//! );
+//!
+//! assert_eq!(
+//!     SentenceBounds::new("Hi! Who's there?\nMe.").collect::<Vec<&str>>(),
+//!     &["Hi! ", "Who's there?\n", "Me."]
+//! );
+//!
+//! assert_eq!(
+//!     SentenceBoundIndices::new("Hi! Who's there?\nMe.").collect::<Vec<(usize, &str)>>(),
+//!     &[(0, "Hi! "), (4, "Who's there?\n"), (17, "Me.")]
+//! );
+//!
+//! assert_eq!(
+//!     LineBreaks::new("Hi! Who's there?\nMe.").collect::<Vec<(usize, BreakOpportunity)>>(),
+//!     &[
+//!         (4, BreakOpportunity::Allowed),
+//!         (10, BreakOpportunity::Allowed),
+//!         (17, BreakOpportunity::Mandatory),
+//!         (20, BreakOpportunity::Mandatory)
+//!     ]
+//! );
 //! ```
 
 pub use unic_ucd_segment::UNICODE_VERSION;
@@ -88,3 +120,9 @@ pub use crate::grapheme::{GraphemeCursor, GraphemeIncomplete, GraphemeIndices, G
 
 mod word;
 pub use crate::word::{WordBoundIndices, WordBounds, Words};
+
+mod sentence;
+pub use crate::sentence::{SentenceBoundIndices, SentenceBounds, Sentences};
+
+mod line_break;
+pub use crate::line_break::{BreakOpportunity, LineBreaks};
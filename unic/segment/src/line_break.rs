@@ -0,0 +1,513 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode Line Breaking Algorithm.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr14/#Algorithm>
+
+use std::str::CharIndices;
+
+use unic_ucd_category::GeneralCategory as GC;
+use unic_ucd_segment::line_break::abbr_names as LB;
+use unic_ucd_segment::LineBreak;
+
+/// The kind of a line break opportunity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BreakOpportunity {
+    /// The line must break here, as after a newline character or at the end of the text.
+    Mandatory,
+
+    /// The line may break here.
+    Allowed,
+}
+
+/// External iterator for a string's
+/// [line break opportunities](https://www.unicode.org/reports/tr14/#BreakOpportunities).
+///
+/// Yields the byte offset of each position before which a line can break, along with the kind of
+/// the break. There is never a break at offset `0`, and the end of a non-empty string is always a
+/// mandatory break.
+///
+/// This implements the default algorithm, with rule LB25 tailored as described in [Example 7 of
+/// UAX#14](https://www.unicode.org/reports/tr14/#Examples), which is what the conformance tests
+/// expect. Characters of class `SA` are resolved from their General Category, without dictionary
+/// lookup.
+///
+/// ```rust
+/// # use unic_segment::{BreakOpportunity, LineBreaks};
+/// assert_eq!(
+///     LineBreaks::new("The quick (\"brown\") fox\ncan't").collect::<Vec<_>>(),
+///     &[
+///         (4, BreakOpportunity::Allowed),
+///         (10, BreakOpportunity::Allowed),
+///         (20, BreakOpportunity::Allowed),
+///         (24, BreakOpportunity::Mandatory),
+///         (29, BreakOpportunity::Mandatory),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct LineBreaks<'a> {
+    string: &'a str,
+    chars: CharIndices<'a>,
+    state: LineBreakState,
+    finished: bool,
+}
+
+impl<'a> LineBreaks<'a> {
+    /// Create new iterator for *line break opportunities*.
+    #[inline]
+    pub fn new(s: &str) -> LineBreaks<'_> {
+        LineBreaks {
+            string: s,
+            chars: s.char_indices(),
+            state: LineBreakState::new(),
+            finished: s.is_empty(),
+        }
+    }
+}
+
+impl<'a> Iterator for LineBreaks<'a> {
+    type Item = (usize, BreakOpportunity);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, BreakOpportunity)> {
+        while let Some((idx, ch)) = self.chars.next() {
+            let rest = self.chars.as_str();
+            if let Some(brk) = self.state.advance(resolve(ch), rest) {
+                return Some((idx, brk));
+            }
+        }
+
+        if self.finished {
+            return None;
+        }
+
+        // rule LB3
+        self.finished = true;
+        Some((self.string.len(), BreakOpportunity::Mandatory))
+    }
+}
+
+/// Resolve the `Line_Break` value of a character, as per rule LB1.
+fn resolve(ch: char) -> LineBreak {
+    match LineBreak::of(ch) {
+        LB::AI | LB::SG | LB::XX => LB::AL,
+        LB::SA => match GC::of(ch) {
+            GC::NonspacingMark | GC::SpacingMark => LB::CM,
+            _ => LB::AL,
+        },
+        LB::CJ => LB::NS,
+        lb => lb,
+    }
+}
+
+/// Whether the character is part of a combining character sequence, as per rule LB9.
+#[inline]
+fn is_combining(lb: LineBreak) -> bool {
+    matches!(lb, LB::CM | LB::ZWJ)
+}
+
+/// Progress in matching the numeric expression of rule LB25.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Numeric {
+    /// Not inside a number.
+    None,
+
+    /// After `NU (NU | SY | IS)*`.
+    Digits,
+
+    /// After `NU (NU | SY | IS)* (CL | CP)`.
+    Closed,
+}
+
+/// The context kept while going forward through the text, one character at a time.
+///
+/// Combining sequences are handled here rather than with the pre-context machinery of
+/// `GraphemeCursor`: rules LB9 and LB10 are defined on the `CM` and `ZWJ` classes of `Line_Break`,
+/// which differ from `Extend` of `Grapheme_Cluster_Break` (for example, for `SA` marks and for
+/// controls), and a combining sequence takes the class of its base instead of just not breaking.
+/// Since `LineBreaks` only goes forward over the whole text, the base class is simply kept here,
+/// and `next_base_class()` looks ahead the same way for rule LB25.
+///
+/// `XX` never remains after rule LB1, so it is used as the value before the first character.
+#[derive(Clone, Copy, Debug)]
+struct LineBreakState {
+    /// Resolved class of the previous character, or of the base of its combining sequence.
+    prev: LineBreak,
+
+    /// Resolved class before `prev`, for rule LB21a.
+    prev_prev: LineBreak,
+
+    /// Whether the previous character is a ZWJ, even if combined, for rule LB8a.
+    prev_zwj: bool,
+
+    /// Resolved class of the last character that is not a space, for the rules with `SP*`.
+    before_sp: LineBreak,
+
+    /// Whether the text so far ends with `ZW SP*`, for rule LB8.
+    after_zw: bool,
+
+    /// Progress in the numeric expression, for rule LB25.
+    numeric: Numeric,
+
+    /// Number of regional indicators in a row at the end of the text so far, for rule LB30a.
+    ri_count: usize,
+}
+
+impl LineBreakState {
+    fn new() -> LineBreakState {
+        LineBreakState {
+            prev: LB::XX,
+            prev_prev: LB::XX,
+            prev_zwj: false,
+            before_sp: LB::XX,
+            after_zw: false,
+            numeric: Numeric::None,
+            ri_count: 0,
+        }
+    }
+
+    /// Move over the next character, of resolved class `lb`, and return the break opportunity
+    /// before it, if any. `rest` is the text after the character.
+    fn advance(&mut self, lb: LineBreak, rest: &str) -> Option<BreakOpportunity> {
+        let sot = self.prev == LB::XX;
+
+        // rule LB9
+        if is_combining(lb)
+            && !sot
+            && !matches!(
+                self.prev,
+                LB::BK | LB::CR | LB::LF | LB::NL | LB::SP | LB::ZW
+            )
+        {
+            self.prev_zwj = lb == LB::ZWJ;
+            return None;
+        }
+
+        // rule LB10
+        let class = if is_combining(lb) { LB::AL } else { lb };
+
+        // rule LB2
+        let brk = if sot {
+            None
+        } else {
+            self.break_before(class, rest)
+        };
+
+        self.push(class, lb == LB::ZWJ);
+        brk
+    }
+
+    /// Update the context with the next character, which is not combined with the previous one.
+    fn push(&mut self, class: LineBreak, is_zwj: bool) {
+        self.prev_prev = self.prev;
+        self.prev = class;
+        self.prev_zwj = is_zwj;
+        if class != LB::SP {
+            self.before_sp = class;
+            self.after_zw = class == LB::ZW;
+        }
+        self.numeric = match (class, self.numeric) {
+            (LB::NU, _) => Numeric::Digits,
+            (LB::SY, Numeric::Digits) | (LB::IS, Numeric::Digits) => Numeric::Digits,
+            (LB::CL, Numeric::Digits) | (LB::CP, Numeric::Digits) => Numeric::Closed,
+            _ => Numeric::None,
+        };
+        self.ri_count = if class == LB::RI {
+            self.ri_count + 1
+        } else {
+            0
+        };
+    }
+
+    /// The break opportunity between the previous character and the next one, of resolved class
+    /// `class`, after rules LB9 and LB10.
+    fn break_before(&self, class: LineBreak, rest: &str) -> Option<BreakOpportunity> {
+        let prev = self.prev;
+
+        // rule LB4
+        if prev == LB::BK {
+            return Some(BreakOpportunity::Mandatory);
+        }
+
+        // rule LB5
+        if prev == LB::CR && class == LB::LF {
+            return None;
+        }
+        if matches!(prev, LB::CR | LB::LF | LB::NL) {
+            return Some(BreakOpportunity::Mandatory);
+        }
+
+        // rule LB6
+        if matches!(class, LB::BK | LB::CR | LB::LF | LB::NL) {
+            return None;
+        }
+
+        // rule LB7
+        if matches!(class, LB::SP | LB::ZW) {
+            return None;
+        }
+
+        // rule LB8
+        if self.after_zw {
+            return Some(BreakOpportunity::Allowed);
+        }
+
+        // rule LB8a
+        if self.prev_zwj && matches!(class, LB::ID | LB::EB | LB::EM) {
+            return None;
+        }
+
+        if self.allows_break(class, rest) {
+            Some(BreakOpportunity::Allowed)
+        } else {
+            None
+        }
+    }
+
+    /// Apply rules LB11 to LB31.
+    fn allows_break(&self, class: LineBreak, rest: &str) -> bool {
+        let prev = self.prev;
+
+        // rule LB11
+        if class == LB::WJ || prev == LB::WJ {
+            return false;
+        }
+
+        // rule LB12
+        if prev == LB::GL {
+            return false;
+        }
+
+        // rule LB12a
+        if class == LB::GL && !matches!(prev, LB::SP | LB::BA | LB::HY) {
+            return false;
+        }
+
+        // rule LB13
+        //
+        // With LB25 tailored, this only applies after non-`NU` characters, but the rules for
+        // numbers keep `NU` in front of these classes too.
+        if matches!(class, LB::CL | LB::CP | LB::EX | LB::IS | LB::SY) {
+            return false;
+        }
+
+        // rule LB14
+        if self.before_sp == LB::OP {
+            return false;
+        }
+
+        // rule LB15
+        if self.before_sp == LB::QU && class == LB::OP {
+            return false;
+        }
+
+        // rule LB16
+        if matches!(self.before_sp, LB::CL | LB::CP) && class == LB::NS {
+            return false;
+        }
+
+        // rule LB17
+        if self.before_sp == LB::B2 && class == LB::B2 {
+            return false;
+        }
+
+        // rule LB18
+        if prev == LB::SP {
+            return true;
+        }
+
+        // rule LB19
+        if class == LB::QU || prev == LB::QU {
+            return false;
+        }
+
+        // rule LB20
+        if class == LB::CB || prev == LB::CB {
+            return true;
+        }
+
+        // rule LB21
+        if matches!(class, LB::BA | LB::HY | LB::NS) || prev == LB::BB {
+            return false;
+        }
+
+        // rule LB21a
+        if self.prev_prev == LB::HL && matches!(prev, LB::HY | LB::BA) {
+            return false;
+        }
+
+        // rule LB21b
+        if prev == LB::SY && class == LB::HL {
+            return false;
+        }
+
+        // rule LB22
+        if class == LB::IN
+            && matches!(
+                prev,
+                LB::AL | LB::HL | LB::EX | LB::ID | LB::EB | LB::EM | LB::IN | LB::NU
+            )
+        {
+            return false;
+        }
+
+        // rule LB23
+        if (matches!(prev, LB::AL | LB::HL) && class == LB::NU)
+            || (prev == LB::NU && matches!(class, LB::AL | LB::HL))
+        {
+            return false;
+        }
+
+        // rule LB23a
+        if (prev == LB::PR && matches!(class, LB::ID | LB::EB | LB::EM))
+            || (matches!(prev, LB::ID | LB::EB | LB::EM) && class == LB::PO)
+        {
+            return false;
+        }
+
+        // rule LB24
+        if (matches!(prev, LB::PR | LB::PO) && matches!(class, LB::AL | LB::HL))
+            || (matches!(prev, LB::AL | LB::HL) && matches!(class, LB::PR | LB::PO))
+        {
+            return false;
+        }
+
+        // rule LB25, tailored as `(PR | PO)? (OP | HY)? NU (NU | SY | IS)* (CL | CP)? (PR | PO)?`
+        if matches!(prev, LB::PR | LB::PO) {
+            if class == LB::NU {
+                return false;
+            }
+            if matches!(class, LB::OP | LB::HY) && next_base_class(rest) == Some(LB::NU) {
+                return false;
+            }
+        }
+        if matches!(prev, LB::OP | LB::HY) && class == LB::NU {
+            return false;
+        }
+        if self.numeric == Numeric::Digits
+            && matches!(class, LB::NU | LB::SY | LB::IS | LB::CL | LB::CP)
+        {
+            return false;
+        }
+        if self.numeric != Numeric::None && matches!(class, LB::PO | LB::PR) {
+            return false;
+        }
+
+        // rule LB26
+        if (prev == LB::JL && matches!(class, LB::JL | LB::JV | LB::H2 | LB::H3))
+            || (matches!(prev, LB::JV | LB::H2) && matches!(class, LB::JV | LB::JT))
+            || (matches!(prev, LB::JT | LB::H3) && class == LB::JT)
+        {
+            return false;
+        }
+
+        // rule LB27
+        let is_korean = |lb| matches!(lb, LB::JL | LB::JV | LB::JT | LB::H2 | LB::H3);
+        if (is_korean(prev) && matches!(class, LB::IN | LB::PO))
+            || (prev == LB::PR && is_korean(class))
+        {
+            return false;
+        }
+
+        // rule LB28
+        if matches!(prev, LB::AL | LB::HL) && matches!(class, LB::AL | LB::HL) {
+            return false;
+        }
+
+        // rule LB29
+        if prev == LB::IS && matches!(class, LB::AL | LB::HL) {
+            return false;
+        }
+
+        // rule LB30
+        if (matches!(prev, LB::AL | LB::HL | LB::NU) && class == LB::OP)
+            || (prev == LB::CP && matches!(class, LB::AL | LB::HL | LB::NU))
+        {
+            return false;
+        }
+
+        // rule LB30a
+        if prev == LB::RI && class == LB::RI && self.ri_count % 2 == 1 {
+            return false;
+        }
+
+        // rule LB30b
+        if prev == LB::EB && class == LB::EM {
+            return false;
+        }
+
+        // rule LB31
+        true
+    }
+}
+
+/// Resolved class of the first character of `rest` that is not combining, which is the class of
+/// any combining sequence starting `rest` after a base character, as per rule LB9.
+fn next_base_class(rest: &str) -> Option<LineBreak> {
+    rest.chars().map(resolve).find(|&lb| !is_combining(lb))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BreakOpportunity, LineBreaks};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(LineBreaks::new("").next(), None);
+    }
+
+    #[test]
+    fn test_mandatory_breaks() {
+        assert_eq!(
+            LineBreaks::new("a\r\nb\u{85}c\u{2028}").collect::<Vec<_>>(),
+            &[
+                (3, BreakOpportunity::Mandatory),
+                (6, BreakOpportunity::Mandatory),
+                (10, BreakOpportunity::Mandatory),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_combining_marks() {
+        // A combining mark takes the class of its base, so `OP CM` does not break before `AL`.
+        assert_eq!(
+            LineBreaks::new("(\u{301}a b").collect::<Vec<_>>(),
+            &[
+                (5, BreakOpportunity::Allowed),
+                (6, BreakOpportunity::Mandatory)
+            ]
+        );
+
+        // After a space, a combining mark is treated as `AL`.
+        assert_eq!(
+            LineBreaks::new("a \u{301}").collect::<Vec<_>>(),
+            &[
+                (2, BreakOpportunity::Allowed),
+                (4, BreakOpportunity::Mandatory)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(
+            LineBreaks::new("$(12.5%) x").collect::<Vec<_>>(),
+            &[
+                (9, BreakOpportunity::Allowed),
+                (10, BreakOpportunity::Mandatory)
+            ]
+        );
+    }
+}
@@ -0,0 +1,447 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode Sentences of a string.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr29/#Sentence_Boundaries>
+
+use std::cmp;
+use std::iter::Filter;
+
+use unic_ucd_segment::SentenceBreak as SB;
+
+/// An iterator over the substrings of a string which, after splitting the string on [sentence
+/// boundaries](https://www.unicode.org/reports/tr29/#Sentence_Boundaries), pass the given filter.
+#[derive(Debug)]
+pub struct Sentences<'a> {
+    inner: Filter<SentenceBounds<'a>, fn(&&str) -> bool>,
+}
+
+impl<'a> Iterator for Sentences<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Sentences<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a> Sentences<'a> {
+    /// Create new iterator for *sentences*.
+    #[inline]
+    pub fn new(s: &str, filter: fn(&&str) -> bool) -> Sentences<'_> {
+        Sentences {
+            inner: SentenceBounds::new(s).filter(filter),
+        }
+    }
+}
+
+/// External iterator for a string's
+/// [sentence boundaries](https://www.unicode.org/reports/tr29/#Sentence_Boundaries).
+#[derive(Clone, Debug)]
+pub struct SentenceBounds<'a> {
+    // The whole source string is kept, as boundaries depend on the context on both sides.
+    string: &'a str,
+    start: usize,
+    end: usize,
+    // Boundaries found going backward and not yet reached, in order, all found in
+    // `back_scanned..end`.
+    back_bounds: Vec<usize>,
+    back_scanned: usize,
+}
+
+/// External iterator for sentence boundaries and byte offsets.
+#[derive(Clone, Debug)]
+pub struct SentenceBoundIndices<'a> {
+    start_offset: usize,
+    iter: SentenceBounds<'a>,
+}
+
+impl<'a> SentenceBoundIndices<'a> {
+    /// Create new iterator for *sentence boundries and their indices*.
+    #[inline]
+    pub fn new(s: &str) -> SentenceBoundIndices<'_> {
+        SentenceBoundIndices {
+            start_offset: s.as_ptr() as usize,
+            iter: SentenceBounds::new(s),
+        }
+    }
+
+    #[inline]
+    /// View the underlying data (the part yet to be iterated) as a slice of the original string.
+    ///
+    /// ```rust
+    /// # use unic_segment::SentenceBoundIndices;
+    /// let mut iter = SentenceBoundIndices::new("Hello world. Goodbye.");
+    /// assert_eq!(iter.as_str(), "Hello world. Goodbye.");
+    ///
+    /// iter.next();
+    /// assert_eq!(iter.as_str(), "Goodbye.");
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        self.iter.as_str()
+    }
+}
+
+impl<'a> Iterator for SentenceBoundIndices<'a> {
+    type Item = (usize, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        self.iter
+            .next()
+            .map(|s| (s.as_ptr() as usize - self.start_offset, s))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for SentenceBoundIndices<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, &'a str)> {
+        self.iter
+            .next_back()
+            .map(|s| (s.as_ptr() as usize - self.start_offset, s))
+    }
+}
+
+impl<'a> Iterator for SentenceBounds<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let slen = self.end - self.start;
+        (cmp::min(slen, 1), Some(slen))
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let mut state = SentenceBreakState::new();
+        let mut idx = self.start;
+        for ch in self.string[self.start..self.end].chars() {
+            let sb = SB::of(ch);
+            if idx != self.start && state.break_before(sb, &self.string[idx..]) {
+                break;
+            }
+            state.push(sb);
+            idx += ch.len_utf8();
+        }
+
+        let retstr = &self.string[self.start..idx];
+        self.start = idx;
+        Some(retstr)
+    }
+}
+
+impl<'a> DoubleEndedIterator for SentenceBounds<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let idx = self.prev_bound();
+        let retstr = &self.string[idx..self.end];
+        self.end = idx;
+        Some(retstr)
+    }
+}
+
+impl<'a> SentenceBounds<'a> {
+    /// Create new iterator for *sentence boundries*.
+    #[inline]
+    pub fn new(s: &str) -> SentenceBounds<'_> {
+        SentenceBounds {
+            string: s,
+            start: 0,
+            end: s.len(),
+            back_bounds: Vec::new(),
+            back_scanned: s.len(),
+        }
+    }
+
+    #[inline]
+    /// View the underlying data (the part yet to be iterated) as a slice of the original string.
+    ///
+    /// ```rust
+    /// # use unic_segment::SentenceBounds;
+    /// let mut iter = SentenceBounds::new("Hello world. Goodbye.");
+    /// assert_eq!(iter.as_str(), "Hello world. Goodbye.");
+    ///
+    /// iter.next();
+    /// assert_eq!(iter.as_str(), "Goodbye.");
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        &self.string[self.start..self.end]
+    }
+
+    /// The last sentence boundary before `end`, or `start` if there is none.
+    ///
+    /// The text is searched going forward from restart positions further and further back,
+    /// keeping all the boundaries found on the way, so that each part of the text is only
+    /// searched once.
+    fn prev_bound(&mut self) -> usize {
+        loop {
+            if let Some(idx) = self.back_bounds.pop() {
+                if idx > self.start {
+                    return idx;
+                }
+                self.back_bounds.clear();
+                return self.start;
+            }
+            if self.back_scanned <= self.start {
+                return self.start;
+            }
+
+            let (from, mut state) = self.restart_before(self.back_scanned);
+            let mut idx = from;
+            for ch in self.string[from..self.back_scanned].chars() {
+                let sb = SB::of(ch);
+                if idx > self.start && state.break_before(sb, &self.string[idx..]) {
+                    self.back_bounds.push(idx);
+                }
+                state.push(sb);
+                idx += ch.len_utf8();
+            }
+            self.back_scanned = from;
+        }
+    }
+
+    /// The last position before `to` where the context of the sentence break rules is known,
+    /// with that context: after a character that does not take part in the rules with `SATerm`
+    /// and is not skipped by rule SB5, or else `start`.
+    fn restart_before(&self, to: usize) -> (usize, SentenceBreakState) {
+        let mut chars = self.string[self.start..to].char_indices().rev();
+        chars.next();
+        for (idx, ch) in chars {
+            let sb = SB::of(ch);
+            match sb {
+                SB::Extend | SB::Format | SB::Sp | SB::Close | SB::ATerm | SB::STerm => {}
+                _ => {
+                    let mut state = SentenceBreakState::new();
+                    state.push(sb);
+                    return (self.start + idx + ch.len_utf8(), state);
+                }
+            }
+        }
+        (self.start, SentenceBreakState::new())
+    }
+}
+
+#[inline]
+fn is_para_sep(sb: SB) -> bool {
+    matches!(sb, SB::Sep | SB::CR | SB::LF)
+}
+
+/// The context kept while going forward through the text, one character at a time.
+///
+/// No context before a sentence boundary is needed after it, so the state at a boundary is the
+/// same as at the start of the text. `Extend` never remains after rule SB5, so it is used as the
+/// value before the first character, and after a paragraph separator.
+#[derive(Clone, Copy, Debug)]
+struct SentenceBreakState {
+    /// Value of the previous character, for rules SB3 and SB4.
+    raw_prev: SB,
+
+    /// Value of the previous character after rule SB5.
+    prev: SB,
+
+    /// Value before `prev` after rule SB5, for rule SB7.
+    prev_prev: SB,
+
+    /// `ATerm` or `STerm` if the text so far ends with `SATerm Close* Sp*`.
+    term: Option<SB>,
+
+    /// Whether `term` is followed by `Sp`, for rule SB9.
+    has_sp: bool,
+}
+
+impl SentenceBreakState {
+    fn new() -> SentenceBreakState {
+        SentenceBreakState {
+            raw_prev: SB::Extend,
+            prev: SB::Extend,
+            prev_prev: SB::Extend,
+            term: None,
+            has_sp: false,
+        }
+    }
+
+    /// Update the context with the next character.
+    fn push(&mut self, sb: SB) {
+        // A paragraph separator ends the sentence, so the context starts over.
+        if is_para_sep(sb) {
+            *self = SentenceBreakState {
+                raw_prev: sb,
+                ..SentenceBreakState::new()
+            };
+            return;
+        }
+
+        self.raw_prev = sb;
+
+        // rule SB5
+        if sb == SB::Extend || sb == SB::Format {
+            return;
+        }
+
+        self.prev_prev = self.prev;
+        self.prev = sb;
+        match sb {
+            SB::ATerm | SB::STerm => {
+                self.term = Some(sb);
+                self.has_sp = false;
+            }
+            SB::Close if self.term.is_some() && !self.has_sp => {}
+            SB::Sp if self.term.is_some() => self.has_sp = true,
+            _ => self.term = None,
+        }
+    }
+
+    /// Whether there is a sentence boundary between the previous character and the next one, of
+    /// value `next`. `rest` is the text from the next character on.
+    fn break_before(&self, next: SB, rest: &str) -> bool {
+        // rule SB3
+        if self.raw_prev == SB::CR && next == SB::LF {
+            return false;
+        }
+
+        // rule SB4
+        if is_para_sep(self.raw_prev) {
+            return true;
+        }
+
+        // rule SB5
+        if next == SB::Extend || next == SB::Format {
+            return false;
+        }
+
+        // rule SB6
+        if self.prev == SB::ATerm && next == SB::Numeric {
+            return false;
+        }
+
+        // rule SB7
+        if self.prev == SB::ATerm
+            && next == SB::Upper
+            && matches!(self.prev_prev, SB::Upper | SB::Lower)
+        {
+            return false;
+        }
+
+        let term = match self.term {
+            Some(term) => term,
+            None => return false, // rule SB998
+        };
+
+        match next {
+            // rule SB8a
+            SB::SContinue | SB::STerm | SB::ATerm => return false,
+            // rule SB9
+            SB::Close if !self.has_sp => return false,
+            // rule SB10
+            SB::Sp | SB::Sep | SB::CR | SB::LF => return false,
+            _ => {}
+        }
+
+        // rule SB8
+        //
+        // Only checked when there would be a break otherwise: the context is reset by the next
+        // character, so the text ahead is searched once for each `ATerm Close* Sp*`.
+        if term == SB::ATerm {
+            let ahead = rest.chars().map(SB::of).find(|&sb| match sb {
+                SB::OLetter | SB::Upper | SB::Lower | SB::STerm | SB::ATerm => true,
+                _ => is_para_sep(sb),
+            });
+            if ahead == Some(SB::Lower) {
+                return false;
+            }
+        }
+
+        // rule SB11
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SentenceBounds, Sentences};
+    use unic_ucd_common::is_alphanumeric;
+
+    #[test]
+    fn test_sentence_bounds() {
+        assert_eq!(
+            SentenceBounds::new("Mr. Fox jumped. [...] The dog was too lazy.")
+                .collect::<Vec<&str>>(),
+            &["Mr. ", "Fox jumped. ", "[...] ", "The dog was too lazy."]
+        );
+        assert_eq!(
+            SentenceBounds::new("He said \"e.g. this.\" Then left.\r\nNext").collect::<Vec<&str>>(),
+            &["He said \"e.g. this.\" ", "Then left.\r\n", "Next"]
+        );
+    }
+
+    #[test]
+    fn test_long_runs() {
+        // Each position is only looked at a bounded number of times, so these take linear time,
+        // where scanning the runs again for each position used to take minutes.
+        const N: usize = 100_000;
+        for &(term, run, next) in &[
+            (".", " ", "x"),
+            (".", ")", "X"),
+            ("!", " ", "a"),
+            (".", ".", " "),
+            ("a", "\u{300}", "."),
+        ] {
+            let input = format!("a{}{}{}", term, run.repeat(N), next);
+            let forward = SentenceBounds::new(&input).collect::<Vec<&str>>();
+            let mut reverse = SentenceBounds::new(&input).rev().collect::<Vec<&str>>();
+            reverse.reverse();
+            assert_eq!(forward, reverse);
+            assert_eq!(forward.concat(), input);
+        }
+
+        // Many sentences in one run of terminators, closing punctuation and spaces
+        let input = ". )".repeat(N);
+        let forward = SentenceBounds::new(&input).collect::<Vec<&str>>();
+        let mut reverse = SentenceBounds::new(&input).rev().collect::<Vec<&str>>();
+        reverse.reverse();
+        assert_eq!(forward.len(), N + 1);
+        assert_eq!(forward, reverse);
+    }
+
+    #[test]
+    fn test_sentences() {
+        assert_eq!(
+            Sentences::new(
+                "Hello! How are you?\n\n...\nFine.",
+                |s: &&str| s.chars().any(is_alphanumeric),
+            )
+            .collect::<Vec<&str>>(),
+            &["Hello! ", "How are you?\n", "Fine."]
+        );
+    }
+}
@@ -18,6 +18,7 @@
 use std::cmp;
 use std::iter::Filter;
 
+use unic_emoji_char::is_extended_pictographic;
 use unic_ucd_segment::WordBreak as WB;
 
 /// An iterator over the substrings of a string which, after splitting the string on [word
@@ -193,7 +194,7 @@ impl<'a> Iterator for WordBounds<'a> {
             prev_zwj = cat == WB::ZWJ;
             // if there's a category cached, grab it
             cat = match self.cat {
-                None => WB::of(ch),
+                None => word_break(ch),
                 _ => self.cat.take().unwrap(),
             };
             take_cat = true;
@@ -217,27 +218,36 @@ impl<'a> Iterator for WordBounds<'a> {
             // WB4 makes all ZWJs collapse into the previous state
             // but you can still be in a Zwj state if you started with Zwj
             //
-            // This means that Zwj + Extend will collapse into Zwj, which is wrong,
-            // since Extend has a boundary with following EBG/GAZ chars but ZWJ doesn't,
-            // and that rule (WB3c) has higher priority
-            //
-            // Additionally, Emoji_Base+ZWJ+(EBG/GAZ) will collapse into Emoji_Base+EBG/GAZ
-            // which won't have a boundary even though EB+ZWJ+GAZ should have a boundary.
+            // This means that an EP + Zwj will collapse into EP, which is wrong,
+            // since EP+EP is not a boundary but EP+ZWJ+EP is
             //
             // Thus, we separately keep track of whether or not the last character
             // was a ZWJ. This is an additional bit of state tracked outside of the
             // state enum; the state enum represents the last non-zwj state encountered.
             // When prev_zwj is true, for the purposes of WB3c, we are in the Zwj state,
             // however we are in the previous state for the purposes of all other rules.
-            if prev_zwj {
-                match cat {
-                    WB::GlueAfterZwj => continue,
-                    WB::EBaseGAZ => {
-                        state = Emoji;
-                        continue;
-                    }
-                    _ => (),
-                }
+            //
+            // An `Extended_Pictographic` character can also be a letter, which then goes on with
+            // the rules for letters and numbers, unless it has to complete a `MidLetter` or
+            // `MidNum` sequence, which is then checked as usual.
+            if prev_zwj
+                && is_extended_pictographic(ch)
+                && !matches!(
+                    state,
+                    FormatExtend(RequireLetter)
+                        | FormatExtend(RequireHLetter)
+                        | FormatExtend(RequireNumeric)
+                )
+            {
+                state = match cat {
+                    WB::ALetter => Letter,
+                    WB::HebrewLetter => HLetter,
+                    WB::Numeric => Numeric,
+                    WB::Katakana => Katakana,
+                    WB::ExtendNumLet => ExtendNumLet,
+                    _ => Emoji,
+                };
+                continue;
             }
             // Don't use `continue` in this match without updating `cat`
             state = match state {
@@ -257,7 +267,6 @@ impl<'a> Iterator for WordBounds<'a> {
                     WB::RegionalIndicator => Regional(RegionalState::Half), // rule WB13c
                     WB::LF | WB::Newline => break,    // rule WB3a
                     WB::ZWJ => Zwj,                   // rule WB3c
-                    WB::EBase | WB::EBaseGAZ => Emoji, // rule WB14
                     _ => {
                         if let Some(ncat) = self.get_next_cat(idx) {
                             // rule WB4
@@ -271,9 +280,7 @@ impl<'a> Iterator for WordBounds<'a> {
                     }
                 },
                 Zwj => {
-                    // We already handle WB3c above. At this point,
-                    // the current category is not GAZ or EBG,
-                    // or the previous character was not actually a ZWJ
+                    // We already handle WB3c above.
                     take_curr = false;
                     break;
                 }
@@ -351,14 +358,12 @@ impl<'a> Iterator for WordBounds<'a> {
                 Regional(_) => {
                     unreachable!("RegionalState::Unknown should not occur on forward iteration")
                 }
-                Emoji => match cat {
-                    // rule WB14
-                    WB::EModifier => state,
-                    _ => {
-                        take_curr = false;
-                        break;
-                    }
-                },
+                Emoji => {
+                    // We already handle WB3c above. If you've reached this point, the emoji
+                    // sequence is over.
+                    take_curr = false;
+                    break;
+                }
                 FormatExtend(t) => match t {
                     // handle FormatExtends depending on what type
                     RequireNumeric if cat == WB::Numeric => Numeric, // rule WB11
@@ -418,6 +423,9 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
         let mut state = Start;
         let mut savestate = Start;
         let mut cat = WB::Other;
+        // Whether the last character taken is an `Extended_Pictographic` letter or number after a
+        // ZWJ, which joins it to the character before the ZWJ (rule WB3c)
+        let mut join_zwj = false;
 
         for (curr, ch) in self.string.char_indices().rev() {
             previdx = idx;
@@ -425,7 +433,7 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
 
             // if there's a category cached, grab it
             cat = match self.catb {
-                None => WB::of(ch),
+                None => word_break(ch),
                 _ => self.catb.take().unwrap(),
             };
             take_cat = true;
@@ -456,6 +464,39 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                 state = savestate;
                 previdx = saveidx;
                 take_cat = false;
+
+                if join_zwj {
+                    // rule WB3c
+                    // The character before the ZWJ is taken whatever the state, but can still
+                    // start a `MidLetter` or `MidNum` sequence, which must not be split from it.
+                    join_zwj = false;
+                    previdx = idx + ch.len_utf8();
+                    state = match (state, cat) {
+                        (Letter, WB::MidLetter)
+                        | (Letter, WB::MidNumLet)
+                        | (Letter, WB::SingleQuote)
+                        | (HLetter, WB::MidLetter)
+                        | (HLetter, WB::MidNumLet)
+                        | (HLetter, WB::SingleQuote) => {
+                            saveidx = idx;
+                            FormatExtend(RequireLetter)
+                        }
+                        (HLetter, WB::DoubleQuote) => {
+                            saveidx = idx;
+                            FormatExtend(RequireHLetter)
+                        }
+                        (Numeric, WB::MidNum)
+                        | (Numeric, WB::MidNumLet)
+                        | (Numeric, WB::SingleQuote) => {
+                            saveidx = idx;
+                            FormatExtend(RequireNumeric)
+                        }
+                        _ => FormatExtend(AcceptAny),
+                    };
+                    if state != FormatExtend(AcceptAny) {
+                        continue;
+                    }
+                }
             }
 
             // Don't use `continue` in this match without updating `catb`
@@ -466,15 +507,19 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                     WB::Numeric => Numeric,           // rule WB8, WB9, WB11, WB13b
                     WB::Katakana => Katakana,         // rule WB13, WB13b
                     WB::ExtendNumLet => ExtendNumLet, // rule WB13a
+                    // rule WB3c
+                    _ if is_extended_pictographic(ch)
+                        && self.get_prev_cat(idx) == Some(WB::ZWJ) =>
+                    {
+                        Zwj
+                    }
                     WB::RegionalIndicator => Regional(RegionalState::Unknown), // rule WB13c
-                    WB::GlueAfterZwj | WB::EBaseGAZ => Zwj, // rule WB3c
                     // rule WB4:
                     WB::Extend | WB::Format | WB::ZWJ => FormatExtend(AcceptAny),
                     WB::SingleQuote => {
                         saveidx = idx;
                         FormatExtend(AcceptQLetter) // rule WB7a
                     }
-                    WB::EModifier => Emoji, // rule WB14
                     WB::CR | WB::LF | WB::Newline => {
                         if state == Start {
                             if cat == WB::LF {
@@ -556,7 +601,7 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                             let count = self.string[..previdx]
                                 .chars()
                                 .rev()
-                                .map(WB::of)
+                                .map(word_break)
                                 .filter(|&c| !(c == WB::ZWJ || c == WB::Extend || c == WB::Format))
                                 .take_while(|&c| c == WB::RegionalIndicator)
                                 .count();
@@ -578,14 +623,15 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                         break;
                     }
                 },
-                Emoji => match cat {
-                    // rule WB14
-                    WB::EBase | WB::EBaseGAZ => Zwj,
-                    _ => {
+                Emoji => {
+                    // rule WB3c
+                    if is_extended_pictographic(ch) {
+                        Zwj
+                    } else {
                         take_curr = false;
                         break;
                     }
-                },
+                }
                 FormatExtend(t) => match t {
                     RequireNumeric if cat == WB::Numeric => Numeric, // rule WB12
                     RequireLetter if cat == WB::ALetter => Letter,   // rule WB6
@@ -594,15 +640,20 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                     RequireHLetter if cat == WB::HebrewLetter => HLetter, // rule WB7b
                     _ => break,                                      // backtrack will happens
                 },
-            }
+            };
+
+            join_zwj = is_extended_pictographic(ch)
+                && matches!(state, Letter | HLetter | Numeric | Katakana | ExtendNumLet)
+                && self.get_prev_cat(idx) == Some(WB::ZWJ);
         }
 
         if let FormatExtend(t) = state {
-            // if we required something but didn't find it, backtrack
+            // if we required something but didn't find it, backtrack; a ZWJ joined to the
+            // character after it at the start of the text is kept (rule WB3c)
             if t == RequireLetter
                 || t == RequireHLetter
                 || t == RequireNumeric
-                || t == AcceptNone
+                || (t == AcceptNone && !join_zwj)
                 || t == AcceptQLetter
             {
                 previdx = saveidx;
@@ -662,7 +713,7 @@ impl<'a> WordBounds<'a> {
         let nidx = idx + self.string[idx..].chars().next().unwrap().len_utf8();
         if nidx < self.string.len() {
             let nch = self.string[nidx..].chars().next().unwrap();
-            Some(WB::of(nch))
+            Some(word_break(nch))
         } else {
             None
         }
@@ -672,13 +723,24 @@ impl<'a> WordBounds<'a> {
     fn get_prev_cat(&self, idx: usize) -> Option<WB> {
         if idx > 0 {
             let nch = self.string[..idx].chars().next_back().unwrap();
-            Some(WB::of(nch))
+            Some(word_break(nch))
         } else {
             None
         }
     }
 }
 
+// The `Word_Break` value of a character for the current rules, with `E_Modifier` characters as
+// `Extend`, and the other emoji values as `Other`; emoji zwj sequences are handled with
+// `Extended_Pictographic` instead (WB3c).
+fn word_break(ch: char) -> WB {
+    match WB::of(ch) {
+        WB::EModifier => WB::Extend,
+        WB::EBase | WB::EBaseGAZ | WB::GlueAfterZwj => WB::Other,
+        wb => wb,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{WordBounds, Words};
@@ -9,7 +9,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use unic_segment::{GraphemeIndices, Graphemes, WordBoundIndices, WordBounds, Words};
+use unic_segment::{
+    GraphemeIndices,
+    Graphemes,
+    SentenceBoundIndices,
+    SentenceBounds,
+    Sentences,
+    WordBoundIndices,
+    WordBounds,
+    Words,
+};
 use unic_ucd_common::is_alphanumeric;
 
 #[test]
@@ -57,4 +66,23 @@ fn test_all() {
             (17, "!")
         ]
     );
+
+    assert_eq!(
+        Sentences::new(
+            "Mr. Fox jumped. [...] The dog was too lazy.",
+            |s: &&str| s.chars().any(is_alphanumeric),
+        )
+        .collect::<Vec<&str>>(),
+        &["Mr. ", "Fox jumped. ", "The dog was too lazy."]
+    );
+
+    assert_eq!(
+        SentenceBounds::new("Hi! Who's there?\nMe.").collect::<Vec<&str>>(),
+        &["Hi! ", "Who's there?\n", "Me."]
+    );
+
+    assert_eq!(
+        SentenceBoundIndices::new("Hi! Who's there?\nMe.").collect::<Vec<(usize, &str)>>(),
+        &[(0, "Hi! "), (4, "Who's there?\n"), (17, "Me.")]
+    );
 }
@@ -19,12 +19,23 @@ type TestData = &'static [(
 
 const TEST_DATA: TestData = include!("tables/grapheme_cluster_break_test_data.rsv");
 
-/// Extra cases that the official test suite doesn't cover.
+/// Extra cases that the official test suite doesn't cover, or that supersede its cases.
 const EXTRA_TEST_DATA: TestData = include!("extra_grapheme_cluster_break_test_data.rsv");
 
+/// Whether a case of the official test suite is superseded by an extra case with the same input,
+/// giving its segmentation under the current rules.
+fn is_superseded(input: &str) -> bool {
+    EXTRA_TEST_DATA
+        .iter()
+        .any(|&(extra_input, ..)| extra_input == input)
+}
+
 #[test]
 fn test_graphemes_conformance() {
-    let tests = TEST_DATA.iter().chain(EXTRA_TEST_DATA);
+    let tests = TEST_DATA
+        .iter()
+        .filter(|&&(input, ..)| !is_superseded(input))
+        .chain(EXTRA_TEST_DATA);
     for &(input, graphemes, legacy_graphemes) in tests {
         let legacy_graphemes = match legacy_graphemes {
             Some(s) => s,
@@ -0,0 +1,51 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_segment::{BreakOpportunity, LineBreaks};
+use unic_ucd_segment::line_break::abbr_names as LB;
+use unic_ucd_segment::LineBreak;
+
+type TestData = &'static [(&'static str, &'static [&'static str])];
+
+const TEST_DATA: TestData = include!("tables/line_break_test_data.rsv");
+
+#[test]
+fn test_line_breaks_conformance() {
+    for &(input, lines) in TEST_DATA {
+        // generate breaks from line strings: a line ending with a newline character ends with a
+        // mandatory break (rules LB4 and LB5), as does the last line (rule LB3)
+        let breaks = lines
+            .iter()
+            .enumerate()
+            .scan(0, |t, (i, s)| {
+                *t += s.len();
+                let last = s.chars().next_back().map(LineBreak::of);
+                let brk = if i == lines.len() - 1
+                    || matches!(
+                        last,
+                        Some(LB::BK) | Some(LB::CR) | Some(LB::LF) | Some(LB::NL)
+                    ) {
+                    BreakOpportunity::Mandatory
+                } else {
+                    BreakOpportunity::Allowed
+                };
+                Some((*t, brk))
+            })
+            .collect::<Vec<_>>();
+
+        // collect into vector for better diagnostics in failure case
+        let testing = LineBreaks::new(input).collect::<Vec<_>>();
+        assert_eq!(
+            testing, breaks,
+            "Line break test for testcase ({:?}, {:?}) failed.",
+            input, lines
+        );
+    }
+}
@@ -12,7 +12,7 @@
 #[macro_use]
 extern crate quickcheck;
 
-use unic_segment::{Graphemes, WordBounds};
+use unic_segment::{BreakOpportunity, Graphemes, LineBreaks, SentenceBounds, WordBounds};
 
 // QuickCheck Graphemes
 quickcheck! {
@@ -55,3 +55,39 @@ quickcheck! {
         words1 == words2
     }
 }
+
+// QuickCheck Sentences
+quickcheck! {
+    fn quickcheck_sentences_new_join_vs_input(input: String) -> bool {
+        let sentences = SentenceBounds::new(&input).collect::<String>();
+        sentences == input
+    }
+
+    fn quickcheck_sentences_new_forward_vs_reverse(input: String) -> bool {
+        let sentences1 = SentenceBounds::new(&input).collect::<Vec<_>>();
+        let mut sentences2 = SentenceBounds::new(&input).rev().collect::<Vec<_>>();
+        sentences2.reverse();
+        sentences1 == sentences2
+    }
+}
+
+// QuickCheck Line Breaks
+quickcheck! {
+    fn quickcheck_line_breaks_new_ascending_boundaries(input: String) -> bool {
+        let mut last = 0;
+        LineBreaks::new(&input).all(|(idx, _)| {
+            let ascending = idx > last && input.is_char_boundary(idx);
+            last = idx;
+            ascending
+        })
+    }
+
+    fn quickcheck_line_breaks_new_ends_with_mandatory(input: String) -> bool {
+        let last = LineBreaks::new(&input).last();
+        if input.is_empty() {
+            last.is_none()
+        } else {
+            last == Some((input.len(), BreakOpportunity::Mandatory))
+        }
+    }
+}
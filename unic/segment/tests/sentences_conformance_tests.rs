@@ -0,0 +1,78 @@
+// Copyright 2012-2015 The Rust Project Developers.
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_segment::{SentenceBoundIndices, SentenceBounds};
+
+type TestData = &'static [(&'static str, &'static [&'static str])];
+
+const TEST_DATA: TestData = include!("tables/sentence_break_test_data.rsv");
+
+/// Extra cases that the official test suite doesn't cover.
+const EXTRA_TEST_DATA: TestData = include!("extra_sentence_break_test_data.rsv");
+
+#[test]
+fn test_sentences_conformance() {
+    let tests = TEST_DATA.iter().chain(EXTRA_TEST_DATA);
+
+    for &(input, sentences) in tests {
+        macro_rules! assert_ {
+            ($test:expr, $exp:expr, $name:expr) => {
+                // collect into vector for better diagnostics in failure case
+                let testing = $test.collect::<Vec<_>>();
+                let expected = $exp.collect::<Vec<_>>();
+                assert_eq!(
+                    testing, expected,
+                    "{} test for testcase ({:?}, {:?}) failed.",
+                    $name, input, sentences
+                )
+            };
+        }
+
+        // test forward sentence boundaries
+        assert_!(
+            SentenceBounds::new(input),
+            sentences.iter().cloned(),
+            "Forward sentence boundaries"
+        );
+
+        // test reverse sentence boundaries
+        assert_!(
+            SentenceBounds::new(input).rev(),
+            sentences.iter().rev().cloned(),
+            "Reverse sentence boundaries"
+        );
+
+        // generate offsets from sentence string lengths
+        let mut indices = vec![0];
+        for i in sentences.iter().cloned().map(|s| s.len()).scan(0, |t, n| {
+            *t += n;
+            Some(*t)
+        }) {
+            indices.push(i);
+        }
+        indices.pop();
+        let indices = indices;
+
+        // test forward indices iterator
+        assert_!(
+            SentenceBoundIndices::new(input).map(|(l, _)| l),
+            indices.iter().cloned(),
+            "Forward sentence indices"
+        );
+
+        // test backward indices iterator
+        assert_!(
+            SentenceBoundIndices::new(input).rev().map(|(l, _)| l),
+            indices.iter().rev().cloned(),
+            "Reverse sentence indices"
+        );
+    }
+}
@@ -15,12 +15,23 @@ type TestData = &'static [(&'static str, &'static [&'static str])];
 
 const TEST_DATA: TestData = include!("tables/word_break_test_data.rsv");
 
-/// Extra cases that the official test suite doesn't cover.
+/// Extra cases that the official test suite doesn't cover, or that supersede its cases.
 const EXTRA_TEST_DATA: TestData = include!("extra_word_break_test_data.rsv");
 
+/// Whether a case of the official test suite is superseded by an extra case with the same input,
+/// giving its segmentation under the current rules.
+fn is_superseded(input: &str) -> bool {
+    EXTRA_TEST_DATA
+        .iter()
+        .any(|&(extra_input, _)| extra_input == input)
+}
+
 #[test]
 fn test_words_conformance() {
-    let tests = TEST_DATA.iter().chain(EXTRA_TEST_DATA);
+    let tests = TEST_DATA
+        .iter()
+        .filter(|&&(input, _)| !is_superseded(input))
+        .chain(EXTRA_TEST_DATA);
 
     for &(input, words) in tests {
         macro_rules! assert_ {
@@ -31,12 +31,20 @@
 //!
 //! -   [`bidi`](/unic-bidi): Unicode Bidirectional Algorithm (UAX\#9).
 //!
+//! -   [`case`](/unic-case): Unicode Default Case Conversion.
+//!
 //! -   [`normal`](/unic-normal): Unicode Normalization Forms (UAX\#15).
 //!
 //! -   [`segment`](/unic-segment): Unicode Text Segmentation (UAX\#29).
 //!
+//! -   [`ident`](/unic-ident): Unicode Identifier and Pattern Syntax (UAX\#31).
+//!
 //! -   [`idna`](/unic-idna): Unicode IDNA Compatibility Processing (UTS\#46).
 //!
+//! -   [`security`](/unic-security): Unicode Security Mechanisms (UTS\#39).
+//!
+//! -   [`width`](/unic-width): Unicode Display Width (UAX\#11).
+//!
 //!
 //! ## A Basic Example
 //!
@@ -172,12 +180,16 @@
 //! ```
 
 pub use unic_bidi as bidi;
+pub use unic_case as case;
 pub use unic_char as char;
 pub use unic_emoji as emoji;
+pub use unic_ident as ident;
 pub use unic_idna as idna;
 pub use unic_normal as normal;
+pub use unic_security as security;
 pub use unic_segment as segment;
 pub use unic_ucd as ucd;
+pub use unic_width as width;
 
 /// The [Unicode version](https://www.unicode.org/versions/) of data
 pub use crate::ucd::UNICODE_VERSION;
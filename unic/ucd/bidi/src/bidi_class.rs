@@ -11,7 +11,7 @@
 
 //! Unicode `Bidi_Class` Character Property.
 
-use unic_char_property::TotalCharProperty;
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
 
 char_property! {
     /// Represents the Unicode character
@@ -212,6 +212,14 @@ impl TotalCharProperty for BidiClass {
     }
 }
 
+impl TableCharProperty for BidiClass {
+    type TableValue = BidiClass;
+
+    fn table() -> CharDataTable<BidiClass> {
+        data::BIDI_CLASS_TABLE
+    }
+}
+
 /// UCD/extracted/DerivedBidiClass.txt:
 /// "All code points not explicitly listed for `Bidi_Class` have the value `Left_To_Right` (`L`)."
 impl Default for BidiClass {
@@ -0,0 +1,80 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Bidi_Mirroring_Glyph` Character Property.
+
+mod data {
+    use unic_char_property::tables::CharDataTable;
+    pub const BIDI_MIRRORING_GLYPH_TABLE: CharDataTable<char> =
+        include!("../tables/bidi_mirroring_glyph.rsv");
+}
+
+/// Return the
+/// [`Bidi_Mirroring_Glyph`](https://www.unicode.org/reports/tr44/#Bidi_Mirroring_Glyph) of the
+/// character: a character whose glyph is typically a mirror image of the glyph of this
+/// character, if there is one.
+///
+/// Some characters with the `Bidi_Mirrored` property have no such counterpart, and need a
+/// mirrored glyph from the font instead.
+///
+/// ```rust
+/// # use unic_ucd_bidi::bidi_mirroring_glyph;
+/// assert_eq!(bidi_mirroring_glyph('('), Some(')'));
+/// assert_eq!(bidi_mirroring_glyph('\u{2264}'), Some('\u{2265}'));
+/// assert_eq!(bidi_mirroring_glyph('\u{221B}'), None);
+/// assert_eq!(bidi_mirroring_glyph('a'), None);
+/// ```
+pub fn bidi_mirroring_glyph(ch: char) -> Option<char> {
+    data::BIDI_MIRRORING_GLYPH_TABLE.find(ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bidi_mirroring_glyph, data};
+    use crate::is_bidi_mirrored;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(bidi_mirroring_glyph('\u{0028}'), Some('\u{0029}'));
+        assert_eq!(bidi_mirroring_glyph('\u{0029}'), Some('\u{0028}'));
+        assert_eq!(bidi_mirroring_glyph('\u{003C}'), Some('\u{003E}'));
+        assert_eq!(bidi_mirroring_glyph('\u{00AB}'), Some('\u{00BB}'));
+        assert_eq!(bidi_mirroring_glyph('\u{2039}'), Some('\u{203A}'));
+        assert_eq!(bidi_mirroring_glyph('\u{2208}'), Some('\u{220B}'));
+        assert_eq!(bidi_mirroring_glyph('\u{FF62}'), Some('\u{FF63}'));
+
+        assert_eq!(bidi_mirroring_glyph('\u{0000}'), None);
+        assert_eq!(bidi_mirroring_glyph('\u{0041}'), None);
+        assert_eq!(bidi_mirroring_glyph('\u{10FFFF}'), None);
+    }
+
+    #[test]
+    fn test_mirrored() {
+        // Every character with a mirroring glyph is mirrored, but not the other way around.
+        for (range, _) in data::BIDI_MIRRORING_GLYPH_TABLE.iter() {
+            for ch in range {
+                assert!(is_bidi_mirrored(ch), "{:?}", ch);
+            }
+        }
+        assert!(is_bidi_mirrored('\u{221B}'));
+        assert!(bidi_mirroring_glyph('\u{221B}').is_none());
+    }
+
+    #[test]
+    fn test_pairs() {
+        // Mirroring glyphs come in pairs.
+        for (range, glyph) in data::BIDI_MIRRORING_GLYPH_TABLE.iter() {
+            for ch in range {
+                assert_ne!(glyph, ch);
+                assert_eq!(bidi_mirroring_glyph(glyph), Some(ch), "{:?}", ch);
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Bidi_Paired_Bracket` Character Property.
+
+mod data {
+    use unic_char_property::tables::CharDataTable;
+    pub const BIDI_PAIRED_BRACKET_TABLE: CharDataTable<char> =
+        include!("../tables/bidi_paired_bracket.rsv");
+}
+
+/// Return the
+/// [`Bidi_Paired_Bracket`](https://www.unicode.org/reports/tr44/#Bidi_Paired_Bracket) of the
+/// character: the opening or closing bracket it pairs with, if it is a paired bracket.
+///
+/// ```rust
+/// # use unic_ucd_bidi::bidi_paired_bracket;
+/// assert_eq!(bidi_paired_bracket('('), Some(')'));
+/// assert_eq!(bidi_paired_bracket(']'), Some('['));
+/// assert_eq!(bidi_paired_bracket('<'), None);
+/// ```
+pub fn bidi_paired_bracket(ch: char) -> Option<char> {
+    data::BIDI_PAIRED_BRACKET_TABLE.find(ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bidi_paired_bracket;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(bidi_paired_bracket('\u{0028}'), Some('\u{0029}'));
+        assert_eq!(bidi_paired_bracket('\u{0029}'), Some('\u{0028}'));
+        assert_eq!(bidi_paired_bracket('\u{007B}'), Some('\u{007D}'));
+        assert_eq!(bidi_paired_bracket('\u{0F3A}'), Some('\u{0F3B}'));
+        assert_eq!(bidi_paired_bracket('\u{2329}'), Some('\u{232A}'));
+        assert_eq!(bidi_paired_bracket('\u{3008}'), Some('\u{3009}'));
+        assert_eq!(bidi_paired_bracket('\u{FF62}'), Some('\u{FF63}'));
+
+        assert_eq!(bidi_paired_bracket('\u{0000}'), None);
+        assert_eq!(bidi_paired_bracket('\u{003C}'), None);
+        assert_eq!(bidi_paired_bracket('\u{00AB}'), None);
+        assert_eq!(bidi_paired_bracket('\u{10FFFF}'), None);
+    }
+
+    #[test]
+    fn test_pairs_are_symmetric() {
+        for &ch in &[
+            '(', ')', '[', ']', '\u{2045}', '\u{2046}', '\u{FE59}', '\u{FE5A}',
+        ] {
+            let pair = bidi_paired_bracket(ch).unwrap();
+            assert_eq!(bidi_paired_bracket(pair), Some(ch));
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Bidi_Paired_Bracket_Type` Character Property.
+
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`Bidi_Paired_Bracket_Type`](https://www.unicode.org/reports/tr44/#Bidi_Paired_Bracket_Type)
+    /// property.
+    ///
+    /// * <https://www.unicode.org/reports/tr9/#Paired_Brackets>
+    /// * <https://www.unicode.org/Public/UCD/latest/ucd/BidiBrackets.txt>
+    pub enum BidiPairedBracketType {
+        abbr => "bpt";
+        long => "Bidi_Paired_Bracket_Type";
+        human => "Bidi Paired Bracket Type";
+
+        /// An opening paired bracket
+        Open {
+            abbr => o,
+            long => Open,
+            human => "Open",
+        }
+
+        /// A closing paired bracket
+        Close {
+            abbr => c,
+            long => Close,
+            human => "Close",
+        }
+
+        /// Not a paired bracket
+        None {
+            abbr => n,
+            long => None,
+            human => "None",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Bidi_Paired_Bracket_Type`](https://www.unicode.org/reports/tr44/#Bidi_Paired_Bracket_Type)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Bidi_Paired_Bracket_Type>
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Bidi_Paired_Bracket_Type`](https://www.unicode.org/reports/tr44/#Bidi_Paired_Bracket_Type)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Bidi_Paired_Bracket_Type>
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for BidiPairedBracketType {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+impl TableCharProperty for BidiPairedBracketType {
+    type TableValue = BidiPairedBracketType;
+
+    fn table() -> CharDataTable<BidiPairedBracketType> {
+        data::BIDI_PAIRED_BRACKET_TYPE_TABLE
+    }
+}
+
+/// UCD/BidiBrackets.txt:
+/// "For all code points not explicitly listed, `Bidi_Paired_Bracket_Type` has the value `None`."
+impl Default for BidiPairedBracketType {
+    #[inline]
+    fn default() -> Self {
+        BidiPairedBracketType::None
+    }
+}
+
+mod data {
+    use super::long_names as BPT;
+    use unic_char_property::tables::CharDataTable;
+    pub const BIDI_PAIRED_BRACKET_TYPE_TABLE: CharDataTable<super::BidiPairedBracketType> =
+        include!("../tables/bidi_paired_bracket_type.rsv");
+}
+
+impl BidiPairedBracketType {
+    /// Find the character `Bidi_Paired_Bracket_Type` property value.
+    pub fn of(ch: char) -> BidiPairedBracketType {
+        data::BIDI_PAIRED_BRACKET_TYPE_TABLE.find_or_default(ch)
+    }
+
+    /// Whether the character is an opening paired bracket.
+    #[inline]
+    pub fn is_open(&self) -> bool {
+        *self == BidiPairedBracketType::Open
+    }
+
+    /// Whether the character is a closing paired bracket.
+    #[inline]
+    pub fn is_close(&self) -> bool {
+        *self == BidiPairedBracketType::Close
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BidiPairedBracketType as BPT;
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(BPT::of('\u{0028}'), BPT::Open);
+        assert_eq!(BPT::of('\u{0029}'), BPT::Close);
+        assert_eq!(BPT::of('\u{005B}'), BPT::Open);
+        assert_eq!(BPT::of('\u{005D}'), BPT::Close);
+        assert_eq!(BPT::of('\u{007B}'), BPT::Open);
+        assert_eq!(BPT::of('\u{007D}'), BPT::Close);
+        assert_eq!(BPT::of('\u{003C}'), BPT::None);
+        assert_eq!(BPT::of('\u{2329}'), BPT::Open);
+        assert_eq!(BPT::of('\u{232A}'), BPT::Close);
+        assert_eq!(BPT::of('\u{3008}'), BPT::Open);
+        assert_eq!(BPT::of('\u{3009}'), BPT::Close);
+        assert_eq!(BPT::of('\u{FF63}'), BPT::Close);
+        assert_eq!(BPT::of('a'), BPT::None);
+        assert_eq!(BPT::of('\u{10FFFF}'), BPT::None);
+    }
+
+    #[test]
+    fn test_abbr_name() {
+        assert_eq!(BPT::Open.abbr_name(), "o");
+        assert_eq!(BPT::Close.abbr_name(), "c");
+        assert_eq!(BPT::None.abbr_name(), "n");
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(BPT::Open.long_name(), "Open");
+        assert_eq!(BPT::Close.long_name(), "Close");
+        assert_eq!(BPT::None.long_name(), "None");
+    }
+}
@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use unic_char_property::TotalCharProperty;
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
 
 char_property! {
     /// Represents the Unicode Character
@@ -82,6 +82,7 @@ char_property! {
         DecimalNumber {
             abbr => Nd,
             long => Decimal_Number,
+            other_aliases => ["digit"],
             human => "Decimal Digit",
         }
 
@@ -201,6 +202,7 @@ char_property! {
         Control {
             abbr => Cc,
             long => Control,
+            other_aliases => ["cntrl"],
             human => "Control",
         }
 
@@ -243,6 +245,14 @@ impl TotalCharProperty for GeneralCategory {
     }
 }
 
+impl TableCharProperty for GeneralCategory {
+    type TableValue = GeneralCategory;
+
+    fn table() -> CharDataTable<GeneralCategory> {
+        data::GENERAL_CATEGORY_TABLE
+    }
+}
+
 impl Default for GeneralCategory {
     fn default() -> Self {
         GeneralCategory::Unassigned
@@ -430,4 +440,18 @@ mod tests {
         assert_eq!(GC::UppercaseLetter.human_name(), "Uppercase Letter");
         assert_eq!(GC::Unassigned.human_name(), "Unassigned");
     }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(GC::from_name("Lu"), Some(GC::UppercaseLetter));
+        assert_eq!(GC::from_name("Uppercase_Letter"), Some(GC::UppercaseLetter));
+        assert_eq!(GC::from_name("uppercase letter"), Some(GC::UppercaseLetter));
+        assert_eq!(GC::from_name("isLu"), Some(GC::UppercaseLetter));
+        assert_eq!(GC::from_name("cntrl"), Some(GC::Control));
+        assert_eq!(GC::from_name("digit"), Some(GC::DecimalNumber));
+        assert_eq!(GC::from_name("L"), None);
+
+        assert_eq!("decimal-number".parse(), Ok(GC::DecimalNumber));
+        assert_eq!("Decimal Digit".parse::<GC>(), Err(()));
+    }
 }
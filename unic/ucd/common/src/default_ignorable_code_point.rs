@@ -0,0 +1,54 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Default_Ignorable_Code_Point` Character Property.
+
+char_property! {
+    /// Represents values of the Unicode character property
+    /// [*Default_Ignorable_Code_Point*](https://www.unicode.org/reports/tr44/#Default_Ignorable_Code_Point).
+    ///
+    /// The value is `true` for characters that should be ignored in rendering when not
+    /// explicitly supported, `false` otherwise.
+    pub struct DefaultIgnorableCodePoint(bool) {
+        abbr => "DI";
+        long => "Default_Ignorable_Code_Point";
+        human => "Default Ignorable Code Point";
+
+        data_table_path => "../tables/default_ignorable_code_point.rsv";
+    }
+
+    /// Return `true` for default-ignorable code points, `false` otherwise.
+    pub fn is_default_ignorable_code_point(char) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_values() {
+        use super::is_default_ignorable_code_point;
+
+        assert_eq!(is_default_ignorable_code_point('\u{0020}'), false);
+        assert_eq!(is_default_ignorable_code_point('\u{0041}'), false);
+        assert_eq!(is_default_ignorable_code_point('\u{00AD}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{034F}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{0600}'), false);
+        assert_eq!(is_default_ignorable_code_point('\u{200B}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{200D}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{2065}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{FE0F}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{FEFF}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{FFF9}'), false);
+        assert_eq!(is_default_ignorable_code_point('\u{1D173}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{E0001}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{E0FFF}'), true);
+        assert_eq!(is_default_ignorable_code_point('\u{E1000}'), false);
+        assert_eq!(is_default_ignorable_code_point('\u{10FFFF}'), false);
+    }
+}
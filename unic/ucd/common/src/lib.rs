@@ -40,6 +40,12 @@ pub use crate::alphabetic::{is_alphabetic, Alphabetic};
 pub mod white_space;
 pub use crate::white_space::{is_white_space, WhiteSpace};
 
+pub mod default_ignorable_code_point;
+pub use crate::default_ignorable_code_point::{
+    is_default_ignorable_code_point,
+    DefaultIgnorableCodePoint,
+};
+
 // == Non-UCD-defined: methods only ==
 
 pub mod alphanumeric;
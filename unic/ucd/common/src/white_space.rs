@@ -18,6 +18,7 @@ char_property! {
     pub struct WhiteSpace(bool) {
         abbr => "WSpace";
         long => "White_Space";
+        other_aliases => ["space"];
         human => "White Space";
 
         data_table_path => "../tables/white_space.rsv";
@@ -99,4 +100,15 @@ mod tests {
         assert_eq!(is_white_space('\u{10fffe}'), false);
         assert_eq!(is_white_space('\u{10ffff}'), false);
     }
+
+    #[test]
+    fn test_prop_name() {
+        use super::WhiteSpace;
+        use unic_char_property::CharProperty;
+
+        assert!(WhiteSpace::is_prop_name("WSpace"));
+        assert!(WhiteSpace::is_prop_name("white space"));
+        assert!(WhiteSpace::is_prop_name("space"));
+        assert!(!WhiteSpace::is_prop_name("Pattern_White_Space"));
+    }
 }
@@ -0,0 +1,201 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `East_Asian_Width` Character Property.
+
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`East_Asian_Width`](https://www.unicode.org/reports/tr44/#East_Asian_Width)
+    /// property.
+    ///
+    /// * <https://www.unicode.org/reports/tr11/>
+    /// * <https://www.unicode.org/Public/UCD/latest/ucd/EastAsianWidth.txt>
+    pub enum EastAsianWidth {
+        abbr => "ea";
+        long => "East_Asian_Width";
+        human => "East Asian Width";
+
+        /// Width depends on the context: wide in East Asian legacy encodings, narrow elsewhere
+        Ambiguous {
+            abbr => A,
+            long => Ambiguous,
+            human => "Ambiguous",
+        }
+
+        /// Wide compatibility form of a narrow character, such as FULLWIDTH LATIN CAPITAL LETTER A
+        Fullwidth {
+            abbr => F,
+            long => Fullwidth,
+            human => "Fullwidth",
+        }
+
+        /// Narrow compatibility form of a wide character, such as HALFWIDTH KATAKANA LETTER A
+        Halfwidth {
+            abbr => H,
+            long => Halfwidth,
+            human => "Halfwidth",
+        }
+
+        /// Does not occur in legacy East Asian character sets
+        Neutral {
+            abbr => N,
+            long => Neutral,
+            human => "Neutral",
+        }
+
+        /// Narrow character with a wide counterpart, such as LATIN CAPITAL LETTER A
+        Narrow {
+            abbr => Na,
+            long => Narrow,
+            human => "Narrow",
+        }
+
+        /// Always wide, such as CJK ideographs
+        Wide {
+            abbr => W,
+            long => Wide,
+            human => "Wide",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`East_Asian_Width`](https://www.unicode.org/reports/tr44/#East_Asian_Width)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#East_Asian_Width>
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`East_Asian_Width`](https://www.unicode.org/reports/tr44/#East_Asian_Width)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#East_Asian_Width>
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for EastAsianWidth {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+impl TableCharProperty for EastAsianWidth {
+    type TableValue = EastAsianWidth;
+
+    fn table() -> CharDataTable<EastAsianWidth> {
+        data::EAST_ASIAN_WIDTH_TABLE
+    }
+}
+
+/// UCD/EastAsianWidth.txt:
+/// "All code points, assigned or unassigned, that are not listed explicitly are given the value
+/// "N"."
+///
+/// The unassigned code points of the CJK ideograph blocks default to `W`, and are listed in the
+/// table.
+impl Default for EastAsianWidth {
+    #[inline]
+    fn default() -> Self {
+        EastAsianWidth::Neutral
+    }
+}
+
+mod data {
+    use super::abbr_names as EA;
+    use unic_char_property::tables::CharDataTable;
+    pub const EAST_ASIAN_WIDTH_TABLE: CharDataTable<super::EastAsianWidth> =
+        include!("../tables/east_asian_width.rsv");
+}
+
+impl EastAsianWidth {
+    /// Find the character `East_Asian_Width` property value.
+    pub fn of(ch: char) -> EastAsianWidth {
+        data::EAST_ASIAN_WIDTH_TABLE.find_or_default(ch)
+    }
+
+    /// Whether the character is displayed wide in all contexts, that is `Wide` or `Fullwidth`.
+    #[inline]
+    pub fn is_wide(self) -> bool {
+        matches!(self, EastAsianWidth::Wide | EastAsianWidth::Fullwidth)
+    }
+}
+
+/// Methods for `East_Asian_Width` character property.
+pub trait CharEastAsianWidth {
+    /// Get `East_Asian_Width` of the character.
+    fn east_asian_width(self) -> EastAsianWidth;
+}
+
+impl CharEastAsianWidth for char {
+    #[inline]
+    fn east_asian_width(self) -> EastAsianWidth {
+        EastAsianWidth::of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharEastAsianWidth, EastAsianWidth as EA};
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(EA::of('\u{0000}'), EA::Neutral);
+        assert_eq!(EA::of('a'), EA::Narrow);
+        assert_eq!(EA::of('\u{00A1}'), EA::Ambiguous);
+        assert_eq!(EA::of('\u{00E9}'), EA::Ambiguous);
+        assert_eq!(EA::of('\u{0300}'), EA::Ambiguous);
+        assert_eq!(EA::of('\u{0627}'), EA::Neutral);
+        assert_eq!(EA::of('\u{1100}'), EA::Wide);
+        assert_eq!(EA::of('\u{3000}'), EA::Fullwidth);
+        assert_eq!(EA::of('\u{4E00}'), EA::Wide);
+        assert_eq!(EA::of('\u{E000}'), EA::Ambiguous);
+        assert_eq!(EA::of('\u{FF21}'), EA::Fullwidth);
+        assert_eq!(EA::of('\u{FF71}'), EA::Halfwidth);
+        assert_eq!(EA::of('\u{1F600}'), EA::Wide);
+        assert_eq!('\u{10FFFD}'.east_asian_width(), EA::Ambiguous);
+    }
+
+    #[test]
+    fn test_unassigned_defaults() {
+        assert_eq!(EA::of('\u{9FFF}'), EA::Wide);
+        assert_eq!(EA::of('\u{2FFFD}'), EA::Wide);
+        assert_eq!(EA::of('\u{3FFFD}'), EA::Wide);
+        assert_eq!(EA::of('\u{0378}'), EA::Neutral);
+        assert_eq!(EA::of('\u{4FFFD}'), EA::Neutral);
+        assert_eq!(EA::of('\u{10FFFF}'), EA::Neutral);
+    }
+
+    #[test]
+    fn test_is_wide() {
+        assert!(EA::Wide.is_wide());
+        assert!(EA::Fullwidth.is_wide());
+        assert!(!EA::Ambiguous.is_wide());
+        assert!(!EA::Halfwidth.is_wide());
+    }
+
+    #[test]
+    fn test_abbr_name() {
+        assert_eq!(EA::Ambiguous.abbr_name(), "A");
+        assert_eq!(EA::Narrow.abbr_name(), "Na");
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(EA::Fullwidth.long_name(), "Fullwidth");
+        assert_eq!(EA::Neutral.long_name(), "Neutral");
+    }
+}
@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use unic_case::to_uppercase;
 use unic_char_basics::unicode_notation;
 use unic_char_range::CharRange;
 use unic_ucd_category::GeneralCategory;
@@ -20,8 +21,7 @@ fn main() {
     for ch in CharRange::assigned_normal_planes() {
         if GeneralCategory::of(ch) == GeneralCategory::LowercaseLetter {
             total_count += 1;
-            // TODO(GH-153): Use unic-case for case manipulation.
-            if ch.to_string() == ch.to_uppercase().collect::<String>() {
+            if ch.to_string() == to_uppercase(ch).collect::<String>() {
                 if cond_count > 0 {
                     print!(", ");
                 }
@@ -0,0 +1,190 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Hangul_Syllable_Type` Character Property.
+
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`Hangul_Syllable_Type`](https://www.unicode.org/reports/tr44/#Hangul_Syllable_Type)
+    /// property.
+    ///
+    /// * <https://www.unicode.org/versions/latest/ch03.pdf> (3.12 Conjoining Jamo Behavior)
+    /// * <https://www.unicode.org/Public/UCD/latest/ucd/HangulSyllableType.txt>
+    pub enum HangulSyllableType {
+        abbr => "hst";
+        long => "Hangul_Syllable_Type";
+        human => "Hangul Syllable Type";
+
+        /// Leading consonant jamo (choseong)
+        LeadingJamo {
+            abbr => L,
+            long => Leading_Jamo,
+            human => "Leading Jamo",
+        }
+
+        /// Vowel jamo (jungseong)
+        VowelJamo {
+            abbr => V,
+            long => Vowel_Jamo,
+            human => "Vowel Jamo",
+        }
+
+        /// Trailing consonant jamo (jongseong)
+        TrailingJamo {
+            abbr => T,
+            long => Trailing_Jamo,
+            human => "Trailing Jamo",
+        }
+
+        /// Precomposed syllable of a leading consonant and a vowel
+        LVSyllable {
+            abbr => LV,
+            long => LV_Syllable,
+            human => "LV Syllable",
+        }
+
+        /// Precomposed syllable of a leading consonant, a vowel and a trailing consonant
+        LVTSyllable {
+            abbr => LVT,
+            long => LVT_Syllable,
+            human => "LVT Syllable",
+        }
+
+        /// Not a conjoining jamo or a precomposed syllable
+        NotApplicable {
+            abbr => NA,
+            long => Not_Applicable,
+            human => "Not Applicable",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Hangul_Syllable_Type`](https://www.unicode.org/reports/tr44/#Hangul_Syllable_Type)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Hangul_Syllable_Type>
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Hangul_Syllable_Type`](https://www.unicode.org/reports/tr44/#Hangul_Syllable_Type)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Hangul_Syllable_Type>
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for HangulSyllableType {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+impl TableCharProperty for HangulSyllableType {
+    type TableValue = HangulSyllableType;
+
+    fn table() -> CharDataTable<HangulSyllableType> {
+        data::HANGUL_SYLLABLE_TYPE_TABLE
+    }
+}
+
+/// UCD/HangulSyllableType.txt:
+/// "All code points not explicitly listed for Hangul_Syllable_Type have the value Not_Applicable
+/// (NA)."
+impl Default for HangulSyllableType {
+    #[inline]
+    fn default() -> Self {
+        HangulSyllableType::NotApplicable
+    }
+}
+
+mod data {
+    use super::abbr_names as HST;
+    use unic_char_property::tables::CharDataTable;
+    pub const HANGUL_SYLLABLE_TYPE_TABLE: CharDataTable<super::HangulSyllableType> =
+        include!("../tables/hangul_syllable_type.rsv");
+}
+
+impl HangulSyllableType {
+    /// Find the character `Hangul_Syllable_Type` property value.
+    pub fn of(ch: char) -> HangulSyllableType {
+        data::HANGUL_SYLLABLE_TYPE_TABLE.find_or_default(ch)
+    }
+
+    /// Whether the character is a conjoining jamo, that is `L`, `V` or `T`.
+    #[inline]
+    pub fn is_jamo(self) -> bool {
+        matches!(
+            self,
+            HangulSyllableType::LeadingJamo
+                | HangulSyllableType::VowelJamo
+                | HangulSyllableType::TrailingJamo
+        )
+    }
+}
+
+/// Methods for `Hangul_Syllable_Type` character property.
+pub trait CharHangulSyllableType {
+    /// Get `Hangul_Syllable_Type` of the character.
+    fn hangul_syllable_type(self) -> HangulSyllableType;
+}
+
+impl CharHangulSyllableType for char {
+    #[inline]
+    fn hangul_syllable_type(self) -> HangulSyllableType {
+        HangulSyllableType::of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HangulSyllableType as HST;
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(HST::of('\u{1100}'), HST::LeadingJamo);
+        assert_eq!(HST::of('\u{A97C}'), HST::LeadingJamo);
+        assert_eq!(HST::of('\u{1160}'), HST::VowelJamo);
+        assert_eq!(HST::of('\u{D7B0}'), HST::VowelJamo);
+        assert_eq!(HST::of('\u{11A8}'), HST::TrailingJamo);
+        assert_eq!(HST::of('\u{D7FB}'), HST::TrailingJamo);
+        assert_eq!(HST::of('가'), HST::LVSyllable);
+        assert_eq!(HST::of('각'), HST::LVTSyllable);
+        assert_eq!(HST::of('\u{D7A3}'), HST::LVTSyllable);
+
+        assert_eq!(HST::of('\u{0000}'), HST::NotApplicable);
+        assert_eq!(HST::of('A'), HST::NotApplicable);
+        assert_eq!(HST::of('\u{3131}'), HST::NotApplicable);
+        assert_eq!(HST::of('\u{D7A4}'), HST::NotApplicable);
+        assert_eq!(HST::of('\u{10FFFF}'), HST::NotApplicable);
+    }
+
+    #[test]
+    fn test_is_jamo() {
+        assert!(HST::of('\u{1100}').is_jamo());
+        assert!(HST::of('\u{1160}').is_jamo());
+        assert!(HST::of('\u{11A8}').is_jamo());
+        assert!(!HST::of('가').is_jamo());
+        assert!(!HST::of('\u{3131}').is_jamo());
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(HST::LVSyllable.abbr_name(), "LV");
+        assert_eq!(HST::LVSyllable.long_name(), "LV_Syllable");
+        assert_eq!(HST::NotApplicable.human_name(), "Not Applicable");
+    }
+}
@@ -22,7 +22,8 @@
 //!
 //! This UCD component provides algorithms described in [Unicode®
 //! Standard - 3.12 Conjoining Jamo Behavior](https://www.unicode.org/versions/latest/ch03.pdf),
-//! used for detecting, composing and decomposing Hangul syllables.
+//! used for detecting, composing and decomposing Hangul syllables, and the
+//! `Hangul_Syllable_Type` character property.
 //!
 //! # Examples
 //!
@@ -46,11 +47,20 @@
 //! assert_eq!(composed, sample_hangul);
 //! ```
 
+#[macro_use]
+extern crate unic_char_property;
+
+#[macro_use]
+extern crate unic_char_range;
+
 use unic_ucd_version::UnicodeVersion;
 
 mod hangul;
 pub use crate::hangul::{compose_syllable, decompose_syllable, is_syllable};
 
+pub mod hangul_syllable_type;
+pub use crate::hangul_syllable_type::{CharHangulSyllableType, HangulSyllableType};
+
 mod pkg_info;
 pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
 
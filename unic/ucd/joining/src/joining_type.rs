@@ -0,0 +1,181 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Joining_Type` Character Property.
+
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`Joining_Type`](https://www.unicode.org/reports/tr44/#Joining_Type)
+    /// property.
+    ///
+    /// * <https://www.unicode.org/Public/UCD/latest/ucd/ArabicShaping.txt>
+    /// * <https://www.unicode.org/Public/UCD/latest/ucd/extracted/DerivedJoiningType.txt>
+    pub enum JoiningType {
+        abbr => "jt";
+        long => "Joining_Type";
+        human => "Joining Type";
+
+        /// Forces the joining of surrounding characters, such as ZERO WIDTH JOINER
+        JoinCausing {
+            abbr => C,
+            long => Join_Causing,
+            human => "Join Causing",
+        }
+
+        /// Joins on both sides
+        DualJoining {
+            abbr => D,
+            long => Dual_Joining,
+            human => "Dual Joining",
+        }
+
+        /// Joins on the left side only
+        LeftJoining {
+            abbr => L,
+            long => Left_Joining,
+            human => "Left Joining",
+        }
+
+        /// Joins on the right side only
+        RightJoining {
+            abbr => R,
+            long => Right_Joining,
+            human => "Right Joining",
+        }
+
+        /// Does not affect the joining of surrounding characters, such as most combining marks
+        Transparent {
+            abbr => T,
+            long => Transparent,
+            human => "Transparent",
+        }
+
+        /// Does not join
+        NonJoining {
+            abbr => U,
+            long => Non_Joining,
+            human => "Non Joining",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Joining_Type`](https://www.unicode.org/reports/tr44/#Joining_Type)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Joining_Type>
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Joining_Type`](https://www.unicode.org/reports/tr44/#Joining_Type)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Joining_Type>
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for JoiningType {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+impl TableCharProperty for JoiningType {
+    type TableValue = JoiningType;
+
+    fn table() -> CharDataTable<JoiningType> {
+        data::JOINING_TYPE_TABLE
+    }
+}
+
+/// UCD/ArabicShaping.txt:
+/// "Those that are not explicitly listed and that are of General Category Mn, Me, or Cf have
+/// joining type T. All others not explicitly listed have joining type U."
+impl Default for JoiningType {
+    #[inline]
+    fn default() -> Self {
+        JoiningType::NonJoining
+    }
+}
+
+mod data {
+    use super::abbr_names as JT;
+    use unic_char_property::tables::CharDataTable;
+    pub const JOINING_TYPE_TABLE: CharDataTable<super::JoiningType> =
+        include!("../tables/joining_type.rsv");
+}
+
+impl JoiningType {
+    /// Find the character `Joining_Type` property value.
+    pub fn of(ch: char) -> JoiningType {
+        data::JOINING_TYPE_TABLE.find_or_default(ch)
+    }
+}
+
+/// Methods for `Joining_Type` character property.
+pub trait CharJoiningType {
+    /// Get `Joining_Type` of the character.
+    fn joining_type(self) -> JoiningType;
+}
+
+impl CharJoiningType for char {
+    #[inline]
+    fn joining_type(self) -> JoiningType {
+        JoiningType::of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharJoiningType, JoiningType as JT};
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(JT::of('a'), JT::NonJoining);
+        assert_eq!(JT::of('\u{0600}'), JT::NonJoining);
+        assert_eq!(JT::of('\u{0627}'), JT::RightJoining);
+        assert_eq!(JT::of('\u{0628}'), JT::DualJoining);
+        assert_eq!(JT::of('\u{0644}'), JT::DualJoining);
+        assert_eq!(JT::of('\u{0710}'), JT::RightJoining);
+        assert_eq!(JT::of('\u{A872}'), JT::LeftJoining);
+        assert_eq!(JT::of('\u{0640}'), JT::JoinCausing);
+        assert_eq!(JT::of('\u{200D}'), JT::JoinCausing);
+        assert_eq!(JT::of('\u{200C}'), JT::NonJoining);
+        assert_eq!(JT::of('\u{10FFFF}'), JT::NonJoining);
+    }
+
+    #[test]
+    fn test_derived_transparent() {
+        // Mn, Me and Cf characters not listed in ArabicShaping.txt
+        assert_eq!(JT::of('\u{0300}'), JT::Transparent);
+        assert_eq!(JT::of('\u{064B}'), JT::Transparent);
+        assert_eq!(JT::of('\u{20DD}'), JT::Transparent);
+        assert_eq!(JT::of('\u{00AD}'), JT::Transparent);
+        assert_eq!('\u{E0001}'.joining_type(), JT::Transparent);
+    }
+
+    #[test]
+    fn test_abbr_name() {
+        assert_eq!(JT::DualJoining.abbr_name(), "D");
+        assert_eq!(JT::NonJoining.abbr_name(), "U");
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(JT::JoinCausing.long_name(), "Join_Causing");
+        assert_eq!(JT::Transparent.long_name(), "Transparent");
+    }
+}
@@ -29,6 +29,18 @@ pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
 mod name;
 pub use crate::name::Name;
 
+mod lookup;
+pub use crate::lookup::{
+    char_from_name,
+    named_sequence,
+    sequence_name,
+    string_from_name,
+    NamedString,
+};
+
+mod search;
+pub use crate::search::{search_names, search_names_fuzzy, SearchNames};
+
 use unic_ucd_version::UnicodeVersion;
 
 /// The [Unicode version](https://www.unicode.org/versions/) of data
@@ -0,0 +1,326 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reverse lookup, from names to characters and named character sequences.
+
+use core::fmt;
+
+use unic_ucd_hangul::compose_syllable;
+
+use crate::name::{
+    self, Name, PREFIX_CJK_COMPATIBILITY_IDEOGRAPH, PREFIX_CJK_UNIFIED_IDEOGRAPH,
+    PREFIX_HANGUL_SYLLABLE, PREFIX_NUSHU_CHARACTER, PREFIX_TANGUT_IDEOGRAPH,
+};
+
+/// Maximum length of a loose matching key, in bytes; longer than any name, alias or name of a
+/// named sequence.
+const KEY_BUFFER_SIZE: usize = 128;
+
+/// The one name that keeps its medial hyphen under loose matching, to stay distinct from
+/// HANGUL JUNGSEONG OE (U+116C).
+const HANGUL_JUNGSEONG_O_E: char = '\u{1180}';
+const HANGUL_JUNGSEONG_OE_KEY: &[u8] = b"HANGULJUNGSEONGOE";
+
+/// Prefixes of NR2 names, for ideographs named after their code points.
+const IDEOGRAPH_PREFIXES: &[&str] = &[
+    PREFIX_CJK_UNIFIED_IDEOGRAPH,
+    PREFIX_TANGUT_IDEOGRAPH,
+    PREFIX_NUSHU_CHARACTER,
+    PREFIX_CJK_COMPATIBILITY_IDEOGRAPH,
+];
+
+/// Find the character with the given name, or formal name alias.
+///
+/// Names are matched loosely, following
+/// [UAX44-LM2](https://www.unicode.org/reports/tr44/#UAX44-LM2): case, whitespace, underscores
+/// and medial hyphens are ignored, except for the hyphen of *HANGUL JUNGSEONG O-E* (U+1180).
+///
+/// Accepts the names derived by rule for Hangul syllables (NR1) and ideographs (NR2), the names
+/// listed in *UnicodeData.txt* (NR3), and all the formal aliases of *NameAliases.txt*, as
+/// provided by `unic-ucd-name-aliases`.
+///
+/// ```
+/// use unic_ucd_name::char_from_name;
+///
+/// assert_eq!(char_from_name("LATIN SMALL LETTER A WITH ACUTE"), Some('á'));
+/// assert_eq!(char_from_name("latin_small_letter_a_with_acute"), Some('á'));
+/// assert_eq!(char_from_name("HANGUL SYLLABLE GOBS"), Some('곲'));
+/// assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-8A00"), Some('言'));
+/// assert_eq!(char_from_name("BYTE ORDER MARK"), Some('\u{FEFF}'));
+/// assert_eq!(char_from_name("NOT A CHARACTER NAME"), None);
+/// ```
+pub fn char_from_name(name: &str) -> Option<char> {
+    let key = LooseKey::new(name)?;
+    let key = key.as_bytes();
+
+    if key == HANGUL_JUNGSEONG_OE_KEY && has_o_e_hyphen(name) {
+        return Some(HANGUL_JUNGSEONG_O_E);
+    }
+
+    if let Some(rest) = strip_prefix(key, PREFIX_HANGUL_SYLLABLE) {
+        return hangul_syllable(rest);
+    }
+
+    for prefix in IDEOGRAPH_PREFIXES {
+        if let Some(ch) = strip_prefix(key, prefix).and_then(|rest| ideograph(prefix, rest)) {
+            return Some(ch);
+        }
+    }
+
+    explicit_name(key).or_else(|| name_alias(key))
+}
+
+/// Find the named character sequence with the given name.
+///
+/// Names are matched loosely, like with [`char_from_name()`]. Both approved and provisional named
+/// sequences, from *NamedSequences.txt* and *NamedSequencesProv.txt*, are accepted.
+///
+/// [`char_from_name()`]: fn.char_from_name.html
+///
+/// ```
+/// use unic_ucd_name::named_sequence;
+///
+/// assert_eq!(
+///     named_sequence("LATIN CAPITAL LETTER A WITH MACRON AND GRAVE"),
+///     Some("\u{100}\u{300}")
+/// );
+/// assert_eq!(named_sequence("keycap digit one"), Some("1\u{FE0F}\u{20E3}"));
+/// assert_eq!(named_sequence("LATIN CAPITAL LETTER A"), None);
+/// ```
+pub fn named_sequence(name: &str) -> Option<&'static str> {
+    let key = LooseKey::new(name)?;
+    let key = key.as_bytes();
+    data::NAMED_SEQUENCES
+        .binary_search_by(|&(name, _)| loose_key(name).cmp(key.iter().cloned()))
+        .ok()
+        .map(|index| data::NAMED_SEQUENCES[index].1)
+}
+
+/// A character or a named character sequence, found by [`string_from_name()`].
+///
+/// [`string_from_name()`]: fn.string_from_name.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum NamedString {
+    /// A character, found by its name or formal name alias.
+    Char(char),
+
+    /// A named character sequence.
+    Sequence(&'static str),
+}
+
+impl fmt::Display for NamedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            NamedString::Char(ch) => write!(f, "{}", ch),
+            NamedString::Sequence(sequence) => f.write_str(sequence),
+        }
+    }
+}
+
+/// Find the character or the named character sequence with the given name.
+///
+/// Tries the names and formal name aliases of characters, like [`char_from_name()`], then the
+/// named character sequences, like [`named_sequence()`]. The two namespaces are disjoint, so the
+/// order does not matter.
+///
+/// [`char_from_name()`]: fn.char_from_name.html
+/// [`named_sequence()`]: fn.named_sequence.html
+///
+/// ```
+/// use unic_ucd_name::{string_from_name, NamedString};
+///
+/// assert_eq!(string_from_name("LATIN SMALL LETTER A"), Some(NamedString::Char('a')));
+/// assert_eq!(
+///     string_from_name("keycap digit one"),
+///     Some(NamedString::Sequence("1\u{FE0F}\u{20E3}"))
+/// );
+/// assert_eq!(
+///     string_from_name("KEYCAP DIGIT ONE").map(|string| string.to_string()),
+///     Some("1\u{FE0F}\u{20E3}".to_owned())
+/// );
+/// assert_eq!(string_from_name("NOT A CHARACTER NAME"), None);
+/// ```
+pub fn string_from_name(name: &str) -> Option<NamedString> {
+    char_from_name(name)
+        .map(NamedString::Char)
+        .or_else(|| named_sequence(name).map(NamedString::Sequence))
+}
+
+/// Find the name of a named character sequence.
+///
+/// ```
+/// use unic_ucd_name::sequence_name;
+///
+/// assert_eq!(
+///     sequence_name("\u{100}\u{300}"),
+///     Some("LATIN CAPITAL LETTER A WITH MACRON AND GRAVE")
+/// );
+/// assert_eq!(sequence_name("\u{100}"), None);
+/// ```
+pub fn sequence_name(sequence: &str) -> Option<&'static str> {
+    data::SEQUENCE_NAMES
+        .binary_search_by(|&(other, _)| other.cmp(sequence))
+        .ok()
+        .map(|index| data::SEQUENCE_NAMES[index].1)
+}
+
+/// Loose matching key of a name, in a stack buffer.
+struct LooseKey {
+    bytes: [u8; KEY_BUFFER_SIZE],
+    len: usize,
+}
+
+impl LooseKey {
+    /// The key of the name, unless it is too long to be a name.
+    fn new(name: &str) -> Option<LooseKey> {
+        let mut key = LooseKey {
+            bytes: [0; KEY_BUFFER_SIZE],
+            len: 0,
+        };
+        for byte in loose_key(name) {
+            *key.bytes.get_mut(key.len)? = byte;
+            key.len += 1;
+        }
+        Some(key)
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+/// Loose matching key of a name or alias, following UAX44-LM2, without the special case of
+/// U+1180.
+///
+/// Must be kept in sync with the index generation in `unic-gen`.
+fn loose_key(name: &str) -> impl Iterator<Item = u8> + '_ {
+    let bytes = name.as_bytes();
+    bytes
+        .iter()
+        .enumerate()
+        .filter(move |&(i, &byte)| {
+            let is_medial_hyphen = byte == b'-'
+                && i > 0
+                && i + 1 < bytes.len()
+                && bytes[i - 1].is_ascii_alphanumeric()
+                && bytes[i + 1].is_ascii_alphanumeric();
+            !(byte.is_ascii_whitespace() || byte == b'_' || is_medial_hyphen)
+        })
+        .map(|(_, &byte)| byte.to_ascii_uppercase())
+}
+
+/// Whether the name ends with `O-E`, ignoring case, whitespace and underscores.
+fn has_o_e_hyphen(name: &str) -> bool {
+    let mut tail = name
+        .bytes()
+        .rev()
+        .filter(|&byte| !(byte.is_ascii_whitespace() || byte == b'_'))
+        .map(|byte| byte.to_ascii_uppercase());
+    (tail.next(), tail.next(), tail.next()) == (Some(b'E'), Some(b'-'), Some(b'O'))
+}
+
+fn strip_prefix<'a>(key: &'a [u8], prefix: &str) -> Option<&'a [u8]> {
+    let mut rest = key;
+    for byte in loose_key(prefix.trim_end_matches(&[' ', '-'][..])) {
+        let (&first, tail) = rest.split_first()?;
+        if first != byte {
+            return None;
+        }
+        rest = tail;
+    }
+    Some(rest)
+}
+
+/// NR1: Find the Hangul syllable from the Jamo short names of its leading consonant, vowel and
+/// optional trailing consonant.
+fn hangul_syllable(key: &[u8]) -> Option<char> {
+    for leading in '\u{1100}'..='\u{1112}' {
+        let after_leading = match strip_jamo_short_name(key, leading) {
+            Some(rest) => rest,
+            None => continue,
+        };
+        for vowel in '\u{1161}'..='\u{1175}' {
+            let after_vowel = match strip_jamo_short_name(after_leading, vowel) {
+                Some(rest) => rest,
+                None => continue,
+            };
+            let syllable = compose_syllable(leading, vowel)?;
+            if after_vowel.is_empty() {
+                return Some(syllable);
+            }
+            for trailing in '\u{11A8}'..='\u{11C2}' {
+                if strip_jamo_short_name(after_vowel, trailing) == Some(&[][..]) {
+                    return compose_syllable(syllable, trailing);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn strip_jamo_short_name(key: &[u8], jamo: char) -> Option<&[u8]> {
+    let short_name = name::data::JAMO_SHORT_NAMES.find(jamo)?.as_bytes();
+    if key.starts_with(short_name) {
+        Some(&key[short_name.len()..])
+    } else {
+        None
+    }
+}
+
+/// NR2: Find the ideograph from its code point, written in hexadecimal with the usual 4- to
+/// 6-digit convention.
+fn ideograph(prefix: &'static str, digits: &[u8]) -> Option<char> {
+    if digits.len() < 4
+        || digits.len() > 6
+        || digits[0] == b'0'
+        || !digits.iter().all(u8::is_ascii_hexdigit)
+    {
+        return None;
+    }
+    let code_point = digits.iter().fold(0, |value, &digit| {
+        value * 16 + char::from(digit).to_digit(16).unwrap()
+    });
+    let ch = core::char::from_u32(code_point)?;
+    if Name::of(ch) == Some(Name::NR2(prefix, ch)) {
+        Some(ch)
+    } else {
+        None
+    }
+}
+
+/// NR3: Find the character from its explicitly listed name.
+fn explicit_name(key: &[u8]) -> Option<char> {
+    data::NAME_INDEX
+        .binary_search_by(|&ch| name_key(ch).cmp(key.iter().cloned()))
+        .ok()
+        .map(|index| data::NAME_INDEX[index])
+}
+
+fn name_key(ch: char) -> impl Iterator<Item = u8> {
+    name::data::NAMES
+        .find(ch)
+        .unwrap_or(&[])
+        .iter()
+        .flat_map(|piece| loose_key(piece))
+}
+
+fn name_alias(key: &[u8]) -> Option<char> {
+    data::NAME_ALIAS_INDEX
+        .binary_search_by(|&(alias, _)| loose_key(alias).cmp(key.iter().cloned()))
+        .ok()
+        .map(|index| data::NAME_ALIAS_INDEX[index].1)
+}
+
+mod data {
+    pub const NAME_INDEX: &[char] = include!("../tables/name_index.rsv");
+    pub const NAME_ALIAS_INDEX: &[(&str, char)] = include!("../tables/name_alias_index.rsv");
+    pub const NAMED_SEQUENCES: &[(&str, &str)] = include!("../tables/named_sequences.rsv");
+    pub const SEQUENCE_NAMES: &[(&str, &str)] = include!("../tables/sequence_names.rsv");
+}
@@ -20,6 +20,44 @@ pub static PREFIX_CJK_COMPATIBILITY_IDEOGRAPH: &str = "CJK COMPATIBILITY IDEOGRA
 
 const JAMO_BUFFER_SIZE: usize = 3;
 
+/// Define the ranges of characters with names derived by rule once, both as a table for iterating
+/// and as a `match` for lookup.
+macro_rules! derived_name_ranges {
+    ( $( $first:literal ..= $last:literal => $prefix:expr, )* ) => {
+        /// Ranges of characters with names derived by rule, in code point order, with the prefix
+        /// of their names for NR2, or `None` for NR1.
+        pub(crate) static DERIVED_NAME_RANGES: &[(char, char, Option<&str>)] = &[
+            $( ($first, $last, $prefix), )*
+        ];
+
+        /// The entry of `DERIVED_NAME_RANGES` for the character, without the range, or `None` if
+        /// its name is not derived by rule.
+        #[inline]
+        fn derived_name_prefix(ch: char) -> Option<Option<&'static str>> {
+            match ch {
+                $( $first..=$last => Some($prefix), )*
+                _ => None,
+            }
+        }
+    };
+}
+
+derived_name_ranges! {
+    '\u{3400}'..='\u{4DB5}' => Some(PREFIX_CJK_UNIFIED_IDEOGRAPH),
+    '\u{4E00}'..='\u{9FEA}' => Some(PREFIX_CJK_UNIFIED_IDEOGRAPH),
+    '\u{AC00}'..='\u{D7A3}' => None,
+    '\u{F900}'..='\u{FA6D}' => Some(PREFIX_CJK_COMPATIBILITY_IDEOGRAPH),
+    '\u{FA70}'..='\u{FAD9}' => Some(PREFIX_CJK_COMPATIBILITY_IDEOGRAPH),
+    '\u{17000}'..='\u{187EC}' => Some(PREFIX_TANGUT_IDEOGRAPH),
+    '\u{1B170}'..='\u{1B2FB}' => Some(PREFIX_NUSHU_CHARACTER),
+    '\u{20000}'..='\u{2A6D6}' => Some(PREFIX_CJK_UNIFIED_IDEOGRAPH),
+    '\u{2A700}'..='\u{2B734}' => Some(PREFIX_CJK_UNIFIED_IDEOGRAPH),
+    '\u{2B740}'..='\u{2B81D}' => Some(PREFIX_CJK_UNIFIED_IDEOGRAPH),
+    '\u{2B820}'..='\u{2CEA1}' => Some(PREFIX_CJK_UNIFIED_IDEOGRAPH),
+    '\u{2CEB0}'..='\u{2EBE0}' => Some(PREFIX_CJK_UNIFIED_IDEOGRAPH),
+    '\u{2F800}'..='\u{2FA1D}' => Some(PREFIX_CJK_COMPATIBILITY_IDEOGRAPH),
+}
+
 /// Represents values of the Unicode character property
 /// [*Name*](https://www.unicode.org/reports/tr44/#Name).
 ///
@@ -53,21 +91,10 @@ pub enum Name {
 impl Name {
     /// Find the character `Name` property value.
     pub fn of(ch: char) -> Option<Name> {
-        match ch {
-            '\u{AC00}'..='\u{D7A3}' => Some(Name::NR1(ch)),
-            '\u{3400}'..='\u{4DB5}'
-            | '\u{4E00}'..='\u{9FEA}'
-            | '\u{20000}'..='\u{2A6D6}'
-            | '\u{2A700}'..='\u{2B734}'
-            | '\u{2B740}'..='\u{2B81D}'
-            | '\u{2B820}'..='\u{2CEA1}'
-            | '\u{2CEB0}'..='\u{2EBE0}' => Some(Name::NR2(PREFIX_CJK_UNIFIED_IDEOGRAPH, ch)),
-            '\u{17000}'..='\u{187EC}' => Some(Name::NR2(PREFIX_TANGUT_IDEOGRAPH, ch)),
-            '\u{1B170}'..='\u{1B2FB}' => Some(Name::NR2(PREFIX_NUSHU_CHARACTER, ch)),
-            '\u{F900}'..='\u{FA6D}' | '\u{FA70}'..='\u{FAD9}' | '\u{2F800}'..='\u{2FA1D}' => {
-                Some(Name::NR2(PREFIX_CJK_COMPATIBILITY_IDEOGRAPH, ch))
-            }
-            _ => data::NAMES.find(ch).map(|pieces| Name::NR3(pieces)),
+        match derived_name_prefix(ch) {
+            Some(None) => Some(Name::NR1(ch)),
+            Some(Some(prefix)) => Some(Name::NR2(prefix, ch)),
+            None => data::NAMES.find(ch).map(Name::NR3),
         }
     }
 
@@ -212,7 +239,7 @@ impl PartialOrd for Name {
     }
 }
 
-mod data {
+pub(crate) mod data {
     use unic_char_property::tables::CharDataTable;
     include!("../tables/name_values.rsd");
     pub const NAMES: CharDataTable<&[&str]> = include!("../tables/name_map.rsv");
@@ -0,0 +1,406 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Search of characters by the words of their names.
+
+use core::fmt::{self, Write};
+use core::str;
+
+use unic_char_property::tables::CharDataTable;
+use unic_ucd_name_aliases::{name_aliases_of, NameAliasType};
+
+use crate::name::{self, Name, DERIVED_NAME_RANGES, PREFIX_HANGUL_SYLLABLE};
+
+/// Maximum length of a name, in bytes.
+const NAME_BUFFER_SIZE: usize = 128;
+
+/// Maximum length of a word compared by edit distance, in bytes.
+const WORD_BUFFER_SIZE: usize = 64;
+
+/// How well a name matches a query, from best to worst.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+enum MatchQuality {
+    /// The name has exactly the words of the query.
+    Exact,
+    /// Every word of the query is a word of the name.
+    Words,
+    /// Every word of the query starts a word of the name.
+    Prefixes,
+    /// Every word of the query is part of a word of the name.
+    Substrings,
+    /// Every word of the query is a word of the name, with a few typos.
+    Fuzzy,
+}
+
+/// Search characters by name.
+///
+/// Returns every character with a `Name` which, or one of its formal name aliases, contains all
+/// the words of the query, ignoring case. Words are separated by ASCII whitespace, hyphens or
+/// underscores.
+///
+/// Exact matches come first, then names having all the query words as whole words, then names
+/// with words starting with them, then any other matches; characters of the same rank are in code
+/// point order.
+///
+/// ```
+/// use unic_ucd_name::search_names;
+///
+/// let mut found = search_names("latin letter a acute").map(|(ch, _)| ch);
+/// assert_eq!(found.next(), Some('Á'));
+/// assert_eq!(found.next(), Some('á'));
+/// ```
+pub fn search_names(query: &str) -> SearchNames<'_> {
+    SearchNames::new(query, None)
+}
+
+/// Search characters by name, also accepting words with typos.
+///
+/// Like [`search_names()`], followed by the characters having, for every word of the query, a
+/// name word within `max_distance` edits (insertions, deletions or substitutions of a letter).
+///
+/// [`search_names()`]: fn.search_names.html
+///
+/// ```
+/// use unic_ucd_name::{search_names, search_names_fuzzy};
+///
+/// assert_eq!(search_names("greek small leter alpa").next(), None);
+/// assert!(search_names_fuzzy("greek small leter alpa", 1).any(|(ch, _)| ch == 'α'));
+/// ```
+pub fn search_names_fuzzy(query: &str, max_distance: usize) -> SearchNames<'_> {
+    SearchNames::new(query, Some(max_distance))
+}
+
+/// Iterator over the characters matching a name search, with their names, best matches first.
+///
+/// Each rank of matches is found by a pass over all character names, so the search does not
+/// allocate.
+#[derive(Clone, Debug)]
+pub struct SearchNames<'a> {
+    query: &'a str,
+    max_distance: Option<usize>,
+    quality: Option<MatchQuality>,
+    candidates: Candidates,
+}
+
+impl<'a> SearchNames<'a> {
+    fn new(query: &'a str, max_distance: Option<usize>) -> SearchNames<'a> {
+        let quality = if words(query).next().is_some() {
+            Some(MatchQuality::Exact)
+        } else {
+            None
+        };
+        SearchNames {
+            query,
+            max_distance,
+            quality,
+            candidates: Candidates::default(),
+        }
+    }
+
+    fn next_quality(&self, quality: MatchQuality) -> Option<MatchQuality> {
+        match quality {
+            MatchQuality::Exact => Some(MatchQuality::Words),
+            MatchQuality::Words => Some(MatchQuality::Prefixes),
+            MatchQuality::Prefixes => Some(MatchQuality::Substrings),
+            MatchQuality::Substrings => self.max_distance.map(|_| MatchQuality::Fuzzy),
+            MatchQuality::Fuzzy => None,
+        }
+    }
+
+    /// Best match quality of the name and the formal name aliases of the character.
+    ///
+    /// Qualities worse than `worst` are not looked for, and reported as no match.
+    fn match_quality(&self, ch: char, name: Name, worst: MatchQuality) -> Option<MatchQuality> {
+        let mut quality = match name {
+            Name::NR3(pieces) => {
+                self.text_quality(|| pieces.iter().flat_map(|piece| words(piece)), worst)
+            }
+            _ => {
+                let mut buffer = NameBuffer::default();
+                match write!(buffer, "{}", name) {
+                    Ok(()) => self.text_quality(|| words(buffer.as_str()), worst),
+                    Err(_) => None,
+                }
+            }
+        };
+        for &alias_type in NameAliasType::of(ch).unwrap_or(&[]) {
+            for alias in name_aliases_of(ch, alias_type).unwrap_or(&[]) {
+                quality = quality
+                    .into_iter()
+                    .chain(self.text_quality(|| words(alias), worst))
+                    .min();
+            }
+        }
+        quality
+    }
+
+    /// Whether the query may match the names of a range of characters with names derived by rule,
+    /// given the prefix of their names (for NR2) or `None` (for NR1).
+    ///
+    /// Each word of the query must match a word of the prefix, or only be a few edits away from
+    /// words made of Jamo short names (for NR1) or hexadecimal digits (for NR2).
+    fn may_match_derived(&self, prefix: Option<&str>, worst: MatchQuality) -> bool {
+        let (prefix, is_derived_byte): (&str, fn(&u8) -> bool) = match prefix {
+            Some(prefix) => (prefix, u8::is_ascii_hexdigit),
+            None => (PREFIX_HANGUL_SYLLABLE, u8::is_ascii_alphabetic),
+        };
+        // Exact matches have the words of the query as whole words, as well.
+        let worst = worst.max(MatchQuality::Words);
+        words(self.query).all(|query_word| {
+            words(prefix).any(|word| self.word_quality(query_word, word, worst).is_some())
+                || query_word
+                    .iter()
+                    .filter(|&byte| !is_derived_byte(byte))
+                    .count()
+                    <= self.max_distance.unwrap_or(0)
+        })
+    }
+
+    /// Match quality of a name or alias, given by its words.
+    fn text_quality<'t, F, I>(&self, text_words: F, worst: MatchQuality) -> Option<MatchQuality>
+    where
+        F: Fn() -> I,
+        I: Iterator<Item = &'t [u8]>,
+    {
+        if same_words(words(self.query), text_words()) {
+            return Some(MatchQuality::Exact);
+        }
+        let mut quality = MatchQuality::Words;
+        for query_word in words(self.query) {
+            let word_quality = text_words()
+                .filter_map(|word| self.word_quality(query_word, word, worst))
+                .min()?;
+            quality = quality.max(word_quality);
+        }
+        Some(quality)
+    }
+
+    fn word_quality(
+        &self,
+        query_word: &[u8],
+        word: &[u8],
+        worst: MatchQuality,
+    ) -> Option<MatchQuality> {
+        if worst >= MatchQuality::Words && query_word.eq_ignore_ascii_case(word) {
+            Some(MatchQuality::Words)
+        } else if worst >= MatchQuality::Prefixes
+            && word.len() > query_word.len()
+            && word[..query_word.len()].eq_ignore_ascii_case(query_word)
+        {
+            Some(MatchQuality::Prefixes)
+        } else if worst >= MatchQuality::Substrings
+            && word
+                .windows(query_word.len())
+                .any(|window| window.eq_ignore_ascii_case(query_word))
+        {
+            Some(MatchQuality::Substrings)
+        } else if worst >= MatchQuality::Fuzzy {
+            let max_distance = self.max_distance?;
+            match edit_distance(query_word, word) {
+                Some(distance) if distance <= max_distance => Some(MatchQuality::Fuzzy),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for SearchNames<'a> {
+    type Item = (char, Name);
+
+    fn next(&mut self) -> Option<(char, Name)> {
+        loop {
+            let quality = self.quality?;
+            if let Some(prefix) = self.candidates.derived_range_start() {
+                if !self.may_match_derived(prefix, quality) {
+                    self.candidates.skip_derived_range();
+                    continue;
+                }
+            }
+            match self.candidates.next() {
+                Some((ch, name)) => {
+                    if self.match_quality(ch, name, quality) == Some(quality) {
+                        return Some((ch, name));
+                    }
+                }
+                None => {
+                    self.quality = self.next_quality(quality);
+                    self.candidates = Candidates::default();
+                }
+            }
+        }
+    }
+}
+
+fn words(text: &str) -> impl Iterator<Item = &[u8]> {
+    text.as_bytes()
+        .split(|&byte| byte.is_ascii_whitespace() || byte == b'-' || byte == b'_')
+        .filter(|word| !word.is_empty())
+}
+
+fn same_words<'a, 'b>(
+    mut query_words: impl Iterator<Item = &'a [u8]>,
+    mut text_words: impl Iterator<Item = &'b [u8]>,
+) -> bool {
+    loop {
+        match (query_words.next(), text_words.next()) {
+            (None, None) => return true,
+            (Some(query_word), Some(word)) if query_word.eq_ignore_ascii_case(word) => {}
+            _ => return false,
+        }
+    }
+}
+
+/// Levenshtein distance between two words, ignoring ASCII case, unless the second word is too
+/// long.
+fn edit_distance(lhs: &[u8], rhs: &[u8]) -> Option<usize> {
+    if rhs.len() >= WORD_BUFFER_SIZE {
+        return None;
+    }
+    let mut row = [0; WORD_BUFFER_SIZE];
+    for (j, distance) in row.iter_mut().enumerate().take(rhs.len() + 1) {
+        *distance = j;
+    }
+    for (i, lhs_byte) in lhs.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, rhs_byte) in rhs.iter().enumerate() {
+            let substitution = diagonal + usize::from(!lhs_byte.eq_ignore_ascii_case(rhs_byte));
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(diagonal + 1).min(row[j] + 1);
+        }
+    }
+    Some(row[rhs.len()])
+}
+
+/// Stack buffer for writing a name.
+struct NameBuffer {
+    bytes: [u8; NAME_BUFFER_SIZE],
+    len: usize,
+}
+
+impl Default for NameBuffer {
+    fn default() -> NameBuffer {
+        NameBuffer {
+            bytes: [0; NAME_BUFFER_SIZE],
+            len: 0,
+        }
+    }
+}
+
+impl NameBuffer {
+    fn as_str(&self) -> &str {
+        str::from_utf8(&self.bytes[..self.len]).unwrap_or("")
+    }
+}
+
+impl Write for NameBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > NAME_BUFFER_SIZE {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Iterator over all characters with a `Name`, in code point order.
+#[derive(Clone, Debug)]
+struct Candidates {
+    /// Index of the next explicitly listed name.
+    explicit: usize,
+    /// Index of the range of the next character with a name derived by rule.
+    derived: usize,
+    /// The next character with a name derived by rule.
+    next_derived: Option<char>,
+}
+
+impl Default for Candidates {
+    fn default() -> Candidates {
+        Candidates {
+            explicit: 0,
+            derived: 0,
+            next_derived: DERIVED_NAME_RANGES.first().map(|&(first, _, _)| first),
+        }
+    }
+}
+
+impl Candidates {
+    /// The explicitly listed names, in code point order.
+    ///
+    /// `unic-gen` writes the names table with one entry per character, so it is never a range
+    /// table; `test_search_names` checks that all of it is searched.
+    fn explicit_names() -> &'static [(char, &'static [&'static str])] {
+        match name::data::NAMES {
+            CharDataTable::Direct(table) => table,
+            CharDataTable::Range(_) => &[],
+        }
+    }
+
+    /// The prefix of the names of the range of characters with names derived by rule, when the
+    /// next candidate is the first character of that range.
+    fn derived_range_start(&self) -> Option<Option<&'static str>> {
+        let &(first, _, prefix) = DERIVED_NAME_RANGES.get(self.derived)?;
+        if self.next_derived == Some(first) {
+            Some(prefix)
+        } else {
+            None
+        }
+    }
+
+    fn skip_derived_range(&mut self) {
+        self.derived += 1;
+        self.next_derived = DERIVED_NAME_RANGES
+            .get(self.derived)
+            .map(|&(first, _, _)| first);
+    }
+
+    fn advance_derived(&mut self, ch: char) {
+        let (_, last, _) = DERIVED_NAME_RANGES[self.derived];
+        if ch < last {
+            self.next_derived = core::char::from_u32(ch as u32 + 1);
+        } else {
+            self.skip_derived_range();
+        }
+    }
+}
+
+impl Iterator for Candidates {
+    type Item = (char, Name);
+
+    fn next(&mut self) -> Option<(char, Name)> {
+        loop {
+            let explicit = Candidates::explicit_names()
+                .get(self.explicit)
+                .map(|&(ch, _)| ch);
+            match (explicit, self.next_derived) {
+                (Some(ch), Some(derived)) if ch >= derived => {
+                    self.advance_derived(derived);
+                    return Name::of(derived).map(|name| (derived, name));
+                }
+                (Some(ch), _) => {
+                    self.explicit += 1;
+                    // Skip explicitly listed names of characters with names derived by rule.
+                    if let Some(name @ Name::NR3(_)) = Name::of(ch) {
+                        return Some((ch, name));
+                    }
+                }
+                (None, Some(derived)) => {
+                    self.advance_derived(derived);
+                    return Name::of(derived).map(|name| (derived, name));
+                }
+                (None, None) => return None,
+            }
+        }
+    }
+}
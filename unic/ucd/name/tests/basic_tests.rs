@@ -8,7 +8,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use unic_ucd_name::Name;
+use unic_ucd_name::{
+    char_from_name,
+    named_sequence,
+    search_names,
+    search_names_fuzzy,
+    sequence_name,
+    string_from_name,
+    Name,
+    NamedString,
+};
 
 #[test]
 fn test_name_str() {
@@ -111,3 +120,237 @@ fn test_name_cmp() {
     name_cmp_assert!('A', >, '理');
     name_cmp_assert!('A', <, 'B');
 }
+
+#[test]
+fn test_char_from_name() {
+    // == NR1: Hangul Syllable ==
+
+    assert_eq!(char_from_name("HANGUL SYLLABLE GOBS"), Some('곲'));
+    assert_eq!(char_from_name("HANGUL SYLLABLE GA"), Some('가'));
+    assert_eq!(char_from_name("HANGUL SYLLABLE A"), Some('아'));
+    assert_eq!(char_from_name("hangul syllable nwelm"), Some('뉆'));
+    assert_eq!(char_from_name("HANGUL SYLLABLE"), None);
+    assert_eq!(char_from_name("HANGUL SYLLABLE GOBSX"), None);
+
+    // == NR2: Ideographs ==
+
+    assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-8A00"), Some('言'));
+    assert_eq!(char_from_name("cjk unified ideograph-8a00"), Some('言'));
+    assert_eq!(char_from_name("TANGUT IDEOGRAPH-17005"), Some('\u{17005}'));
+    assert_eq!(char_from_name("NUSHU CHARACTER-1B175"), Some('\u{1B175}'));
+    assert_eq!(
+        char_from_name("CJK COMPATIBILITY IDEOGRAPH-F9E4"),
+        Some('\u{F9E4}')
+    );
+    assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-08A00"), None);
+    assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-0041"), None);
+    assert_eq!(char_from_name("TANGUT IDEOGRAPH-8A00"), None);
+
+    // == NR3: Explicitly Listed Names ==
+
+    assert_eq!(char_from_name("LATIN CAPITAL LETTER A"), Some('A'));
+    assert_eq!(
+        char_from_name("LATIN SMALL LETTER A WITH ACUTE"),
+        Some('\u{E1}')
+    );
+    assert_eq!(char_from_name("TIBETAN MARK TSA -PHRU"), Some('\u{F39}'));
+    assert_eq!(char_from_name("ZERO WIDTH SPACE"), Some('\u{200B}'));
+    assert_eq!(char_from_name("LATIN CAPITAL LETTER"), None);
+    assert_eq!(char_from_name(""), None);
+
+    // == Loose Matching ==
+
+    assert_eq!(
+        char_from_name("latin_small_letter_a_with_acute"),
+        Some('\u{E1}')
+    );
+    assert_eq!(
+        char_from_name("  Latin Small Letter A   With Acute "),
+        Some('\u{E1}')
+    );
+    assert_eq!(char_from_name("ZERO-WIDTH-SPACE"), Some('\u{200B}'));
+    assert_eq!(char_from_name("zerowidthspace"), Some('\u{200B}'));
+    assert_eq!(char_from_name("TIBETAN MARK TSA PHRU"), None);
+    assert_eq!(char_from_name("HANGUL JUNGSEONG O-E"), Some('\u{1180}'));
+    assert_eq!(char_from_name("hangul_jungseong_o-e"), Some('\u{1180}'));
+    assert_eq!(char_from_name("HANGUL JUNGSEONG OE"), Some('\u{116C}'));
+    assert_eq!(char_from_name("LATIN SMALL LETTER \u{C0}"), None);
+
+    // == Name Aliases ==
+
+    assert_eq!(char_from_name("LATIN CAPITAL LETTER GHA"), Some('\u{1A2}'));
+    assert_eq!(char_from_name("LATIN CAPITAL LETTER OI"), Some('\u{1A2}'));
+    assert_eq!(char_from_name("NULL"), Some('\u{0}'));
+    assert_eq!(char_from_name("NUL"), Some('\u{0}'));
+    assert_eq!(char_from_name("line feed"), Some('\n'));
+    assert_eq!(char_from_name("BYTE ORDER MARK"), Some('\u{FEFF}'));
+    assert_eq!(char_from_name("BOM"), Some('\u{FEFF}'));
+}
+
+#[test]
+fn test_char_from_name_round_trip() {
+    for ch in (0..=0x10_FFFF).filter_map(std::char::from_u32) {
+        if let Some(name) = Name::of(ch) {
+            assert_eq!(char_from_name(&name.to_string()), Some(ch), "{}", name);
+        }
+    }
+}
+
+#[test]
+fn test_named_sequences() {
+    assert_eq!(
+        named_sequence("LATIN CAPITAL LETTER A WITH MACRON AND GRAVE"),
+        Some("\u{100}\u{300}")
+    );
+    assert_eq!(
+        named_sequence("KEYCAP NUMBER SIGN"),
+        Some("#\u{FE0F}\u{20E3}")
+    );
+    assert_eq!(
+        named_sequence("tamil syllable kssa"),
+        Some("\u{B95}\u{BCD}\u{BB7}")
+    );
+    assert_eq!(
+        named_sequence("Bengali_Letter_Khinya"),
+        Some("\u{995}\u{9CD}\u{9B7}")
+    );
+    assert_eq!(named_sequence("KEYCAP"), None);
+    assert_eq!(named_sequence("LATIN CAPITAL LETTER A"), None);
+    assert_eq!(named_sequence(""), None);
+
+    assert_eq!(
+        sequence_name("#\u{FE0F}\u{20E3}"),
+        Some("KEYCAP NUMBER SIGN")
+    );
+    assert_eq!(sequence_name("#\u{20E3}"), None);
+    assert_eq!(sequence_name("A"), None);
+    assert_eq!(sequence_name(""), None);
+
+    for name in &[
+        "LATIN SMALL LETTER U WITH MACRON AND GRAVE",
+        "KEYCAP DIGIT NINE",
+        "GEORGIAN LETTER U-BRJGU",
+        "KHMER CONSONANT SIGN COENG KA",
+        "HIRAGANA LETTER BIDAKUON NGA",
+    ] {
+        let sequence = named_sequence(name).unwrap();
+        assert_eq!(sequence_name(sequence), Some(*name));
+    }
+
+    // Named sequences are not characters
+    assert_eq!(char_from_name("KEYCAP NUMBER SIGN"), None);
+}
+
+#[test]
+fn test_string_from_name() {
+    assert_eq!(
+        string_from_name("LATIN CAPITAL LETTER A"),
+        Some(NamedString::Char('A'))
+    );
+    assert_eq!(
+        string_from_name("HANGUL SYLLABLE GOBS"),
+        Some(NamedString::Char('곲'))
+    );
+    assert_eq!(string_from_name("BOM"), Some(NamedString::Char('\u{FEFF}')));
+    assert_eq!(
+        string_from_name("KEYCAP NUMBER SIGN"),
+        Some(NamedString::Sequence("#\u{FE0F}\u{20E3}"))
+    );
+    assert_eq!(string_from_name("KEYCAP"), None);
+    assert_eq!(string_from_name(""), None);
+
+    assert_eq!(NamedString::Char('A').to_string(), "A");
+    assert_eq!(
+        NamedString::Sequence("#\u{FE0F}\u{20E3}").to_string(),
+        "#\u{FE0F}\u{20E3}"
+    );
+}
+
+#[test]
+fn test_search_names() {
+    fn search(query: &str) -> Vec<char> {
+        search_names(query).map(|(ch, _)| ch).collect()
+    }
+
+    // == Ranking ==
+
+    let found = search("latin small letter a");
+    assert_eq!(found[0], 'a');
+    assert!(found.contains(&'\u{E1}'));
+    assert!(found.iter().position(|&ch| ch == '\u{E1}') > found.iter().position(|&ch| ch == 'a'));
+
+    let found = search("DIGIT ZE");
+    assert_eq!(found[0], '0');
+
+    let found = search("igit zer");
+    assert_eq!(found[0], '0');
+
+    // Whole words before prefixes
+    let found = search("DIGIT");
+    let digit = found.iter().position(|&ch| ch == '\u{1F100}').unwrap();
+    let digits = found.iter().position(|&ch| ch == '\u{1DA86}').unwrap();
+    assert!(digit < digits);
+
+    // == Explicitly Listed Names ==
+
+    assert_eq!(search("LATIN CAPITAL LETTER A")[0], 'A');
+    assert_eq!(search("ZERO WIDTH SPACE")[0], '\u{200B}');
+
+    // All of the names table is searched
+    let found = search("LATIN");
+    for ch in (0..=0x10_FFFF).filter_map(std::char::from_u32) {
+        if let Some(Name::NR3(pieces)) = Name::of(ch) {
+            if pieces.contains(&"LATIN") {
+                assert!(found.contains(&ch), "{:?}", ch);
+            }
+        }
+    }
+
+    // == Derived Names ==
+
+    assert_eq!(search("HANGUL SYLLABLE GOBS")[0], '곲');
+    assert_eq!(search("cjk ideograph 8a00")[0], '言');
+    assert_eq!(search("TANGUT IDEOGRAPH-17005")[0], '\u{17005}');
+    assert_eq!(search("nushu").len(), 397);
+
+    // == Name Aliases ==
+
+    assert_eq!(search("ZWSP")[0], '\u{200B}');
+    assert_eq!(search("byte order mark")[0], '\u{FEFF}');
+    // Characters without a name
+    assert_eq!(search("NULL")[0], '\u{2400}');
+    assert!(!search("NULL").contains(&'\u{0}'));
+
+    // == Results ==
+
+    for (ch, name) in search_names("arrow double").take(100) {
+        assert_eq!(Name::of(ch), Some(name));
+        let name = name.to_string();
+        assert!(name.contains("ARROW"), "{}", name);
+        assert!(name.contains("DOUBLE"), "{}", name);
+    }
+
+    assert_eq!(search(""), Vec::<char>::new());
+    assert_eq!(search(" - "), Vec::<char>::new());
+    assert_eq!(search("NOT A CHARACTER NAME QWERTY"), Vec::<char>::new());
+}
+
+#[test]
+fn test_search_names_fuzzy() {
+    assert_eq!(search_names("GREEK SMALL LETTER ALHPA").next(), None);
+    assert_eq!(
+        search_names_fuzzy("GREEK SMALL LETTER ALHPA", 1).next(),
+        None
+    );
+
+    let found: Vec<char> = search_names_fuzzy("GREEK SMALL LETTER ALHPA", 2)
+        .map(|(ch, _)| ch)
+        .collect();
+    assert!(found.contains(&'\u{3B1}'));
+
+    // Exact matches still come first
+    let (ch, _) = search_names_fuzzy("LATIN SMALL LETTER A", 1)
+        .next()
+        .unwrap();
+    assert_eq!(ch, 'a');
+}
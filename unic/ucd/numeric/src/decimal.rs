@@ -0,0 +1,334 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing and formatting of integers written with the decimal digits of any script.
+//!
+//! Decimal digits (`Numeric_Type=Decimal`) are encoded in contiguous ascending ranges from zero
+//! to nine, so each decimal system is identified by its digit zero.
+//!
+//! * <https://www.unicode.org/versions/latest/ch04.pdf> (Section 4.6, Numeric Value)
+
+use core::char;
+use core::fmt;
+
+use crate::numeric_type::NumericType;
+use crate::numeric_value::numeric_value;
+
+/// Integer types that decimal numbers can be parsed into and formatted from.
+///
+/// Implemented for all primitive integer types.
+pub trait DecimalInteger: Copy {
+    #[doc(hidden)]
+    fn zero() -> Self;
+
+    /// `self * 10 + digit`, or `self * 10 - digit` for negative numbers, if it does not overflow.
+    #[doc(hidden)]
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn to_sign_magnitude(self) -> (bool, u128);
+}
+
+macro_rules! impl_decimal_integer {
+    ($($int:ty)*; $($uint:ty)*) => {
+        $(
+            impl DecimalInteger for $int {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                    let value = self.checked_mul(10)?;
+                    if negative {
+                        value.checked_sub(digit as $int)
+                    } else {
+                        value.checked_add(digit as $int)
+                    }
+                }
+
+                fn to_sign_magnitude(self) -> (bool, u128) {
+                    (self < 0, (self as i128).wrapping_abs() as u128)
+                }
+            }
+        )*
+        $(
+            impl DecimalInteger for $uint {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                    let value = self.checked_mul(10)?;
+                    if negative {
+                        value.checked_sub(digit as $uint)
+                    } else {
+                        value.checked_add(digit as $uint)
+                    }
+                }
+
+                fn to_sign_magnitude(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_decimal_integer!(i8 i16 i32 i64 i128 isize; u8 u16 u32 u64 u128 usize);
+
+/// Kinds of errors of parsing decimal numbers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum DecimalErrorKind {
+    /// An empty string.
+    Empty,
+
+    /// A character that is not a decimal digit, or a sign without digits.
+    InvalidDigit,
+
+    /// A decimal digit from another decimal system than the first digit.
+    MixedDigits,
+
+    /// A number too large or too small for the target type.
+    Overflow,
+}
+
+impl fmt::Display for DecimalErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            DecimalErrorKind::Empty => "cannot parse number from empty string",
+            DecimalErrorKind::InvalidDigit => "invalid digit found in string",
+            DecimalErrorKind::MixedDigits => "digits from more than one decimal system",
+            DecimalErrorKind::Overflow => "number too large or too small to fit in target type",
+        })
+    }
+}
+
+/// An error of parsing a decimal number, located at the first offending code point.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ParseDecimalError {
+    kind: DecimalErrorKind,
+    offset: usize,
+}
+
+impl ParseDecimalError {
+    /// The kind of the error.
+    pub fn kind(&self) -> DecimalErrorKind {
+        self.kind
+    }
+
+    /// The byte offset of the offending code point in the string, or of the end of the string
+    /// if the digits are missing.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDecimalError {}
+
+/// The value of the character, if it is a decimal digit (`Numeric_Type=Decimal`).
+fn decimal_digit(ch: char) -> Option<u8> {
+    if NumericType::of(ch) != Some(NumericType::Decimal) {
+        return None;
+    }
+    numeric_value(ch)
+        .and_then(|value| value.to_integer())
+        .map(|value| value as u8)
+}
+
+/// Parse an integer written with the decimal digits of a single decimal system, such as ASCII,
+/// Arabic-Indic, Devanagari or fullwidth digits.
+///
+/// The digits can be preceded by a `+` or `-` sign. Digits from different decimal systems cannot
+/// be mixed.
+///
+/// ```rust
+/// use unic_ucd_numeric::{parse_decimal, DecimalErrorKind};
+///
+/// assert_eq!(parse_decimal::<u32>("123"), Ok(123));
+/// assert_eq!(parse_decimal::<u32>("\u{661}\u{662}\u{663}"), Ok(123));
+/// assert_eq!(parse_decimal::<i32>("-\u{967}\u{968}\u{969}"), Ok(-123));
+///
+/// let error = parse_decimal::<u32>("1\u{662}3").unwrap_err();
+/// assert_eq!(error.kind(), DecimalErrorKind::MixedDigits);
+/// assert_eq!(error.offset(), 1);
+/// ```
+pub fn parse_decimal<T: DecimalInteger>(s: &str) -> Result<T, ParseDecimalError> {
+    let error = |kind, offset| Err(ParseDecimalError { kind, offset });
+
+    if s.is_empty() {
+        return error(DecimalErrorKind::Empty, 0);
+    }
+
+    let (negative, digits_offset) = match s.as_bytes()[0] {
+        b'+' => (false, 1),
+        b'-' => (true, 1),
+        _ => (false, 0),
+    };
+    if digits_offset == s.len() {
+        return error(DecimalErrorKind::InvalidDigit, digits_offset);
+    }
+
+    let mut value = T::zero();
+    let mut zero = None;
+    for (offset, ch) in s[digits_offset..].char_indices() {
+        let offset = digits_offset + offset;
+        let digit = match decimal_digit(ch) {
+            Some(digit) => digit,
+            None => return error(DecimalErrorKind::InvalidDigit, offset),
+        };
+        let digit_zero = ch as u32 - u32::from(digit);
+        if *zero.get_or_insert(digit_zero) != digit_zero {
+            return error(DecimalErrorKind::MixedDigits, offset);
+        }
+        value = match value.push_digit(digit, negative) {
+            Some(value) => value,
+            None => return error(DecimalErrorKind::Overflow, offset),
+        };
+    }
+    Ok(value)
+}
+
+/// An integer written with the decimal digits of a decimal system, for display.
+///
+/// Returned by [`to_digits()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Digits {
+    negative: bool,
+    magnitude: u128,
+    zero: char,
+}
+
+impl fmt::Display for Digits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // u128::MAX has 39 decimal digits.
+        let mut digits = [0u8; 39];
+        let mut len = 0;
+        let mut magnitude = self.magnitude;
+        loop {
+            digits[len] = (magnitude % 10) as u8;
+            len += 1;
+            magnitude /= 10;
+            if magnitude == 0 {
+                break;
+            }
+        }
+
+        if self.negative {
+            f.write_str("-")?;
+        }
+        for &digit in digits[..len].iter().rev() {
+            let ch = char::from_u32(self.zero as u32 + u32::from(digit)).unwrap();
+            write!(f, "{}", ch)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write an integer with the decimal digits of the decimal system of `script_zero`, such as
+/// `'\u{660}'` ARABIC-INDIC DIGIT ZERO or `'\u{966}'` DEVANAGARI DIGIT ZERO.
+///
+/// Return `None` if `script_zero` is not a decimal digit zero.
+///
+/// ```rust
+/// use unic_ucd_numeric::to_digits;
+///
+/// assert_eq!(to_digits(123, '\u{660}').unwrap().to_string(), "\u{661}\u{662}\u{663}");
+/// assert_eq!(to_digits(-45, '\u{ff10}').unwrap().to_string(), "-\u{ff14}\u{ff15}");
+/// assert_eq!(to_digits(1, 'a'), None);
+/// ```
+pub fn to_digits<T: DecimalInteger>(n: T, script_zero: char) -> Option<Digits> {
+    if decimal_digit(script_zero) != Some(0) {
+        return None;
+    }
+    let (negative, magnitude) = n.to_sign_magnitude();
+    Some(Digits {
+        negative,
+        magnitude,
+        zero: script_zero,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_decimal, to_digits, DecimalErrorKind as EK};
+
+    fn error_of<T: super::DecimalInteger>(s: &str) -> (EK, usize) {
+        let error = parse_decimal::<T>(s).err().unwrap();
+        (error.kind(), error.offset())
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal::<u32>("0"), Ok(0));
+        assert_eq!(parse_decimal::<u32>("0042"), Ok(42));
+        assert_eq!(parse_decimal::<i32>("+42"), Ok(42));
+        assert_eq!(parse_decimal::<i32>("-42"), Ok(-42));
+
+        // ARABIC-INDIC
+        assert_eq!(parse_decimal::<u32>("\u{661}\u{662}\u{663}"), Ok(123));
+        // EXTENDED ARABIC-INDIC
+        assert_eq!(parse_decimal::<u32>("\u{6f1}\u{6f2}\u{6f3}"), Ok(123));
+        // DEVANAGARI
+        assert_eq!(parse_decimal::<u32>("\u{967}\u{968}\u{969}"), Ok(123));
+        // FULLWIDTH
+        assert_eq!(parse_decimal::<u64>("\u{ff19}\u{ff10}"), Ok(90));
+        // MATHEMATICAL BOLD
+        assert_eq!(parse_decimal::<u8>("\u{1d7cf}\u{1d7ce}"), Ok(10));
+    }
+
+    #[test]
+    fn test_parse_decimal_limits() {
+        assert_eq!(parse_decimal::<u8>("255"), Ok(255));
+        assert_eq!(parse_decimal::<i8>("-128"), Ok(-128));
+        assert_eq!(parse_decimal::<i8>("127"), Ok(127));
+        assert_eq!(
+            parse_decimal::<u128>("340282366920938463463374607431768211455"),
+            Ok(u128::MAX)
+        );
+        assert_eq!(error_of::<u8>("256"), (EK::Overflow, 2));
+        assert_eq!(error_of::<i8>("-129"), (EK::Overflow, 3));
+        assert_eq!(error_of::<u8>("-1"), (EK::Overflow, 1));
+        assert_eq!(parse_decimal::<u8>("-0"), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_decimal_errors() {
+        assert_eq!(error_of::<u32>(""), (EK::Empty, 0));
+        assert_eq!(error_of::<u32>("-"), (EK::InvalidDigit, 1));
+        assert_eq!(error_of::<u32>("12a"), (EK::InvalidDigit, 2));
+        assert_eq!(error_of::<u32>(" 12"), (EK::InvalidDigit, 0));
+        assert_eq!(error_of::<u32>("1.5"), (EK::InvalidDigit, 1));
+
+        // Digit, not Decimal: SUPERSCRIPT TWO
+        assert_eq!(error_of::<u32>("\u{b2}"), (EK::InvalidDigit, 0));
+        // Numeric, not Decimal: VULGAR FRACTION ONE HALF
+        assert_eq!(error_of::<u32>("1\u{bd}"), (EK::InvalidDigit, 1));
+
+        assert_eq!(error_of::<u32>("1\u{662}3"), (EK::MixedDigits, 1));
+        assert_eq!(error_of::<u32>("\u{661}\u{6f2}"), (EK::MixedDigits, 2));
+        assert_eq!(error_of::<u32>("\u{ff11}2"), (EK::MixedDigits, 3));
+    }
+
+    #[test]
+    fn test_to_digits() {
+        assert!(to_digits(0, '0').is_some());
+        assert!(to_digits(0u8, '\u{660}').is_some());
+        assert!(to_digits(0, '1').is_none());
+        assert!(to_digits(0, '\u{2070}').is_none());
+        assert!(to_digits(0, '\u{3007}').is_none());
+    }
+}
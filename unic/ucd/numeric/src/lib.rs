@@ -0,0 +1,64 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(
+    bad_style,
+    missing_debug_implementations,
+    missing_docs,
+    unconditional_recursion
+)]
+#![forbid(unsafe_code)]
+
+//! # UNIC — UCD — Numeric
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! Accessor for the `Numeric_Type` and `Numeric_Value` character properties from Unicode
+//! Character Database (UCD)
+//!
+//! * <https://www.unicode.org/reports/tr44/#Numeric_Type>
+//!
+//! Also provides parsing and formatting of integers written with the decimal digits of any script,
+//! with [`parse_decimal()`] and [`to_digits()`].
+//!
+//! NOTE: Only the values from `UnicodeData.txt` are provided. The numeric values of CJK
+//! ideographs from the Unihan database (`kPrimaryNumeric`, `kAccountingNumeric` and
+//! `kOtherNumeric`) are not included.
+
+#[macro_use]
+extern crate unic_char_property;
+
+#[macro_use]
+extern crate unic_char_range;
+
+mod pkg_info;
+pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
+
+pub mod numeric_type;
+pub use crate::numeric_type::NumericType;
+
+pub mod numeric_value;
+pub use crate::numeric_value::{numeric_value, NumericValue, Rational};
+
+pub mod decimal;
+pub use crate::decimal::{
+    parse_decimal,
+    to_digits,
+    DecimalErrorKind,
+    DecimalInteger,
+    Digits,
+    ParseDecimalError,
+};
+
+use unic_ucd_version::UnicodeVersion;
+
+/// The [Unicode version](https://www.unicode.org/versions/) of data
+pub const UNICODE_VERSION: UnicodeVersion = include!("../tables/unicode_version.rsv");
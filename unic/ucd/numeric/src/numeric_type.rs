@@ -0,0 +1,130 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Numeric_Type` Character Property.
+
+use unic_char_property::{tables::CharDataTable, PartialCharProperty, TableCharProperty};
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`Numeric_Type`](https://www.unicode.org/reports/tr44/#Numeric_Type) property.
+    ///
+    /// Characters without a numeric value have no `Numeric_Type` (`None` in UCD).
+    ///
+    /// * <https://www.unicode.org/Public/UCD/latest/ucd/extracted/DerivedNumericType.txt>
+    pub enum NumericType {
+        abbr => "nt";
+        long => "Numeric_Type";
+        human => "Numeric Type";
+
+        /// Digits of a decimal radix positional numeral system, such as DIGIT ZERO
+        Decimal {
+            abbr => De,
+            long => Decimal,
+            human => "Decimal",
+        }
+
+        /// Digits that need special handling, such as SUPERSCRIPT TWO
+        Digit {
+            abbr => Di,
+            long => Digit,
+            human => "Digit",
+        }
+
+        /// Other numeric characters, such as fractions and Roman numerals
+        Numeric {
+            abbr => Nu,
+            long => Numeric,
+            human => "Numeric",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Numeric_Type`](https://www.unicode.org/reports/tr44/#Numeric_Type)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Numeric_Type>
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Numeric_Type`](https://www.unicode.org/reports/tr44/#Numeric_Type)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Numeric_Type>
+    pub mod long_names for long;
+}
+
+impl PartialCharProperty for NumericType {
+    fn of(ch: char) -> Option<Self> {
+        Self::of(ch)
+    }
+}
+
+impl TableCharProperty for NumericType {
+    type TableValue = NumericType;
+
+    fn table() -> CharDataTable<NumericType> {
+        data::NUMERIC_TYPE_TABLE
+    }
+}
+
+mod data {
+    use super::abbr_names as NT;
+    use unic_char_property::tables::CharDataTable;
+    pub const NUMERIC_TYPE_TABLE: CharDataTable<super::NumericType> =
+        include!("../tables/numeric_type.rsv");
+}
+
+impl NumericType {
+    /// Find the character `Numeric_Type` property value, if any.
+    pub fn of(ch: char) -> Option<NumericType> {
+        data::NUMERIC_TYPE_TABLE.find(ch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumericType as NT;
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(NT::of('0'), Some(NT::Decimal));
+        assert_eq!(NT::of('9'), Some(NT::Decimal));
+        assert_eq!(NT::of('\u{0663}'), Some(NT::Decimal));
+        assert_eq!(NT::of('\u{FF15}'), Some(NT::Decimal));
+        assert_eq!(NT::of('\u{1D7CE}'), Some(NT::Decimal));
+
+        assert_eq!(NT::of('\u{00B2}'), Some(NT::Digit));
+        assert_eq!(NT::of('\u{2460}'), Some(NT::Digit));
+
+        assert_eq!(NT::of('\u{00BD}'), Some(NT::Numeric));
+        assert_eq!(NT::of('\u{2167}'), Some(NT::Numeric));
+        assert_eq!(NT::of('\u{3007}'), Some(NT::Numeric));
+
+        assert_eq!(NT::of('a'), None);
+        assert_eq!(NT::of('\u{10FFFF}'), None);
+    }
+
+    #[test]
+    fn test_abbr_name() {
+        assert_eq!(NT::Decimal.abbr_name(), "De");
+        assert_eq!(NT::Numeric.abbr_name(), "Nu");
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(NT::Digit.long_name(), "Digit");
+    }
+}
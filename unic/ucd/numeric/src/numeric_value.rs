@@ -0,0 +1,244 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Numeric_Value` Character Property.
+
+use core::cmp::Ordering;
+use core::fmt;
+
+use unic_char_property::{
+    CharProperty,
+    NumericCharProperty,
+    NumericCharPropertyValue,
+    PartialCharProperty,
+};
+
+/// An exact rational number, as used for values of the `Numeric_Value` property.
+///
+/// Values are kept in lowest terms, with the sign on the numerator, so that equal numbers compare
+/// equal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: u32,
+}
+
+impl Rational {
+    /// Create a rational number from a numerator and a (non-zero) denominator, reduced to lowest
+    /// terms.
+    ///
+    /// # Panics
+    ///
+    /// If `denominator` is zero.
+    pub fn new(numerator: i64, denominator: u32) -> Rational {
+        assert_ne!(denominator, 0, "zero denominator");
+        let divisor = gcd(numerator.wrapping_abs() as u64, u64::from(denominator));
+        Rational {
+            numerator: numerator / divisor as i64,
+            denominator: (u64::from(denominator) / divisor) as u32,
+        }
+    }
+
+    /// The numerator, carrying the sign of the number.
+    #[inline]
+    pub fn numerator(self) -> i64 {
+        self.numerator
+    }
+
+    /// The denominator, which is `1` for integers.
+    #[inline]
+    pub fn denominator(self) -> u32 {
+        self.denominator
+    }
+
+    /// Whether the number is an integer.
+    #[inline]
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    /// The number as an integer, if it is one.
+    pub fn to_integer(self) -> Option<i64> {
+        if self.is_integer() {
+            Some(self.numerator)
+        } else {
+            None
+        }
+    }
+
+    /// The number as a floating-point approximation.
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / f64::from(self.denominator)
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+impl From<i64> for Rational {
+    fn from(integer: i64) -> Rational {
+        Rational {
+            numerator: integer,
+            denominator: 1,
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        let lhs = i128::from(self.numerator) * i128::from(other.denominator);
+        let rhs = i128::from(other.numerator) * i128::from(self.denominator);
+        lhs.cmp(&rhs)
+    }
+}
+
+/// Formats as `numerator/denominator`, or only the numerator for integers, as in UCD data files.
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl NumericCharPropertyValue for Rational {}
+
+/// Represents the Unicode character
+/// [`Numeric_Value`](https://www.unicode.org/reports/tr44/#Numeric_Value) property.
+///
+/// Characters without a numeric value have no `Numeric_Value` (`NaN` in UCD).
+///
+/// * <https://www.unicode.org/Public/UCD/latest/ucd/extracted/DerivedNumericValues.txt>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NumericValue(Rational);
+
+impl CharProperty for NumericValue {
+    fn prop_abbr_name() -> &'static str {
+        "nv"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Numeric_Value"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Numeric Value"
+    }
+}
+
+impl PartialCharProperty for NumericValue {
+    fn of(ch: char) -> Option<Self> {
+        Self::of(ch)
+    }
+}
+
+impl NumericCharProperty<Rational> for NumericValue {
+    /// Get numeric value for character property value
+    fn number(&self) -> Rational {
+        Self::number(self)
+    }
+}
+
+mod data {
+    use unic_char_property::tables::CharDataTable;
+    pub const NUMERIC_VALUES_TABLE: CharDataTable<(i64, u32)> =
+        include!("../tables/numeric_values.rsv");
+}
+
+impl NumericValue {
+    /// Find the character `Numeric_Value` property value, if any.
+    pub fn of(ch: char) -> Option<NumericValue> {
+        data::NUMERIC_VALUES_TABLE
+            .find(ch)
+            .map(|(numerator, denominator)| {
+                NumericValue(Rational {
+                    numerator,
+                    denominator,
+                })
+            })
+    }
+
+    /// Get numeric value for character property value
+    pub fn number(&self) -> Rational {
+        self.0
+    }
+}
+
+impl fmt::Display for NumericValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Return the exact `Numeric_Value` of the character, if any.
+pub fn numeric_value(ch: char) -> Option<Rational> {
+    NumericValue::of(ch).map(|value| value.number())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{numeric_value, NumericValue, Rational};
+    use unic_char_property::NumericCharProperty;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(numeric_value('0'), Some(Rational::from(0)));
+        assert_eq!(numeric_value('7'), Some(Rational::from(7)));
+        assert_eq!(numeric_value('\u{00B2}'), Some(Rational::from(2)));
+        assert_eq!(numeric_value('\u{00BD}'), Some(Rational::new(1, 2)));
+        assert_eq!(numeric_value('\u{0F33}'), Some(Rational::new(-1, 2)));
+        assert_eq!(numeric_value('\u{2180}'), Some(Rational::from(1000)));
+        assert_eq!(numeric_value('\u{216B}'), Some(Rational::from(12)));
+        assert_eq!(numeric_value('\u{3007}'), Some(Rational::from(0)));
+        assert_eq!(
+            numeric_value('\u{16B61}'),
+            Some(Rational::from(1_000_000_000_000))
+        );
+
+        assert_eq!(numeric_value('a'), None);
+        assert_eq!(numeric_value('\u{10FFFF}'), None);
+    }
+
+    #[test]
+    fn test_numeric_value_property() {
+        let value = NumericValue::of('\u{2155}').unwrap();
+        assert_eq!(NumericCharProperty::number(&value), Rational::new(1, 5));
+    }
+
+    #[test]
+    fn test_rational() {
+        assert_eq!(Rational::new(2, 4), Rational::new(1, 2));
+        assert_eq!(Rational::new(-6, 3), Rational::from(-2));
+        assert_eq!(Rational::new(0, 5), Rational::from(0));
+        assert_eq!(Rational::new(3, 4).numerator(), 3);
+        assert_eq!(Rational::new(3, 4).denominator(), 4);
+        assert_eq!(Rational::new(3, 4).to_integer(), None);
+        assert_eq!(Rational::new(8, 4).to_integer(), Some(2));
+        assert_eq!(Rational::new(3, 4).to_f64(), 0.75);
+
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::from(0));
+        assert!(Rational::from(1000) > Rational::new(999, 1));
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_ucd_numeric::{
+    parse_decimal,
+    to_digits,
+    NumericType as NT,
+    NumericValue as NV,
+    Rational,
+};
+
+#[test]
+fn test_numeric_type_display() {
+    assert_eq!(format!("{}", NT::of('7').unwrap()), "Decimal");
+    assert_eq!(format!("{}", NT::of('\u{00BD}').unwrap()), "Numeric");
+}
+
+#[test]
+fn test_numeric_value_display() {
+    assert_eq!(format!("{}", NV::of('\u{2155}').unwrap()), "1/5");
+    assert_eq!(format!("{}", NV::of('\u{2180}').unwrap()), "1000");
+    assert_eq!(format!("{}", Rational::new(-1, 2)), "-1/2");
+}
+
+#[test]
+fn test_to_digits_display() {
+    assert_eq!(format!("{}", to_digits(0, '0').unwrap()), "0");
+    assert_eq!(format!("{}", to_digits(1905, '0').unwrap()), "1905");
+    assert_eq!(
+        format!("{}", to_digits(123, '\u{660}').unwrap()),
+        "\u{661}\u{662}\u{663}"
+    );
+    assert_eq!(
+        format!("{}", to_digits(-2018, '\u{966}').unwrap()),
+        "-\u{968}\u{966}\u{967}\u{96e}"
+    );
+    assert_eq!(
+        format!("{}", to_digits(i64::MIN, '0').unwrap()),
+        "-9223372036854775808"
+    );
+    assert_eq!(
+        format!("{}", to_digits(u128::MAX, '\u{ff10}').unwrap()),
+        "\u{ff13}\u{ff14}\u{ff10}\u{ff12}\u{ff18}\u{ff12}\u{ff13}\u{ff16}\u{ff16}\u{ff19}\u{ff12}\
+         \u{ff10}\u{ff19}\u{ff13}\u{ff18}\u{ff14}\u{ff16}\u{ff13}\u{ff14}\u{ff16}\u{ff13}\u{ff13}\
+         \u{ff17}\u{ff14}\u{ff16}\u{ff10}\u{ff17}\u{ff14}\u{ff13}\u{ff11}\u{ff17}\u{ff16}\u{ff18}\
+         \u{ff12}\u{ff11}\u{ff11}\u{ff14}\u{ff15}\u{ff15}"
+    );
+}
+
+#[test]
+fn test_to_digits_round_trip() {
+    let zeros = [
+        '0',
+        '\u{660}',
+        '\u{6f0}',
+        '\u{966}',
+        '\u{e50}',
+        '\u{ff10}',
+        '\u{1d7ce}',
+    ];
+    let numbers = [
+        0,
+        7,
+        -7,
+        10,
+        99,
+        -1000,
+        65_536,
+        i32::MAX,
+        i32::MIN,
+    ];
+    for &zero in &zeros {
+        for &n in &numbers {
+            let digits = format!("{}", to_digits(n, zero).unwrap());
+            assert_eq!(parse_decimal::<i32>(&digits), Ok(n));
+        }
+    }
+}
+
+#[test]
+fn test_parse_decimal_error_display() {
+    assert_eq!(
+        format!("{}", parse_decimal::<u32>("1\u{662}3").unwrap_err()),
+        "digits from more than one decimal system at offset 1"
+    );
+}
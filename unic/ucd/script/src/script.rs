@@ -0,0 +1,1176 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Script` Character Property.
+
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`Script`](https://www.unicode.org/reports/tr44/#Script) property.
+    ///
+    /// * <https://www.unicode.org/reports/tr24/>
+    pub enum Script {
+        abbr => "sc";
+        long => "Script";
+        human => "Script";
+
+        /// The Adlam script
+        Adlam {
+            abbr => Adlm,
+            long => Adlam,
+            human => "Adlam",
+        }
+
+        /// The Ahom script
+        Ahom {
+            abbr => Ahom,
+            long => Ahom,
+            human => "Ahom",
+        }
+
+        /// The Anatolian Hieroglyphs script
+        AnatolianHieroglyphs {
+            abbr => Hluw,
+            long => Anatolian_Hieroglyphs,
+            human => "Anatolian Hieroglyphs",
+        }
+
+        /// The Arabic script
+        Arabic {
+            abbr => Arab,
+            long => Arabic,
+            human => "Arabic",
+        }
+
+        /// The Armenian script
+        Armenian {
+            abbr => Armn,
+            long => Armenian,
+            human => "Armenian",
+        }
+
+        /// The Avestan script
+        Avestan {
+            abbr => Avst,
+            long => Avestan,
+            human => "Avestan",
+        }
+
+        /// The Balinese script
+        Balinese {
+            abbr => Bali,
+            long => Balinese,
+            human => "Balinese",
+        }
+
+        /// The Bamum script
+        Bamum {
+            abbr => Bamu,
+            long => Bamum,
+            human => "Bamum",
+        }
+
+        /// The Bassa Vah script
+        BassaVah {
+            abbr => Bass,
+            long => Bassa_Vah,
+            human => "Bassa Vah",
+        }
+
+        /// The Batak script
+        Batak {
+            abbr => Batk,
+            long => Batak,
+            human => "Batak",
+        }
+
+        /// The Bengali script
+        Bengali {
+            abbr => Beng,
+            long => Bengali,
+            human => "Bengali",
+        }
+
+        /// The Bhaiksuki script
+        Bhaiksuki {
+            abbr => Bhks,
+            long => Bhaiksuki,
+            human => "Bhaiksuki",
+        }
+
+        /// The Bopomofo script
+        Bopomofo {
+            abbr => Bopo,
+            long => Bopomofo,
+            human => "Bopomofo",
+        }
+
+        /// The Brahmi script
+        Brahmi {
+            abbr => Brah,
+            long => Brahmi,
+            human => "Brahmi",
+        }
+
+        /// The Braille script
+        Braille {
+            abbr => Brai,
+            long => Braille,
+            human => "Braille",
+        }
+
+        /// The Buginese script
+        Buginese {
+            abbr => Bugi,
+            long => Buginese,
+            human => "Buginese",
+        }
+
+        /// The Buhid script
+        Buhid {
+            abbr => Buhd,
+            long => Buhid,
+            human => "Buhid",
+        }
+
+        /// The Canadian Aboriginal script
+        CanadianAboriginal {
+            abbr => Cans,
+            long => Canadian_Aboriginal,
+            human => "Canadian Aboriginal",
+        }
+
+        /// The Carian script
+        Carian {
+            abbr => Cari,
+            long => Carian,
+            human => "Carian",
+        }
+
+        /// The Caucasian Albanian script
+        CaucasianAlbanian {
+            abbr => Aghb,
+            long => Caucasian_Albanian,
+            human => "Caucasian Albanian",
+        }
+
+        /// The Chakma script
+        Chakma {
+            abbr => Cakm,
+            long => Chakma,
+            human => "Chakma",
+        }
+
+        /// The Cham script
+        Cham {
+            abbr => Cham,
+            long => Cham,
+            human => "Cham",
+        }
+
+        /// The Cherokee script
+        Cherokee {
+            abbr => Cher,
+            long => Cherokee,
+            human => "Cherokee",
+        }
+
+        /// Characters that are used with multiple scripts
+        Common {
+            abbr => Zyyy,
+            long => Common,
+            human => "Common",
+        }
+
+        /// The Coptic script
+        Coptic {
+            abbr => Copt,
+            long => Coptic,
+            other_aliases => ["Qaac"],
+            human => "Coptic",
+        }
+
+        /// The Cuneiform script
+        Cuneiform {
+            abbr => Xsux,
+            long => Cuneiform,
+            human => "Cuneiform",
+        }
+
+        /// The Cypriot script
+        Cypriot {
+            abbr => Cprt,
+            long => Cypriot,
+            human => "Cypriot",
+        }
+
+        /// The Cyrillic script
+        Cyrillic {
+            abbr => Cyrl,
+            long => Cyrillic,
+            human => "Cyrillic",
+        }
+
+        /// The Deseret script
+        Deseret {
+            abbr => Dsrt,
+            long => Deseret,
+            human => "Deseret",
+        }
+
+        /// The Devanagari script
+        Devanagari {
+            abbr => Deva,
+            long => Devanagari,
+            human => "Devanagari",
+        }
+
+        /// The Duployan script
+        Duployan {
+            abbr => Dupl,
+            long => Duployan,
+            human => "Duployan",
+        }
+
+        /// The Egyptian Hieroglyphs script
+        EgyptianHieroglyphs {
+            abbr => Egyp,
+            long => Egyptian_Hieroglyphs,
+            human => "Egyptian Hieroglyphs",
+        }
+
+        /// The Elbasan script
+        Elbasan {
+            abbr => Elba,
+            long => Elbasan,
+            human => "Elbasan",
+        }
+
+        /// The Ethiopic script
+        Ethiopic {
+            abbr => Ethi,
+            long => Ethiopic,
+            human => "Ethiopic",
+        }
+
+        /// The Georgian script
+        Georgian {
+            abbr => Geor,
+            long => Georgian,
+            human => "Georgian",
+        }
+
+        /// The Glagolitic script
+        Glagolitic {
+            abbr => Glag,
+            long => Glagolitic,
+            human => "Glagolitic",
+        }
+
+        /// The Gothic script
+        Gothic {
+            abbr => Goth,
+            long => Gothic,
+            human => "Gothic",
+        }
+
+        /// The Grantha script
+        Grantha {
+            abbr => Gran,
+            long => Grantha,
+            human => "Grantha",
+        }
+
+        /// The Greek script
+        Greek {
+            abbr => Grek,
+            long => Greek,
+            human => "Greek",
+        }
+
+        /// The Gujarati script
+        Gujarati {
+            abbr => Gujr,
+            long => Gujarati,
+            human => "Gujarati",
+        }
+
+        /// The Gurmukhi script
+        Gurmukhi {
+            abbr => Guru,
+            long => Gurmukhi,
+            human => "Gurmukhi",
+        }
+
+        /// The Han script
+        Han {
+            abbr => Hani,
+            long => Han,
+            human => "Han",
+        }
+
+        /// The Hangul script
+        Hangul {
+            abbr => Hang,
+            long => Hangul,
+            human => "Hangul",
+        }
+
+        /// The Hanunoo script
+        Hanunoo {
+            abbr => Hano,
+            long => Hanunoo,
+            human => "Hanunoo",
+        }
+
+        /// The Hatran script
+        Hatran {
+            abbr => Hatr,
+            long => Hatran,
+            human => "Hatran",
+        }
+
+        /// The Hebrew script
+        Hebrew {
+            abbr => Hebr,
+            long => Hebrew,
+            human => "Hebrew",
+        }
+
+        /// The Hiragana script
+        Hiragana {
+            abbr => Hira,
+            long => Hiragana,
+            human => "Hiragana",
+        }
+
+        /// The Imperial Aramaic script
+        ImperialAramaic {
+            abbr => Armi,
+            long => Imperial_Aramaic,
+            human => "Imperial Aramaic",
+        }
+
+        /// Combining characters that inherit the script of their base character
+        Inherited {
+            abbr => Zinh,
+            long => Inherited,
+            other_aliases => ["Qaai"],
+            human => "Inherited",
+        }
+
+        /// The Inscriptional Pahlavi script
+        InscriptionalPahlavi {
+            abbr => Phli,
+            long => Inscriptional_Pahlavi,
+            human => "Inscriptional Pahlavi",
+        }
+
+        /// The Inscriptional Parthian script
+        InscriptionalParthian {
+            abbr => Prti,
+            long => Inscriptional_Parthian,
+            human => "Inscriptional Parthian",
+        }
+
+        /// The Javanese script
+        Javanese {
+            abbr => Java,
+            long => Javanese,
+            human => "Javanese",
+        }
+
+        /// The Kaithi script
+        Kaithi {
+            abbr => Kthi,
+            long => Kaithi,
+            human => "Kaithi",
+        }
+
+        /// The Kannada script
+        Kannada {
+            abbr => Knda,
+            long => Kannada,
+            human => "Kannada",
+        }
+
+        /// The Katakana script
+        Katakana {
+            abbr => Kana,
+            long => Katakana,
+            human => "Katakana",
+        }
+
+        /// Katakana or Hiragana (used only as an alias; not assigned to any character)
+        KatakanaOrHiragana {
+            abbr => Hrkt,
+            long => Katakana_Or_Hiragana,
+            human => "Katakana or Hiragana",
+        }
+
+        /// The Kayah Li script
+        KayahLi {
+            abbr => Kali,
+            long => Kayah_Li,
+            human => "Kayah Li",
+        }
+
+        /// The Kharoshthi script
+        Kharoshthi {
+            abbr => Khar,
+            long => Kharoshthi,
+            human => "Kharoshthi",
+        }
+
+        /// The Khmer script
+        Khmer {
+            abbr => Khmr,
+            long => Khmer,
+            human => "Khmer",
+        }
+
+        /// The Khojki script
+        Khojki {
+            abbr => Khoj,
+            long => Khojki,
+            human => "Khojki",
+        }
+
+        /// The Khudawadi script
+        Khudawadi {
+            abbr => Sind,
+            long => Khudawadi,
+            human => "Khudawadi",
+        }
+
+        /// The Lao script
+        Lao {
+            abbr => Laoo,
+            long => Lao,
+            human => "Lao",
+        }
+
+        /// The Latin script
+        Latin {
+            abbr => Latn,
+            long => Latin,
+            human => "Latin",
+        }
+
+        /// The Lepcha script
+        Lepcha {
+            abbr => Lepc,
+            long => Lepcha,
+            human => "Lepcha",
+        }
+
+        /// The Limbu script
+        Limbu {
+            abbr => Limb,
+            long => Limbu,
+            human => "Limbu",
+        }
+
+        /// The Linear A script
+        LinearA {
+            abbr => Lina,
+            long => Linear_A,
+            human => "Linear A",
+        }
+
+        /// The Linear B script
+        LinearB {
+            abbr => Linb,
+            long => Linear_B,
+            human => "Linear B",
+        }
+
+        /// The Lisu script
+        Lisu {
+            abbr => Lisu,
+            long => Lisu,
+            human => "Lisu",
+        }
+
+        /// The Lycian script
+        Lycian {
+            abbr => Lyci,
+            long => Lycian,
+            human => "Lycian",
+        }
+
+        /// The Lydian script
+        Lydian {
+            abbr => Lydi,
+            long => Lydian,
+            human => "Lydian",
+        }
+
+        /// The Mahajani script
+        Mahajani {
+            abbr => Mahj,
+            long => Mahajani,
+            human => "Mahajani",
+        }
+
+        /// The Malayalam script
+        Malayalam {
+            abbr => Mlym,
+            long => Malayalam,
+            human => "Malayalam",
+        }
+
+        /// The Mandaic script
+        Mandaic {
+            abbr => Mand,
+            long => Mandaic,
+            human => "Mandaic",
+        }
+
+        /// The Manichaean script
+        Manichaean {
+            abbr => Mani,
+            long => Manichaean,
+            human => "Manichaean",
+        }
+
+        /// The Marchen script
+        Marchen {
+            abbr => Marc,
+            long => Marchen,
+            human => "Marchen",
+        }
+
+        /// The Masaram Gondi script
+        MasaramGondi {
+            abbr => Gonm,
+            long => Masaram_Gondi,
+            human => "Masaram Gondi",
+        }
+
+        /// The Meetei Mayek script
+        MeeteiMayek {
+            abbr => Mtei,
+            long => Meetei_Mayek,
+            human => "Meetei Mayek",
+        }
+
+        /// The Mende Kikakui script
+        MendeKikakui {
+            abbr => Mend,
+            long => Mende_Kikakui,
+            human => "Mende Kikakui",
+        }
+
+        /// The Meroitic Cursive script
+        MeroiticCursive {
+            abbr => Merc,
+            long => Meroitic_Cursive,
+            human => "Meroitic Cursive",
+        }
+
+        /// The Meroitic Hieroglyphs script
+        MeroiticHieroglyphs {
+            abbr => Mero,
+            long => Meroitic_Hieroglyphs,
+            human => "Meroitic Hieroglyphs",
+        }
+
+        /// The Miao script
+        Miao {
+            abbr => Plrd,
+            long => Miao,
+            human => "Miao",
+        }
+
+        /// The Modi script
+        Modi {
+            abbr => Modi,
+            long => Modi,
+            human => "Modi",
+        }
+
+        /// The Mongolian script
+        Mongolian {
+            abbr => Mong,
+            long => Mongolian,
+            human => "Mongolian",
+        }
+
+        /// The Mro script
+        Mro {
+            abbr => Mroo,
+            long => Mro,
+            human => "Mro",
+        }
+
+        /// The Multani script
+        Multani {
+            abbr => Mult,
+            long => Multani,
+            human => "Multani",
+        }
+
+        /// The Myanmar script
+        Myanmar {
+            abbr => Mymr,
+            long => Myanmar,
+            human => "Myanmar",
+        }
+
+        /// The Nabataean script
+        Nabataean {
+            abbr => Nbat,
+            long => Nabataean,
+            human => "Nabataean",
+        }
+
+        /// The New Tai Lue script
+        NewTaiLue {
+            abbr => Talu,
+            long => New_Tai_Lue,
+            human => "New Tai Lue",
+        }
+
+        /// The Newa script
+        Newa {
+            abbr => Newa,
+            long => Newa,
+            human => "Newa",
+        }
+
+        /// The Nko script
+        Nko {
+            abbr => Nkoo,
+            long => Nko,
+            human => "Nko",
+        }
+
+        /// The Nushu script
+        Nushu {
+            abbr => Nshu,
+            long => Nushu,
+            human => "Nushu",
+        }
+
+        /// The Ogham script
+        Ogham {
+            abbr => Ogam,
+            long => Ogham,
+            human => "Ogham",
+        }
+
+        /// The Ol Chiki script
+        OlChiki {
+            abbr => Olck,
+            long => Ol_Chiki,
+            human => "Ol Chiki",
+        }
+
+        /// The Old Hungarian script
+        OldHungarian {
+            abbr => Hung,
+            long => Old_Hungarian,
+            human => "Old Hungarian",
+        }
+
+        /// The Old Italic script
+        OldItalic {
+            abbr => Ital,
+            long => Old_Italic,
+            human => "Old Italic",
+        }
+
+        /// The Old North Arabian script
+        OldNorthArabian {
+            abbr => Narb,
+            long => Old_North_Arabian,
+            human => "Old North Arabian",
+        }
+
+        /// The Old Permic script
+        OldPermic {
+            abbr => Perm,
+            long => Old_Permic,
+            human => "Old Permic",
+        }
+
+        /// The Old Persian script
+        OldPersian {
+            abbr => Xpeo,
+            long => Old_Persian,
+            human => "Old Persian",
+        }
+
+        /// The Old South Arabian script
+        OldSouthArabian {
+            abbr => Sarb,
+            long => Old_South_Arabian,
+            human => "Old South Arabian",
+        }
+
+        /// The Old Turkic script
+        OldTurkic {
+            abbr => Orkh,
+            long => Old_Turkic,
+            human => "Old Turkic",
+        }
+
+        /// The Oriya script
+        Oriya {
+            abbr => Orya,
+            long => Oriya,
+            human => "Oriya",
+        }
+
+        /// The Osage script
+        Osage {
+            abbr => Osge,
+            long => Osage,
+            human => "Osage",
+        }
+
+        /// The Osmanya script
+        Osmanya {
+            abbr => Osma,
+            long => Osmanya,
+            human => "Osmanya",
+        }
+
+        /// The Pahawh Hmong script
+        PahawhHmong {
+            abbr => Hmng,
+            long => Pahawh_Hmong,
+            human => "Pahawh Hmong",
+        }
+
+        /// The Palmyrene script
+        Palmyrene {
+            abbr => Palm,
+            long => Palmyrene,
+            human => "Palmyrene",
+        }
+
+        /// The Pau Cin Hau script
+        PauCinHau {
+            abbr => Pauc,
+            long => Pau_Cin_Hau,
+            human => "Pau Cin Hau",
+        }
+
+        /// The Phags Pa script
+        PhagsPa {
+            abbr => Phag,
+            long => Phags_Pa,
+            human => "Phags Pa",
+        }
+
+        /// The Phoenician script
+        Phoenician {
+            abbr => Phnx,
+            long => Phoenician,
+            human => "Phoenician",
+        }
+
+        /// The Psalter Pahlavi script
+        PsalterPahlavi {
+            abbr => Phlp,
+            long => Psalter_Pahlavi,
+            human => "Psalter Pahlavi",
+        }
+
+        /// The Rejang script
+        Rejang {
+            abbr => Rjng,
+            long => Rejang,
+            human => "Rejang",
+        }
+
+        /// The Runic script
+        Runic {
+            abbr => Runr,
+            long => Runic,
+            human => "Runic",
+        }
+
+        /// The Samaritan script
+        Samaritan {
+            abbr => Samr,
+            long => Samaritan,
+            human => "Samaritan",
+        }
+
+        /// The Saurashtra script
+        Saurashtra {
+            abbr => Saur,
+            long => Saurashtra,
+            human => "Saurashtra",
+        }
+
+        /// The Sharada script
+        Sharada {
+            abbr => Shrd,
+            long => Sharada,
+            human => "Sharada",
+        }
+
+        /// The Shavian script
+        Shavian {
+            abbr => Shaw,
+            long => Shavian,
+            human => "Shavian",
+        }
+
+        /// The Siddham script
+        Siddham {
+            abbr => Sidd,
+            long => Siddham,
+            human => "Siddham",
+        }
+
+        /// The SignWriting script
+        SignWriting {
+            abbr => Sgnw,
+            long => SignWriting,
+            human => "SignWriting",
+        }
+
+        /// The Sinhala script
+        Sinhala {
+            abbr => Sinh,
+            long => Sinhala,
+            human => "Sinhala",
+        }
+
+        /// The Sora Sompeng script
+        SoraSompeng {
+            abbr => Sora,
+            long => Sora_Sompeng,
+            human => "Sora Sompeng",
+        }
+
+        /// The Soyombo script
+        Soyombo {
+            abbr => Soyo,
+            long => Soyombo,
+            human => "Soyombo",
+        }
+
+        /// The Sundanese script
+        Sundanese {
+            abbr => Sund,
+            long => Sundanese,
+            human => "Sundanese",
+        }
+
+        /// The Syloti Nagri script
+        SylotiNagri {
+            abbr => Sylo,
+            long => Syloti_Nagri,
+            human => "Syloti Nagri",
+        }
+
+        /// The Syriac script
+        Syriac {
+            abbr => Syrc,
+            long => Syriac,
+            human => "Syriac",
+        }
+
+        /// The Tagalog script
+        Tagalog {
+            abbr => Tglg,
+            long => Tagalog,
+            human => "Tagalog",
+        }
+
+        /// The Tagbanwa script
+        Tagbanwa {
+            abbr => Tagb,
+            long => Tagbanwa,
+            human => "Tagbanwa",
+        }
+
+        /// The Tai Le script
+        TaiLe {
+            abbr => Tale,
+            long => Tai_Le,
+            human => "Tai Le",
+        }
+
+        /// The Tai Tham script
+        TaiTham {
+            abbr => Lana,
+            long => Tai_Tham,
+            human => "Tai Tham",
+        }
+
+        /// The Tai Viet script
+        TaiViet {
+            abbr => Tavt,
+            long => Tai_Viet,
+            human => "Tai Viet",
+        }
+
+        /// The Takri script
+        Takri {
+            abbr => Takr,
+            long => Takri,
+            human => "Takri",
+        }
+
+        /// The Tamil script
+        Tamil {
+            abbr => Taml,
+            long => Tamil,
+            human => "Tamil",
+        }
+
+        /// The Tangut script
+        Tangut {
+            abbr => Tang,
+            long => Tangut,
+            human => "Tangut",
+        }
+
+        /// The Telugu script
+        Telugu {
+            abbr => Telu,
+            long => Telugu,
+            human => "Telugu",
+        }
+
+        /// The Thaana script
+        Thaana {
+            abbr => Thaa,
+            long => Thaana,
+            human => "Thaana",
+        }
+
+        /// The Thai script
+        Thai {
+            abbr => Thai,
+            long => Thai,
+            human => "Thai",
+        }
+
+        /// The Tibetan script
+        Tibetan {
+            abbr => Tibt,
+            long => Tibetan,
+            human => "Tibetan",
+        }
+
+        /// The Tifinagh script
+        Tifinagh {
+            abbr => Tfng,
+            long => Tifinagh,
+            human => "Tifinagh",
+        }
+
+        /// The Tirhuta script
+        Tirhuta {
+            abbr => Tirh,
+            long => Tirhuta,
+            human => "Tirhuta",
+        }
+
+        /// The Ugaritic script
+        Ugaritic {
+            abbr => Ugar,
+            long => Ugaritic,
+            human => "Ugaritic",
+        }
+
+        /// Unassigned, private-use, noncharacter, and surrogate code points
+        Unknown {
+            abbr => Zzzz,
+            long => Unknown,
+            human => "Unknown",
+        }
+
+        /// The Vai script
+        Vai {
+            abbr => Vaii,
+            long => Vai,
+            human => "Vai",
+        }
+
+        /// The Warang Citi script
+        WarangCiti {
+            abbr => Wara,
+            long => Warang_Citi,
+            human => "Warang Citi",
+        }
+
+        /// The Yi script
+        Yi {
+            abbr => Yiii,
+            long => Yi,
+            human => "Yi",
+        }
+
+        /// The Zanabazar Square script
+        ZanabazarSquare {
+            abbr => Zanb,
+            long => Zanabazar_Square,
+            human => "Zanabazar Square",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Script`](https://www.unicode.org/reports/tr44/#Script) property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/iso15924/iso15924-codes.html>
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Script>
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Script`](https://www.unicode.org/reports/tr44/#Script) property.
+    ///
+    /// ## See Also
+    ///
+    /// - <https://www.unicode.org/iso15924/iso15924-codes.html>
+    /// - <https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt#Script>
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for Script {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+impl TableCharProperty for Script {
+    type TableValue = Script;
+
+    fn table() -> CharDataTable<Script> {
+        data::SCRIPT_TABLE
+    }
+}
+
+/// UCD/Scripts.txt:
+/// "All code points not explicitly listed for Script have the value Unknown (Zzzz)."
+impl Default for Script {
+    #[inline]
+    fn default() -> Self {
+        Script::Unknown
+    }
+}
+
+mod data {
+    use super::long_names::*;
+    use unic_char_property::tables::CharDataTable;
+    pub const SCRIPT_TABLE: CharDataTable<super::Script> = include!("../tables/script.rsv");
+}
+
+impl Script {
+    /// Find the character `Script` property value.
+    pub fn of(ch: char) -> Script {
+        data::SCRIPT_TABLE.find_or_default(ch)
+    }
+
+    /// Whether the value is one of the special values, `Common`, `Inherited`, or `Unknown`, which
+    /// do not denote a specific script.
+    ///
+    /// * <https://www.unicode.org/reports/tr24/#Values>
+    #[inline]
+    pub fn is_special(&self) -> bool {
+        matches!(*self, Script::Common | Script::Inherited | Script::Unknown)
+    }
+}
+
+/// Methods for `Script` character property.
+pub trait CharScript {
+    /// Get `Script` of the character.
+    fn script(self) -> Script;
+}
+
+impl CharScript for char {
+    #[inline]
+    fn script(self) -> Script {
+        Script::of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::abbr_names::*;
+    use super::Script;
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(Script::of('\u{0000}'), Zyyy);
+        assert_eq!(Script::of('\u{0020}'), Zyyy);
+        assert_eq!(Script::of('\u{0030}'), Zyyy);
+        assert_eq!(Script::of('\u{0041}'), Latn);
+        assert_eq!(Script::of('\u{007A}'), Latn);
+        assert_eq!(Script::of('\u{007F}'), Zyyy);
+    }
+
+    #[test]
+    fn test_bmp() {
+        assert_eq!(Script::of('\u{00AA}'), Latn);
+        assert_eq!(Script::of('\u{0300}'), Zinh);
+        assert_eq!(Script::of('\u{0391}'), Grek);
+        assert_eq!(Script::of('\u{03E2}'), Copt);
+        assert_eq!(Script::of('\u{0410}'), Cyrl);
+        assert_eq!(Script::of('\u{05D0}'), Hebr);
+        assert_eq!(Script::of('\u{0627}'), Arab);
+        assert_eq!(Script::of('\u{0640}'), Zyyy);
+        assert_eq!(Script::of('\u{0915}'), Deva);
+        assert_eq!(Script::of('\u{3042}'), Hira);
+        assert_eq!(Script::of('\u{30A2}'), Kana);
+        assert_eq!(Script::of('\u{4E00}'), Hani);
+        assert_eq!(Script::of('\u{AC00}'), Hang);
+        assert_eq!(Script::of('\u{FFFF}'), Zzzz);
+    }
+
+    #[test]
+    fn test_smp() {
+        assert_eq!(Script::of('\u{10300}'), Ital);
+        assert_eq!(Script::of('\u{1E900}'), Adlm);
+        assert_eq!(Script::of('\u{1F600}'), Zyyy);
+        assert_eq!(Script::of('\u{20000}'), Hani);
+        assert_eq!(Script::of('\u{E0001}'), Zyyy);
+        assert_eq!(Script::of('\u{E0100}'), Zinh);
+    }
+
+    #[test]
+    fn test_unassigned_planes() {
+        assert_eq!(Script::of('\u{30000}'), Zzzz);
+        assert_eq!(Script::of('\u{F0000}'), Zzzz);
+        assert_eq!(Script::of('\u{10FFFF}'), Zzzz);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(Latn.abbr_name(), "Latn");
+        assert_eq!(Latn.long_name(), "Latin");
+        assert_eq!(Hluw.long_name(), "Anatolian_Hieroglyphs");
+        assert_eq!(Hluw.human_name(), "Anatolian Hieroglyphs");
+
+        assert_eq!("Latn".parse(), Ok(Latn));
+        assert_eq!("latin".parse(), Ok(Latn));
+        assert_eq!("Old_Italic".parse(), Ok(Ital));
+        assert_eq!("Zzzz".parse(), Ok(Zzzz));
+    }
+
+    #[test]
+    fn test_all_values_order() {
+        for (idx, &script) in Script::all_values().iter().enumerate() {
+            assert_eq!(script as usize, idx);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Script_Extensions` Character Property.
+
+use core::slice;
+
+use unic_char_property::{
+    CharProperty,
+    CustomCharProperty,
+    EnumeratedCharProperty,
+    TotalCharProperty,
+};
+
+use crate::script::Script;
+
+/// Represents values of the Unicode character property
+/// [`Script_Extensions`](https://www.unicode.org/reports/tr44/#Script_Extensions).
+///
+/// The value is the set of scripts a character is commonly used with. For characters not listed
+/// in `ScriptExtensions.txt`, the value is the set containing only the character's `Script`
+/// property value.
+///
+/// * <https://www.unicode.org/reports/tr24/#Script_Extensions>
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ScriptExtensions(&'static [Script]);
+
+impl CharProperty for ScriptExtensions {
+    fn prop_abbr_name() -> &'static str {
+        "scx"
+    }
+
+    fn prop_long_name() -> &'static str {
+        "Script_Extensions"
+    }
+
+    fn prop_human_name() -> &'static str {
+        "Script Extensions"
+    }
+}
+
+impl TotalCharProperty for ScriptExtensions {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+impl CustomCharProperty<&'static [Script]> for ScriptExtensions {
+    fn actual(&self) -> &'static [Script] {
+        Self::actual(self)
+    }
+}
+
+/// The value for code points not explicitly listed in `Scripts.txt` or `ScriptExtensions.txt`.
+impl Default for ScriptExtensions {
+    #[inline]
+    fn default() -> Self {
+        ScriptExtensions::from(Script::Unknown)
+    }
+}
+
+impl From<Script> for ScriptExtensions {
+    fn from(script: Script) -> Self {
+        let idx = script as usize;
+        ScriptExtensions(&Script::all_values()[idx..=idx])
+    }
+}
+
+mod data {
+    use crate::script::abbr_names::*;
+    use crate::script::Script;
+    use unic_char_property::tables::CharDataTable;
+    pub const SCRIPT_EXTENSIONS_TABLE: CharDataTable<&[Script]> =
+        include!("../tables/script_extensions.rsv");
+}
+
+impl ScriptExtensions {
+    /// Find the character `Script_Extensions` property value.
+    pub fn of(ch: char) -> ScriptExtensions {
+        data::SCRIPT_EXTENSIONS_TABLE
+            .find(ch)
+            .map(ScriptExtensions)
+            .unwrap_or_else(|| ScriptExtensions::from(Script::of(ch)))
+    }
+
+    /// Get the scripts in this set, ordered by their abbreviated names.
+    #[inline]
+    pub fn actual(&self) -> &'static [Script] {
+        self.0
+    }
+
+    /// Whether the set contains the given script.
+    #[inline]
+    pub fn contains(&self, script: Script) -> bool {
+        self.0.contains(&script)
+    }
+
+    /// Number of scripts in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the set is empty. Always `false` for values returned by `of()`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the scripts in this set.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'static, Script> {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for ScriptExtensions {
+    type Item = &'static Script;
+    type IntoIter = slice::Iter<'static, Script>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Methods for `Script_Extensions` character property.
+pub trait CharScriptExtensions {
+    /// Get `ScriptExtensions` of the character.
+    fn script_extensions(self) -> ScriptExtensions;
+}
+
+impl CharScriptExtensions for char {
+    #[inline]
+    fn script_extensions(self) -> ScriptExtensions {
+        ScriptExtensions::of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScriptExtensions;
+    use crate::script::abbr_names::*;
+
+    #[test]
+    fn test_listed() {
+        assert_eq!(ScriptExtensions::of('\u{0483}').actual(), &[Cyrl, Perm]);
+        assert_eq!(
+            ScriptExtensions::of('\u{0640}').actual(),
+            &[Adlm, Arab, Mand, Mani, Phlp, Syrc]
+        );
+        assert_eq!(ScriptExtensions::of('\u{0964}').len(), 17);
+        assert!(ScriptExtensions::of('\u{3001}').contains(Hani));
+        assert!(ScriptExtensions::of('\u{3001}').contains(Hira));
+        assert!(!ScriptExtensions::of('\u{3001}').contains(Zyyy));
+    }
+
+    #[test]
+    fn test_fallback_to_script() {
+        assert_eq!(ScriptExtensions::of('\u{0041}').actual(), &[Latn]);
+        assert_eq!(ScriptExtensions::of('\u{0020}').actual(), &[Zyyy]);
+        assert_eq!(ScriptExtensions::of('\u{0300}').actual(), &[Zinh]);
+        assert_eq!(ScriptExtensions::of('\u{10FFFF}').actual(), &[Zzzz]);
+        assert_eq!(ScriptExtensions::default().actual(), &[Zzzz]);
+    }
+}
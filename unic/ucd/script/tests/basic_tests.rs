@@ -0,0 +1,60 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_char_property::EnumeratedCharProperty;
+use unic_ucd_script::{CharScript, CharScriptExtensions, Script, ScriptExtensions};
+
+#[test]
+fn test_script_display() {
+    use unic_ucd_script::script::abbr_names::*;
+
+    assert_eq!(format!("{}", Latn), "Latin");
+    assert_eq!(format!("{}", Zyyy), "Common");
+    assert_eq!(format!("{}", Cans), "Canadian Aboriginal");
+    assert_eq!(format!("{}", Zzzz), "Unknown");
+}
+
+#[test]
+fn test_script_from_name() {
+    assert_eq!(Script::from_name("Latn"), Some(Script::Latin));
+    assert_eq!(Script::from_name("old italic"), Some(Script::OldItalic));
+    assert_eq!(Script::from_name("Qaac"), Some(Script::Coptic));
+    assert_eq!(Script::from_name("qaai"), Some(Script::Inherited));
+    assert_eq!(Script::from_name("Zzzz"), Some(Script::Unknown));
+    assert_eq!(Script::from_name("Latin Script"), None);
+}
+
+#[test]
+fn test_script_extensions_contain_script() {
+    // Every character's script is among its script extensions, unless the script is `Common` or
+    // `Inherited` and the character is explicitly listed with specific scripts.
+    for ch in (0..0x11_0000).filter_map(std::char::from_u32) {
+        let sc = ch.script();
+        let scx = ch.script_extensions();
+        assert!(!scx.is_empty());
+        assert!(
+            scx.contains(sc) || sc == Script::Common || sc == Script::Inherited,
+            "{:?}: {:?} not in {:?}",
+            ch,
+            sc,
+            scx
+        );
+        if scx.len() > 1 {
+            assert!(!scx.contains(Script::Common) && !scx.contains(Script::Inherited));
+        }
+    }
+}
+
+#[test]
+fn test_script_extensions_from_script() {
+    for &sc in Script::all_values() {
+        assert_eq!(ScriptExtensions::from(sc).actual(), &[sc]);
+    }
+}
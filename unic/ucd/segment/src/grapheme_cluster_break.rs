@@ -16,7 +16,7 @@
 //! * <https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries>
 //! * <https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Break_Property_Values>
 
-use unic_char_property::TotalCharProperty;
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
 
 char_property! {
     /// Represents the Unicode character
@@ -326,6 +326,14 @@ impl TotalCharProperty for GraphemeClusterBreak {
     }
 }
 
+impl TableCharProperty for GraphemeClusterBreak {
+    type TableValue = GraphemeClusterBreak;
+
+    fn table() -> CharDataTable<GraphemeClusterBreak> {
+        data::GRAPHEME_CLUSTER_BREAK_TABLE
+    }
+}
+
 impl Default for GraphemeClusterBreak {
     fn default() -> Self {
         GraphemeClusterBreak::Other
@@ -0,0 +1,484 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Line_Break` Character Property.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr44/#Line_Break>
+//! * <https://www.unicode.org/reports/tr14/>
+//! * <https://www.unicode.org/reports/tr14/#Table1>
+
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`Line_Break`](https://www.unicode.org/reports/tr44/#Line_Break)
+    /// property.
+    ///
+    /// ## References
+    ///
+    /// * <https://www.unicode.org/reports/tr44/#Line_Break>
+    /// * <https://www.unicode.org/reports/tr14/>
+    /// * <https://www.unicode.org/reports/tr14/#Table1>
+    pub enum LineBreak {
+        abbr => "lb";
+        long => "Line_Break";
+        human => "Line Break";
+
+        /// Characters with Ambiguous East Asian Width; act like AL when the resolved East Asian
+        /// Width is narrow and like ID when it is wide.
+        Ambiguous {
+            abbr => AI,
+            long => Ambiguous,
+            human => "Ambiguous (Alphabetic or Ideographic)",
+        }
+
+        /// Alphabets and regular symbols.
+        Alphabetic {
+            abbr => AL,
+            long => Alphabetic,
+            human => "Ordinary Alphabetic and Symbol Characters",
+        }
+
+        /// Em dash.
+        BreakBoth {
+            abbr => B2,
+            long => Break_Both,
+            human => "Break Opportunity Before and After",
+        }
+
+        /// Spaces, hyphens and other characters that allow a break after them.
+        BreakAfter {
+            abbr => BA,
+            long => Break_After,
+            human => "Break After",
+        }
+
+        /// Punctuation used in dictionaries, and other characters that allow a break before them.
+        BreakBefore {
+            abbr => BB,
+            long => Break_Before,
+            human => "Break Before",
+        }
+
+        /// Form feed, line separator and paragraph separator; cause a break after them.
+        MandatoryBreak {
+            abbr => BK,
+            long => Mandatory_Break,
+            human => "Mandatory Break",
+        }
+
+        /// Inline objects, whose break behavior depends on information outside the text.
+        ContingentBreak {
+            abbr => CB,
+            long => Contingent_Break,
+            human => "Contingent Break Opportunity",
+        }
+
+        /// Small kana; resolved to NS by default.
+        ConditionalJapaneseStarter {
+            abbr => CJ,
+            long => Conditional_Japanese_Starter,
+            human => "Conditional Japanese Starter",
+        }
+
+        /// Closing punctuation, which prohibits a break before it.
+        ClosePunctuation {
+            abbr => CL,
+            long => Close_Punctuation,
+            human => "Close Punctuation",
+        }
+
+        /// Combining marks and control codes; take the class of the preceding character.
+        CombiningMark {
+            abbr => CM,
+            long => Combining_Mark,
+            human => "Combining Mark",
+        }
+
+        /// `)` and `]`, which prohibit a break before them.
+        CloseParenthesis {
+            abbr => CP,
+            long => Close_Parenthesis,
+            human => "Close Parenthesis",
+        }
+
+        /// ```text
+        /// U+000D CARRIAGE RETURN (CR)
+        /// ```
+        CarriageReturn {
+            abbr => CR,
+            long => Carriage_Return,
+            human => "Carriage Return",
+        }
+
+        /// Emoji characters that can be followed by an emoji modifier.
+        EBase {
+            abbr => EB,
+            long => E_Base,
+            human => "Emoji Base",
+        }
+
+        /// Emoji skin tone modifiers.
+        EModifier {
+            abbr => EM,
+            long => E_Modifier,
+            human => "Emoji Modifier",
+        }
+
+        /// `!`, `?` and similar, which prohibit a break before them.
+        Exclamation {
+            abbr => EX,
+            long => Exclamation,
+            human => "Exclamation/Interrogation",
+        }
+
+        /// Non-breaking characters, such as no-break space, which prohibit breaks around them.
+        Glue {
+            abbr => GL,
+            long => Glue,
+            human => "Non-breaking (\"Glue\")",
+        }
+
+        /// Hangul LV syllables, which form Korean syllable blocks.
+        H2 {
+            abbr => H2,
+            long => H2,
+            human => "Hangul LV Syllable",
+        }
+
+        /// Hangul LVT syllables, which form Korean syllable blocks.
+        H3 {
+            abbr => H3,
+            long => H3,
+            human => "Hangul LVT Syllable",
+        }
+
+        /// Hebrew letters, which do not allow a break after a hyphen following them.
+        HebrewLetter {
+            abbr => HL,
+            long => Hebrew_Letter,
+            human => "Hebrew Letter",
+        }
+
+        /// ```text
+        /// U+002D HYPHEN-MINUS
+        /// ```
+        Hyphen {
+            abbr => HY,
+            long => Hyphen,
+            human => "Hyphen",
+        }
+
+        /// Ideographs, which allow breaks before and after them.
+        Ideographic {
+            abbr => ID,
+            long => Ideographic,
+            human => "Ideographic",
+        }
+
+        /// Leaders, which allow only an indirect break between pairs of them.
+        Inseparable {
+            abbr => IN,
+            long => Inseparable,
+            other_aliases => ["Inseperable"],
+            human => "Inseparable Characters",
+        }
+
+        /// `.` and `,`, which prevent breaks after them inside numbers.
+        InfixNumeric {
+            abbr => IS,
+            long => Infix_Numeric,
+            human => "Infix Numeric Separator",
+        }
+
+        /// Conjoining Hangul leading consonants (choseong).
+        JL {
+            abbr => JL,
+            long => JL,
+            human => "Hangul L Jamo",
+        }
+
+        /// Conjoining Hangul trailing consonants (jongseong).
+        JT {
+            abbr => JT,
+            long => JT,
+            human => "Hangul T Jamo",
+        }
+
+        /// Conjoining Hangul vowels (jungseong).
+        JV {
+            abbr => JV,
+            long => JV,
+            human => "Hangul V Jamo",
+        }
+
+        /// ```text
+        /// U+000A LINE FEED (LF)
+        /// ```
+        LineFeed {
+            abbr => LF,
+            long => Line_Feed,
+            human => "Line Feed",
+        }
+
+        /// ```text
+        /// U+0085 NEXT LINE (NEL)
+        /// ```
+        NextLine {
+            abbr => NL,
+            long => Next_Line,
+            human => "Next Line",
+        }
+
+        /// Characters that cannot start a line, such as small kana and iteration marks.
+        Nonstarter {
+            abbr => NS,
+            long => Nonstarter,
+            human => "Nonstarter",
+        }
+
+        /// Digits, which form numbers.
+        Numeric {
+            abbr => NU,
+            long => Numeric,
+            human => "Numeric",
+        }
+
+        /// Opening punctuation, which prohibits a break after it.
+        OpenPunctuation {
+            abbr => OP,
+            long => Open_Punctuation,
+            human => "Open Punctuation",
+        }
+
+        /// Percent sign and other characters that do not break following a number.
+        PostfixNumeric {
+            abbr => PO,
+            long => Postfix_Numeric,
+            human => "Postfix Numeric",
+        }
+
+        /// Currency symbols and other characters that do not break in front of a number.
+        PrefixNumeric {
+            abbr => PR,
+            long => Prefix_Numeric,
+            human => "Prefix Numeric",
+        }
+
+        /// Quotation marks, which act like opening or closing punctuation.
+        Quotation {
+            abbr => QU,
+            long => Quotation,
+            human => "Quotation",
+        }
+
+        /// Regional indicator symbols, which keep pairs of them together.
+        RegionalIndicator {
+            abbr => RI,
+            long => Regional_Indicator,
+            human => "Regional Indicator",
+        }
+
+        /// South East Asian scripts, which need dictionary-based breaking.
+        ComplexContext {
+            abbr => SA,
+            long => Complex_Context,
+            human => "Complex Context Dependent (South East Asian)",
+        }
+
+        /// Surrogate code points.
+        Surrogate {
+            abbr => SG,
+            long => Surrogate,
+            human => "Surrogate",
+        }
+
+        /// ```text
+        /// U+0020 SPACE
+        /// ```
+        Space {
+            abbr => SP,
+            long => Space,
+            human => "Space",
+        }
+
+        /// `/`, which allows a break after it, except before a digit.
+        BreakSymbols {
+            abbr => SY,
+            long => Break_Symbols,
+            human => "Symbols Allowing Break After",
+        }
+
+        /// Word joiner and zero width no-break space, which prohibit breaks around them.
+        WordJoiner {
+            abbr => WJ,
+            long => Word_Joiner,
+            human => "Word Joiner",
+        }
+
+        /// Unassigned code points, private use characters and characters with unknown line
+        /// breaking behavior.
+        Unknown {
+            abbr => XX,
+            long => Unknown,
+            human => "Unknown",
+        }
+
+        /// ```text
+        /// U+200B ZERO WIDTH SPACE
+        /// ```
+        ZWSpace {
+            abbr => ZW,
+            long => ZWSpace,
+            human => "Zero Width Space",
+        }
+
+        /// ```text
+        /// U+200D ZERO WIDTH JOINER
+        /// ```
+        ZWJ {
+            abbr => ZWJ,
+            long => ZWJ,
+            human => "Zero Width Joiner",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Line_Break`](https://www.unicode.org/reports/tr44/#Line_Break)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// * <https://www.unicode.org/reports/tr14/#Table1>
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Line_Break`](https://www.unicode.org/reports/tr44/#Line_Break)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// * <https://www.unicode.org/reports/tr14/#Table1>
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for LineBreak {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+impl TableCharProperty for LineBreak {
+    type TableValue = LineBreak;
+
+    fn table() -> CharDataTable<LineBreak> {
+        data::LINE_BREAK_TABLE
+    }
+}
+
+impl Default for LineBreak {
+    fn default() -> Self {
+        LineBreak::Unknown
+    }
+}
+
+mod data {
+    use super::abbr_names as LB;
+    use unic_char_property::tables::CharDataTable;
+    pub const LINE_BREAK_TABLE: CharDataTable<super::LineBreak> =
+        include!("../tables/line_break.rsv");
+}
+
+impl LineBreak {
+    /// Find the character `Line_Break` property value.
+    pub fn of(ch: char) -> LineBreak {
+        data::LINE_BREAK_TABLE.find_or_default(ch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LineBreak as LB;
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(LB::of('\u{0000}'), LB::CombiningMark);
+        assert_eq!(LB::of('\u{000A}'), LB::LineFeed);
+        assert_eq!(LB::of('\u{000D}'), LB::CarriageReturn);
+        assert_eq!(LB::of('\u{0020}'), LB::Space);
+        assert_eq!(LB::of('\u{0021}'), LB::Exclamation);
+        assert_eq!(LB::of('\u{0024}'), LB::PrefixNumeric);
+        assert_eq!(LB::of('\u{0025}'), LB::PostfixNumeric);
+        assert_eq!(LB::of('\u{0028}'), LB::OpenPunctuation);
+        assert_eq!(LB::of('\u{0029}'), LB::CloseParenthesis);
+        assert_eq!(LB::of('\u{002D}'), LB::Hyphen);
+        assert_eq!(LB::of('\u{002F}'), LB::BreakSymbols);
+        assert_eq!(LB::of('\u{0030}'), LB::Numeric);
+        assert_eq!(LB::of('\u{0041}'), LB::Alphabetic);
+    }
+
+    #[test]
+    fn test_bmp() {
+        assert_eq!(LB::of('\u{0085}'), LB::NextLine);
+        assert_eq!(LB::of('\u{00A0}'), LB::Glue);
+        assert_eq!(LB::of('\u{05D0}'), LB::HebrewLetter);
+        assert_eq!(LB::of('\u{0E01}'), LB::ComplexContext);
+        assert_eq!(LB::of('\u{1100}'), LB::JL);
+        assert_eq!(LB::of('\u{2014}'), LB::BreakBoth);
+        assert_eq!(LB::of('\u{200B}'), LB::ZWSpace);
+        assert_eq!(LB::of('\u{200D}'), LB::ZWJ);
+        assert_eq!(LB::of('\u{2028}'), LB::MandatoryBreak);
+        assert_eq!(LB::of('\u{2060}'), LB::WordJoiner);
+        assert_eq!(LB::of('\u{3041}'), LB::ConditionalJapaneseStarter);
+        assert_eq!(LB::of('\u{4E00}'), LB::Ideographic);
+        assert_eq!(LB::of('\u{AC00}'), LB::H2);
+        assert_eq!(LB::of('\u{AC01}'), LB::H3);
+        assert_eq!(LB::of('\u{E000}'), LB::Unknown);
+        assert_eq!(LB::of('\u{FFFC}'), LB::ContingentBreak);
+    }
+
+    #[test]
+    fn test_smp() {
+        assert_eq!(LB::of('\u{1F1E6}'), LB::RegionalIndicator);
+        assert_eq!(LB::of('\u{1F3FB}'), LB::EModifier);
+        assert_eq!(LB::of('\u{1F466}'), LB::EBase);
+        assert_eq!(LB::of('\u{20000}'), LB::Ideographic);
+        assert_eq!(LB::of('\u{E0001}'), LB::CombiningMark);
+    }
+
+    #[test]
+    fn test_unassigned() {
+        assert_eq!(LB::of('\u{0378}'), LB::Unknown);
+        assert_eq!(LB::of('\u{10FFFF}'), LB::Unknown);
+    }
+
+    #[test]
+    fn test_abbr_name() {
+        assert_eq!(LB::Alphabetic.abbr_name(), "AL");
+        assert_eq!(LB::ZWSpace.abbr_name(), "ZW");
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(LB::Alphabetic.long_name(), "Alphabetic");
+        assert_eq!(LB::ZWSpace.long_name(), "ZWSpace");
+    }
+
+    #[test]
+    fn test_human_name() {
+        assert_eq!(
+            LB::BreakBoth.human_name(),
+            "Break Opportunity Before and After"
+        );
+    }
+}
@@ -16,7 +16,7 @@
 //! * <https://www.unicode.org/reports/tr29/#Sentence_Boundaries>
 //! * <https://www.unicode.org/reports/tr29/#Table_Sentence_Break_Property_Values>
 
-use unic_char_property::TotalCharProperty;
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
 
 char_property! {
     /// Represents the Unicode character
@@ -59,6 +59,7 @@ char_property! {
         Extend {
             abbr => Extend,
             long => Extend,
+            other_aliases => ["EX"],
             human => "Extend",
         }
 
@@ -241,6 +242,14 @@ impl TotalCharProperty for SentenceBreak {
     }
 }
 
+impl TableCharProperty for SentenceBreak {
+    type TableValue = SentenceBreak;
+
+    fn table() -> CharDataTable<SentenceBreak> {
+        data::SENTENCE_BREAK_TABLE
+    }
+}
+
 impl Default for SentenceBreak {
     fn default() -> Self {
         SentenceBreak::Other
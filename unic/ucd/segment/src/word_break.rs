@@ -16,7 +16,7 @@
 //! * <https://www.unicode.org/reports/tr29/#Word_Boundaries>
 //! * <https://www.unicode.org/reports/tr29/#Table_Word_Break_Property_Values>
 
-use unic_char_property::TotalCharProperty;
+use unic_char_property::{tables::CharDataTable, TableCharProperty, TotalCharProperty};
 
 char_property! {
     /// Represents the Unicode character
@@ -334,6 +334,14 @@ impl TotalCharProperty for WordBreak {
     }
 }
 
+impl TableCharProperty for WordBreak {
+    type TableValue = WordBreak;
+
+    fn table() -> CharDataTable<WordBreak> {
+        data::WORD_BREAK_TABLE
+    }
+}
+
 impl Default for WordBreak {
     fn default() -> Self {
         WordBreak::Other
@@ -9,6 +9,7 @@
 // except according to those terms.
 
 use unic_ucd_segment::grapheme_cluster_break::GraphemeClusterBreak as GCB;
+use unic_ucd_segment::line_break::LineBreak as LB;
 use unic_ucd_segment::sentence_break::SentenceBreak as SB;
 use unic_ucd_segment::word_break::WordBreak as WB;
 
@@ -26,3 +27,8 @@ fn test_word_break_display() {
 fn test_sentence_break_display() {
     assert_eq!(format!("{}", WB::CR), "Carriage Return");
 }
+
+#[test]
+fn test_line_break_display() {
+    assert_eq!(format!("{}", LB::ZWSpace), "Zero Width Space");
+}
@@ -11,6 +11,7 @@
 use unic_char_property::tables::CharDataTable;
 
 use unic_ucd_segment::grapheme_cluster_break::{self, GraphemeClusterBreak};
+use unic_ucd_segment::sentence_break::{self, SentenceBreak};
 use unic_ucd_segment::word_break::{self, WordBreak};
 
 #[test]
@@ -39,3 +40,15 @@ fn test_word_break_conformance() {
         assert_eq!(WordBreak::of(ch.low), gcb);
     }
 }
+
+#[test]
+fn test_sentence_break_conformance() {
+    use crate::sentence_break::abbr_names::*;
+    use crate::sentence_break::long_names::*;
+
+    const TEST_DATA: CharDataTable<SentenceBreak> = include!("tables/sentence_break_test_data.rsv");
+
+    for (ch, sb) in TEST_DATA.iter() {
+        assert_eq!(SentenceBreak::of(ch.low), sb);
+    }
+}
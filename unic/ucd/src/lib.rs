@@ -32,11 +32,15 @@ pub use unic_ucd_bidi as bidi;
 pub use unic_ucd_block as block;
 pub use unic_ucd_case as case;
 pub use unic_ucd_category as category;
+pub use unic_ucd_east_asian_width as east_asian_width;
 pub use unic_ucd_hangul as hangul;
 pub use unic_ucd_ident as ident;
+pub use unic_ucd_joining as joining;
 pub use unic_ucd_name as name;
 pub use unic_ucd_name_aliases as name_aliases;
 pub use unic_ucd_normal as normal;
+pub use unic_ucd_numeric as numeric;
+pub use unic_ucd_script as script;
 pub use unic_ucd_segment as segment;
 
 pub use crate::version::UnicodeVersion;
@@ -73,15 +77,43 @@ pub use crate::case::{
 
 pub use crate::category::GeneralCategory;
 
-pub use crate::common::{is_alphabetic, is_white_space, Alphabetic, WhiteSpace};
+pub use crate::common::{
+    is_alphabetic,
+    is_default_ignorable_code_point,
+    is_white_space,
+    Alphabetic,
+    DefaultIgnorableCodePoint,
+    WhiteSpace,
+};
+
+pub use crate::east_asian_width::{CharEastAsianWidth, EastAsianWidth};
+
+pub use crate::hangul::{CharHangulSyllableType, HangulSyllableType};
+
+pub use crate::joining::{CharJoiningType, JoiningType};
 
-pub use crate::name::Name;
+pub use crate::name::{
+    char_from_name,
+    named_sequence,
+    search_names,
+    sequence_name,
+    string_from_name,
+    Name,
+    NamedString,
+};
 
 pub use crate::normal::CanonicalCombiningClass;
 
 pub use crate::name_aliases::{name_aliases_of, NameAliasType};
 
-pub use crate::segment::{GraphemeClusterBreak, SentenceBreak, WordBreak};
+pub use crate::numeric::{numeric_value, NumericType, NumericValue, Rational};
+
+pub use crate::script::{CharScript, CharScriptExtensions, Script, ScriptExtensions};
+
+pub use crate::segment::{GraphemeClusterBreak, LineBreak, SentenceBreak, WordBreak};
+
+pub mod registry;
+pub use crate::registry::{CharSet, Property, PropertyValue};
 
 mod pkg_info;
 pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
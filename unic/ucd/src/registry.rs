@@ -0,0 +1,492 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Registry of character properties, for queries by name at runtime.
+//!
+//! Property names and property value names are matched loosely, following
+//! [UAX44-LM3](https://www.unicode.org/reports/tr44/#UAX44-LM3), against all their aliases.
+//!
+//! Blocks are the exception: they are only matched against their names in `Blocks.txt`, such as
+//! `Greek and Coptic`, and not against their abbreviated aliases, such as `Greek`, which are not
+//! part of the Block data.
+//!
+//! ```
+//! use unic_ucd::registry;
+//!
+//! let value = registry::value_of("gc", 'A').unwrap();
+//! assert_eq!(value.abbr_name(), "Lu");
+//!
+//! let greek = registry::chars_where("Block", "Greek and Coptic").unwrap();
+//! assert!(greek.contains('α'));
+//! assert!(!greek.contains('a'));
+//!
+//! let alphabetic = registry::chars_where("Alphabetic", "Yes").unwrap();
+//! assert!(alphabetic.contains('a'));
+//! ```
+
+use core::fmt;
+
+use unic_char_property::{
+    loose_eq,
+    BinaryCharProperty,
+    CharProperty,
+    EnumeratedCharProperty,
+    PartialCharProperty,
+    TableCharProperty,
+};
+use unic_char_range::CharRange;
+
+use crate::bidi::{BidiClass, BidiControl, BidiMirrored, BidiPairedBracketType};
+use crate::block::{Block, BlockIter};
+use crate::case::{
+    CaseIgnorable,
+    Cased,
+    ChangesWhenCasefolded,
+    ChangesWhenCasemapped,
+    ChangesWhenLowercased,
+    ChangesWhenTitlecased,
+    ChangesWhenUppercased,
+    Lowercase,
+    Uppercase,
+};
+use crate::category::GeneralCategory;
+use crate::common::{Alphabetic, DefaultIgnorableCodePoint, WhiteSpace};
+use crate::east_asian_width::EastAsianWidth;
+use crate::hangul::HangulSyllableType;
+use crate::ident::{XidContinue, XidStart};
+use crate::joining::JoiningType;
+use crate::numeric::NumericType;
+use crate::script::Script;
+use crate::segment::{GraphemeClusterBreak, LineBreak, SentenceBreak, WordBreak};
+
+/// A value of a registered character property.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PropertyValue {
+    /// A value of a binary property.
+    Binary(bool),
+
+    /// A value of an enumerated or catalog property.
+    Enumerated {
+        /// The *abbreviated name* of the value.
+        abbr_name: &'static str,
+
+        /// The *long name* of the value.
+        long_name: &'static str,
+
+        /// The *human-readable name* of the value.
+        human_name: &'static str,
+    },
+}
+
+impl PropertyValue {
+    fn enumerated<P: EnumeratedCharProperty>(value: &P) -> PropertyValue {
+        PropertyValue::Enumerated {
+            abbr_name: value.abbr_name(),
+            long_name: value.long_name(),
+            human_name: value.human_name(),
+        }
+    }
+
+    fn block(block: Block) -> PropertyValue {
+        PropertyValue::Enumerated {
+            abbr_name: block.name,
+            long_name: block.name,
+            human_name: block.name,
+        }
+    }
+
+    /// The *abbreviated name* of the value.
+    pub fn abbr_name(&self) -> &'static str {
+        match *self {
+            PropertyValue::Binary(true) => "Y",
+            PropertyValue::Binary(false) => "N",
+            PropertyValue::Enumerated { abbr_name, .. } => abbr_name,
+        }
+    }
+
+    /// The *long name* of the value.
+    pub fn long_name(&self) -> &'static str {
+        match *self {
+            PropertyValue::Binary(true) => "Yes",
+            PropertyValue::Binary(false) => "No",
+            PropertyValue::Enumerated { long_name, .. } => long_name,
+        }
+    }
+
+    /// The *human-readable name* of the value.
+    pub fn human_name(&self) -> &'static str {
+        match *self {
+            PropertyValue::Binary(true) => "Yes",
+            PropertyValue::Binary(false) => "No",
+            PropertyValue::Enumerated { human_name, .. } => human_name,
+        }
+    }
+
+    /// The boolean value of a binary property value.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            PropertyValue::Binary(value) => Some(value),
+            PropertyValue::Enumerated { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for PropertyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.human_name().fmt(f)
+    }
+}
+
+/// A registered character property, with type-erased lookups.
+pub struct Property {
+    abbr_name: fn() -> &'static str,
+    long_name: fn() -> &'static str,
+    human_name: fn() -> &'static str,
+    is_name: fn(&str) -> bool,
+    value_of: fn(char) -> Option<PropertyValue>,
+    value_from_name: fn(&str) -> Option<PropertyValue>,
+    value_at: fn(usize) -> Option<PropertyValue>,
+    entry_at: fn(usize) -> Option<(CharRange, PropertyValue)>,
+}
+
+impl Property {
+    /// The *abbreviated name* of the property.
+    pub fn abbr_name(&self) -> &'static str {
+        (self.abbr_name)()
+    }
+
+    /// The *long name* of the property.
+    pub fn long_name(&self) -> &'static str {
+        (self.long_name)()
+    }
+
+    /// The *human-readable* name of the property.
+    pub fn human_name(&self) -> &'static str {
+        (self.human_name)()
+    }
+
+    /// Whether the given name is a name of the property, under loose matching.
+    pub fn is_name(&self, name: &str) -> bool {
+        (self.is_name)(name)
+    }
+
+    /// The property value of the character, or `None` if the property is not defined for it.
+    pub fn value_of(&self, ch: char) -> Option<PropertyValue> {
+        (self.value_of)(ch)
+    }
+
+    /// Find the property value with the given name, under loose matching.
+    pub fn value_from_name(&self, name: &str) -> Option<PropertyValue> {
+        (self.value_from_name)(name)
+    }
+
+    /// Iterate over all values of the property.
+    pub fn values(&'static self) -> PropertyValues {
+        PropertyValues {
+            property: self,
+            index: 0,
+        }
+    }
+
+    /// The set of characters having the given value of the property.
+    pub fn chars_where(&'static self, value: PropertyValue) -> CharSet {
+        CharSet {
+            property: self,
+            value,
+        }
+    }
+}
+
+impl fmt::Debug for Property {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Property")
+            .field("abbr_name", &self.abbr_name())
+            .field("long_name", &self.long_name())
+            .finish()
+    }
+}
+
+/// Iterator over the values of a registered property.
+#[derive(Clone, Debug)]
+pub struct PropertyValues {
+    property: &'static Property,
+    index: usize,
+}
+
+impl Iterator for PropertyValues {
+    type Item = PropertyValue;
+
+    fn next(&mut self) -> Option<PropertyValue> {
+        let value = (self.property.value_at)(self.index)?;
+        self.index += 1;
+        Some(value)
+    }
+}
+
+/// The set of characters having a given value of a registered property.
+#[derive(Clone, Copy, Debug)]
+pub struct CharSet {
+    property: &'static Property,
+    value: PropertyValue,
+}
+
+impl CharSet {
+    /// The property of the set.
+    pub fn property(&self) -> &'static Property {
+        self.property
+    }
+
+    /// The property value of the characters in the set.
+    pub fn value(&self) -> PropertyValue {
+        self.value
+    }
+
+    /// Whether the character is in the set.
+    pub fn contains(&self, ch: char) -> bool {
+        self.property.value_of(ch) == Some(self.value)
+    }
+
+    /// Iterate over the maximal ranges of characters in the set, in code point order.
+    pub fn ranges(&self) -> CharSetRanges {
+        CharSetRanges {
+            set: *self,
+            index: 0,
+            next: 0,
+        }
+    }
+
+    /// Iterate over the characters in the set, in code point order.
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        self.ranges().flat_map(|range| range.iter())
+    }
+}
+
+/// Iterator over the ranges of characters in a `CharSet`.
+///
+/// Walks the data table of the property, filling the gaps between its entries with the value of
+/// characters missing from the table.
+#[derive(Clone, Debug)]
+pub struct CharSetRanges {
+    set: CharSet,
+    /// Index of the next entry of the data table.
+    index: usize,
+    /// The first code point not visited yet.
+    next: u32,
+}
+
+impl CharSetRanges {
+    /// The next range of characters with the same value: a table entry, or a gap before the next
+    /// one, stopping at surrogates.
+    fn next_segment(&mut self) -> Option<(char, char, Option<PropertyValue>)> {
+        if self.next > 0x10_FFFF {
+            return None;
+        }
+        if (0xD800..=0xDFFF).contains(&self.next) {
+            self.next = 0xE000;
+        }
+        let low = core::char::from_u32(self.next)?;
+
+        let property = self.set.property;
+        match (property.entry_at)(self.index) {
+            Some((range, value)) if range.low <= low => {
+                self.index += 1;
+                self.next = range.high as u32 + 1;
+                Some((range.low, range.high, Some(value)))
+            }
+            entry => {
+                let mut high = entry.map_or(0x10_FFFF, |(range, _)| range.low as u32 - 1);
+                if self.next < 0xD800 {
+                    high = high.min(0xD7FF);
+                }
+                self.next = high + 1;
+                Some((low, core::char::from_u32(high)?, property.value_of(low)))
+            }
+        }
+    }
+}
+
+impl Iterator for CharSetRanges {
+    type Item = CharRange;
+
+    fn next(&mut self) -> Option<CharRange> {
+        let wanted = Some(self.set.value);
+        let (low, mut high) = loop {
+            let (low, high, value) = self.next_segment()?;
+            if value == wanted {
+                break (low, high);
+            }
+        };
+        // Segments are contiguous, so the range extends until the first one with another value.
+        while let Some((_, next_high, value)) = self.next_segment() {
+            if value != wanted {
+                break;
+            }
+            high = next_high;
+        }
+        Some(CharRange::closed(low, high))
+    }
+}
+
+fn enumerated_value_of<P: EnumeratedCharProperty>(ch: char) -> Option<PropertyValue> {
+    <P as PartialCharProperty>::of(ch).map(|value| PropertyValue::enumerated(&value))
+}
+
+fn enumerated_value_from_name<P: EnumeratedCharProperty + 'static>(
+    name: &str,
+) -> Option<PropertyValue> {
+    P::from_name(name).map(|value| PropertyValue::enumerated(&value))
+}
+
+fn enumerated_value_at<P: EnumeratedCharProperty + 'static>(index: usize) -> Option<PropertyValue> {
+    P::all_values().get(index).map(PropertyValue::enumerated)
+}
+
+fn enumerated_entry_at<P>(index: usize) -> Option<(CharRange, PropertyValue)>
+where
+    P: EnumeratedCharProperty + TableCharProperty<TableValue = P> + 'static,
+{
+    P::table()
+        .iter()
+        .nth(index)
+        .map(|(range, value)| (range, PropertyValue::enumerated(&value)))
+}
+
+fn binary_value_of<P: BinaryCharProperty>(ch: char) -> Option<PropertyValue> {
+    <P as PartialCharProperty>::of(ch).map(|value| PropertyValue::Binary(value.as_bool()))
+}
+
+fn binary_value_from_name<P: BinaryCharProperty + From<bool>>(name: &str) -> Option<PropertyValue> {
+    P::from_name(name).map(|value| PropertyValue::Binary(value.as_bool()))
+}
+
+fn binary_value_at(index: usize) -> Option<PropertyValue> {
+    [false, true].get(index).cloned().map(PropertyValue::Binary)
+}
+
+fn binary_entry_at<P>(index: usize) -> Option<(CharRange, PropertyValue)>
+where
+    P: BinaryCharProperty + TableCharProperty<TableValue = ()>,
+{
+    P::table()
+        .iter()
+        .nth(index)
+        .map(|(range, ())| (range, PropertyValue::Binary(true)))
+}
+
+// Only the long names of the Blocks are known, without the aliases of `PropertyValueAliases.txt`.
+fn block_value_from_name(name: &str) -> Option<PropertyValue> {
+    BlockIter::new()
+        .find(|block| loose_eq(block.name, name))
+        .map(PropertyValue::block)
+}
+
+macro_rules! enumerated_property {
+    ($prop:ty) => {
+        Property {
+            abbr_name: <$prop as CharProperty>::prop_abbr_name,
+            long_name: <$prop as CharProperty>::prop_long_name,
+            human_name: <$prop as CharProperty>::prop_human_name,
+            is_name: <$prop as CharProperty>::is_prop_name,
+            value_of: enumerated_value_of::<$prop>,
+            value_from_name: enumerated_value_from_name::<$prop>,
+            value_at: enumerated_value_at::<$prop>,
+            entry_at: enumerated_entry_at::<$prop>,
+        }
+    };
+}
+
+macro_rules! binary_property {
+    ($prop:ty) => {
+        Property {
+            abbr_name: <$prop as CharProperty>::prop_abbr_name,
+            long_name: <$prop as CharProperty>::prop_long_name,
+            human_name: <$prop as CharProperty>::prop_human_name,
+            is_name: <$prop as CharProperty>::is_prop_name,
+            value_of: binary_value_of::<$prop>,
+            value_from_name: binary_value_from_name::<$prop>,
+            value_at: binary_value_at,
+            entry_at: binary_entry_at::<$prop>,
+        }
+    };
+}
+
+/// All registered properties: the enumerated and binary properties of `unic-ucd`, and the
+/// `Block` catalog property.
+static PROPERTIES: &[Property] = &[
+    enumerated_property!(BidiClass),
+    enumerated_property!(BidiPairedBracketType),
+    enumerated_property!(EastAsianWidth),
+    enumerated_property!(GeneralCategory),
+    enumerated_property!(GraphemeClusterBreak),
+    enumerated_property!(HangulSyllableType),
+    enumerated_property!(JoiningType),
+    enumerated_property!(LineBreak),
+    enumerated_property!(NumericType),
+    enumerated_property!(Script),
+    enumerated_property!(SentenceBreak),
+    enumerated_property!(WordBreak),
+    Property {
+        abbr_name: || "blk",
+        long_name: || "Block",
+        human_name: || "Block",
+        is_name: |name| loose_eq("blk", name) || loose_eq("Block", name),
+        value_of: |ch| Block::of(ch).map(PropertyValue::block),
+        value_from_name: block_value_from_name,
+        value_at: |index| BlockIter::new().nth(index).map(PropertyValue::block),
+        entry_at: |index| {
+            BlockIter::new()
+                .nth(index)
+                .map(|block| (block.range, PropertyValue::block(block)))
+        },
+    },
+    binary_property!(Alphabetic),
+    binary_property!(BidiControl),
+    binary_property!(BidiMirrored),
+    binary_property!(CaseIgnorable),
+    binary_property!(Cased),
+    binary_property!(ChangesWhenCasefolded),
+    binary_property!(ChangesWhenCasemapped),
+    binary_property!(ChangesWhenLowercased),
+    binary_property!(ChangesWhenTitlecased),
+    binary_property!(ChangesWhenUppercased),
+    binary_property!(DefaultIgnorableCodePoint),
+    binary_property!(Lowercase),
+    binary_property!(Uppercase),
+    binary_property!(WhiteSpace),
+    binary_property!(XidContinue),
+    binary_property!(XidStart),
+];
+
+/// All registered properties.
+pub fn properties() -> &'static [Property] {
+    PROPERTIES
+}
+
+/// Find the registered property with the given name, under loose matching.
+pub fn property(name: &str) -> Option<&'static Property> {
+    PROPERTIES.iter().find(|property| property.is_name(name))
+}
+
+/// The value of the named property for the character.
+///
+/// Returns `None` if no property has the given name, or if the property is not defined for the
+/// character.
+pub fn value_of(property_name: &str, ch: char) -> Option<PropertyValue> {
+    property(property_name)?.value_of(ch)
+}
+
+/// The set of characters having the named value of the named property.
+///
+/// Returns `None` if no property has the given name, or if it has no value with the given name.
+pub fn chars_where(property_name: &str, value_name: &str) -> Option<CharSet> {
+    let property = property(property_name)?;
+    let value = property.value_from_name(value_name)?;
+    Some(property.chars_where(value))
+}
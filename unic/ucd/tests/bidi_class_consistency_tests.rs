@@ -21,6 +21,7 @@ extern crate unic_char_range;
 
 use unic_ucd::bidi::BidiClass as BC;
 use unic_ucd::category::GeneralCategory as GC;
+use unic_ucd::script::Script as SC;
 
 #[test]
 #[allow(match_same_arms)]
@@ -31,17 +32,26 @@ fn test_from_bidi_class() {
 
             // <https://www.unicode.org/reports/tr9/#L>
             BC::LeftToRight => {
-                // TODO: Impl using Script property, etc
+                assert!(!matches!(
+                    SC::of(cp),
+                    SC::Arabic | SC::Hebrew | SC::Syriac | SC::Thaana | SC::Nko | SC::Adlam
+                ));
             }
 
             // <https://www.unicode.org/reports/tr9/#R>
             BC::RightToLeft => {
-                // TODO: Impl using Script property, etc
+                assert!(!matches!(
+                    SC::of(cp),
+                    SC::Latin | SC::Arabic | SC::Greek | SC::Cyrillic | SC::Han
+                ));
             }
 
             // <https://www.unicode.org/reports/tr9/#AL>
             BC::ArabicLetter => {
-                // TODO: Impl using Script property, etc
+                assert!(matches!(
+                    SC::of(cp),
+                    SC::Arabic | SC::Syriac | SC::Thaana | SC::Common | SC::Unknown
+                ));
             }
 
             // == Weak ==
@@ -0,0 +1,134 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Check the names of the registered properties and their values against all the aliases of
+//! `PropertyAliases.txt` and `PropertyValueAliases.txt`.
+
+use std::collections::HashSet;
+
+use unic_ucd::registry::{self, PropertyValue};
+
+/// Fields of the data lines of a UCD file, without comments.
+fn data_lines(data: &'static str) -> impl Iterator<Item = Vec<&'static str>> {
+    data.lines()
+        .map(|line| line.split('#').next().unwrap().trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split(';').map(str::trim).collect())
+}
+
+#[test]
+fn test_property_aliases() {
+    let mut found = HashSet::new();
+
+    // Source: https://www.unicode.org/Public/UCD/latest/ucd/PropertyAliases.txt
+    for aliases in data_lines(include_str!(
+        "../../../external/unicode/ucd/data/PropertyAliases.txt"
+    )) {
+        let property = match aliases.iter().find_map(|alias| registry::property(alias)) {
+            Some(property) => property,
+            None => continue,
+        };
+        for alias in &aliases {
+            assert!(
+                property.is_name(alias),
+                "Property `{}` not found by its alias `{}`",
+                property.long_name(),
+                alias
+            );
+        }
+        found.insert(property.abbr_name());
+    }
+
+    for property in registry::properties() {
+        assert!(
+            found.contains(property.abbr_name()),
+            "Property `{}` missing from PropertyAliases.txt",
+            property.long_name()
+        );
+    }
+}
+
+#[test]
+fn test_property_value_aliases() {
+    let mut found = HashSet::new();
+
+    // Source: https://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt
+    for fields in data_lines(include_str!(
+        "../../../external/unicode/ucd/data/PropertyValueAliases.txt"
+    )) {
+        let property = match registry::property(fields[0]) {
+            Some(property) => property,
+            None => continue,
+        };
+        // Missing abbreviated names are written `n/a`.
+        //
+        // Blocks are only named by their long names, the names in `Blocks.txt`, and not by their
+        // other aliases.
+        let aliases: Vec<&str> = if property.abbr_name() == "blk" {
+            vec![fields[2]]
+        } else {
+            fields[1..]
+                .iter()
+                .cloned()
+                .filter(|&alias| alias != "n/a")
+                .collect()
+        };
+        // Lines for groups of values, such as `gc=L`, have no value in the registry.
+        let value = match aliases
+            .iter()
+            .find_map(|alias| property.value_from_name(alias))
+        {
+            Some(value) => value,
+            None => continue,
+        };
+        for alias in &aliases {
+            assert_eq!(
+                property.value_from_name(alias),
+                Some(value),
+                "Value `{}` of property `{}` not found by its alias `{}`",
+                value.long_name(),
+                property.long_name(),
+                alias
+            );
+        }
+        found.insert((property.abbr_name(), value));
+    }
+
+    for property in registry::properties() {
+        for value in property.values() {
+            assert!(
+                found.contains(&(property.abbr_name(), value)),
+                "Value `{}` of property `{}` missing from PropertyValueAliases.txt",
+                value.long_name(),
+                property.long_name()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_binary_value_aliases() {
+    let alphabetic = registry::property("Alpha").unwrap();
+    for &(name, value) in &[
+        ("Y", true),
+        ("Yes", true),
+        ("T", true),
+        ("True", true),
+        ("N", false),
+        ("No", false),
+        ("F", false),
+        ("False", false),
+    ] {
+        assert_eq!(
+            alphabetic.value_from_name(name),
+            Some(PropertyValue::Binary(value))
+        );
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate unic_char_range;
+
+use unic_char_range::CharRange;
+use unic_ucd::registry::{self, PropertyValue};
+
+#[test]
+fn test_properties() {
+    for property in registry::properties() {
+        let found = registry::property(property.abbr_name()).unwrap();
+        assert_eq!(found.long_name(), property.long_name());
+        let found = registry::property(property.long_name()).unwrap();
+        assert_eq!(found.abbr_name(), property.abbr_name());
+
+        assert!(property.values().count() > 0);
+        for value in property.values() {
+            assert_eq!(property.value_from_name(value.abbr_name()), Some(value));
+            assert_eq!(property.value_from_name(value.long_name()), Some(value));
+        }
+    }
+
+    assert_eq!(
+        registry::property("General Category").unwrap().abbr_name(),
+        "gc"
+    );
+    assert_eq!(registry::property("space").unwrap().abbr_name(), "WSpace");
+    assert!(registry::property("Not_A_Property").is_none());
+
+    let gc = registry::property("gc").unwrap();
+    assert_eq!(gc.values().count(), 30);
+
+    let alphabetic = registry::property("Alpha").unwrap();
+    let values: Vec<_> = alphabetic.values().map(|value| value.abbr_name()).collect();
+    assert_eq!(values, ["N", "Y"]);
+}
+
+#[test]
+fn test_value_of() {
+    let value = registry::value_of("gc", 'A').unwrap();
+    assert_eq!(value.abbr_name(), "Lu");
+    assert_eq!(value.long_name(), "Uppercase_Letter");
+    assert_eq!(value.to_string(), "Uppercase Letter");
+
+    let value = registry::value_of("Bidi_Class", '\u{0627}').unwrap();
+    assert_eq!(value.abbr_name(), "AL");
+
+    let value = registry::value_of("blk", '\u{03B1}').unwrap();
+    assert_eq!(value.long_name(), "Greek and Coptic");
+    assert_eq!(
+        registry::value_of("Block", '\u{0378}').map(|_| ()),
+        Some(())
+    );
+    assert_eq!(registry::value_of("Block", '\u{2FE0}'), None);
+
+    assert_eq!(
+        registry::value_of("Alphabetic", 'a'),
+        Some(PropertyValue::Binary(true))
+    );
+    assert_eq!(
+        registry::value_of("isAlpha", '1').and_then(|value| value.as_bool()),
+        Some(false)
+    );
+
+    assert_eq!(registry::value_of("nt", 'a'), None);
+    assert_eq!(registry::value_of("Not_A_Property", 'a'), None);
+}
+
+#[test]
+fn test_chars_where() {
+    let arabic_letters = registry::chars_where("Bidi_Class", "AL").unwrap();
+    assert!(arabic_letters.contains('\u{0627}'));
+    assert!(!arabic_letters.contains('a'));
+
+    let greek = registry::chars_where("Block", "greek and coptic").unwrap();
+    let ranges: Vec<_> = greek.ranges().collect();
+    assert_eq!(ranges, [chars!('\u{0370}'..='\u{03FF}')]);
+    // Blocks are not named by their abbreviated aliases.
+    assert!(registry::chars_where("Block", "Greek").is_none());
+
+    let digits = registry::chars_where("gc", "digit").unwrap();
+    assert_eq!(digits.iter().take(10).collect::<String>(), "0123456789");
+
+    let inherited = registry::chars_where("sc", "Qaai").unwrap();
+    assert_eq!(inherited.value().long_name(), "Inherited");
+    assert!(inherited.contains('\u{0300}'));
+
+    // Characters missing from data tables have the default value.
+    let unassigned = registry::chars_where("gc", "Cn").unwrap();
+    assert!(unassigned.contains('\u{0378}'));
+    assert!(unassigned.contains('\u{10FFFF}'));
+    assert_eq!(
+        unassigned.ranges().last(),
+        Some(chars!('\u{10FFFE}'..='\u{10FFFF}'))
+    );
+
+    let not_white_space = registry::chars_where("WSpace", "F").unwrap();
+    assert!(not_white_space.contains('a'));
+    assert!(!not_white_space.contains(' '));
+    assert_eq!(
+        not_white_space.ranges().next(),
+        Some(chars!('\u{0}'..='\u{8}'))
+    );
+
+    assert!(registry::chars_where("gc", "Not_A_Value").is_none());
+    assert!(registry::chars_where("Not_A_Property", "Y").is_none());
+}
+
+/// The sets of all values of a property partition the code space.
+#[test]
+fn test_chars_where_partition() {
+    for name in &["gc", "bc", "blk", "WSpace", "nt", "hst"] {
+        let property = registry::property(name).unwrap();
+        let mut count = 0;
+        for value in property.values() {
+            let set = property.chars_where(value);
+            let mut previous: Option<CharRange> = None;
+            for range in set.ranges() {
+                if let Some(previous) = previous {
+                    assert!(previous.high < range.low);
+                }
+                assert_eq!(property.value_of(range.low), Some(value));
+                assert_eq!(property.value_of(range.high), Some(value));
+                count += range.len();
+                previous = Some(range);
+            }
+        }
+        let missing = chars!(..)
+            .iter()
+            .filter(|&ch| property.value_of(ch).is_none())
+            .count();
+        assert_eq!(count + missing, chars!(..).len(), "{}", name);
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![warn(
+    bad_style,
+    missing_debug_implementations,
+    missing_docs,
+    unconditional_recursion
+)]
+#![forbid(unsafe_code)]
+
+//! # UNIC — Unicode Display Width
+//!
+//! A component of [`unic`: Unicode and Internationalization Crates for Rust](/unic/).
+//!
+//! This UNIC component computes the number of columns text takes in monospace environments, such
+//! as terminals, from the `East_Asian_Width` character property ([Unicode Standard Annex #11 -
+//! East Asian Width](https://www.unicode.org/reports/tr11/)) and the emoji presentation of
+//! characters ([Unicode Technical Standard #51 - Unicode
+//! Emoji](https://www.unicode.org/reports/tr51/)).
+//!
+//! Widths are computed per grapheme cluster, so that combining marks, emoji modifiers and emoji
+//! ZWJ sequences do not add to the width of their base.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unic_width::{display_width, display_width_with, AmbiguousWidth};
+//!
+//! assert_eq!(display_width("abc"), 3);
+//! assert_eq!(display_width("\u{65e5}\u{672c}"), 4);
+//! assert_eq!(display_width("e\u{301}"), 1);
+//! assert_eq!(display_width("\u{1f44d}\u{1f3fd}"), 2);
+//!
+//! assert_eq!(display_width("\u{2190}"), 1);
+//! assert_eq!(display_width_with("\u{2190}", AmbiguousWidth::Wide), 2);
+//! ```
+
+mod pkg_info;
+pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
+
+pub use unic_ucd_east_asian_width::UNICODE_VERSION;
+
+mod width;
+pub use crate::width::{display_width, display_width_with, grapheme_width, AmbiguousWidth};
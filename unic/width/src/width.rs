@@ -0,0 +1,202 @@
+// Copyright 2018 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_emoji_char::{is_emoji, is_emoji_modifier, is_emoji_modifier_base, is_emoji_presentation};
+use unic_segment::Graphemes;
+use unic_ucd_category::GeneralCategory;
+use unic_ucd_common::is_default_ignorable_code_point;
+use unic_ucd_east_asian_width::EastAsianWidth;
+
+const VARIATION_SELECTOR_15: char = '\u{FE0E}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+
+/// Display width of characters with `East_Asian_Width=Ambiguous`.
+///
+/// Ambiguous characters, such as Greek and Cyrillic letters, box drawing characters and many
+/// symbols, are wide in East Asian legacy character sets and narrow elsewhere.
+///
+/// Reference: <https://www.unicode.org/reports/tr11/#Recommendations>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AmbiguousWidth {
+    /// One column, for non-East Asian contexts.
+    Narrow,
+
+    /// Two columns, for East Asian (CJK) contexts.
+    Wide,
+}
+
+impl Default for AmbiguousWidth {
+    fn default() -> Self {
+        AmbiguousWidth::Narrow
+    }
+}
+
+/// Display width of a string, with Ambiguous characters taking one column.
+///
+/// See [`display_width_with()`] for details.
+pub fn display_width(s: &str) -> usize {
+    display_width_with(s, AmbiguousWidth::Narrow)
+}
+
+/// Display width of a string, with the given treatment of Ambiguous characters.
+///
+/// The width is the sum of the width of the grapheme clusters of the string, as returned by
+/// [`grapheme_width()`].
+pub fn display_width_with(s: &str, ambiguous: AmbiguousWidth) -> usize {
+    Graphemes::new(s)
+        .map(|grapheme| grapheme_width(grapheme, ambiguous))
+        .sum()
+}
+
+/// Display width of a single grapheme cluster, with the given treatment of Ambiguous characters.
+///
+/// The width is decided by the first character of the cluster that is not zero-width:
+///
+/// * Controls, nonspacing and enclosing marks, format characters and default ignorable code
+///   points take no columns.
+///
+/// * Emoji followed by VARIATION SELECTOR-16, emoji modifier sequences and characters with
+///   `Emoji_Presentation` take two columns, and emoji followed by VARIATION SELECTOR-15 take one
+///   column, or two for Ambiguous characters in East Asian contexts.
+///
+/// * A pair of regional indicators (a flag) takes two columns, and a single one takes one.
+///
+/// * Otherwise, `Wide` and `Fullwidth` characters take two columns, `Ambiguous` characters take
+///   one or two depending on `ambiguous`, and all others take one.
+pub fn grapheme_width(grapheme: &str, ambiguous: AmbiguousWidth) -> usize {
+    let mut chars = grapheme.chars().skip_while(|&ch| is_zero_width(ch));
+    let base = match chars.next() {
+        Some(ch) => ch,
+        None => return 0,
+    };
+    let next = chars.next();
+
+    if is_regional_indicator(base) {
+        return if matches!(next, Some(ch) if is_regional_indicator(ch)) {
+            2
+        } else {
+            1
+        };
+    }
+
+    if is_emoji(base) {
+        match next {
+            Some(VARIATION_SELECTOR_16) => return 2,
+            Some(VARIATION_SELECTOR_15) => {
+                return match EastAsianWidth::of(base) {
+                    EastAsianWidth::Ambiguous => ambiguous_width(ambiguous),
+                    _ => 1,
+                };
+            }
+            Some(ch) if is_emoji_modifier(ch) && is_emoji_modifier_base(base) => return 2,
+            _ if is_emoji_presentation(base) => return 2,
+            _ => {}
+        }
+    }
+
+    match EastAsianWidth::of(base) {
+        EastAsianWidth::Wide | EastAsianWidth::Fullwidth => 2,
+        EastAsianWidth::Ambiguous => ambiguous_width(ambiguous),
+        _ => 1,
+    }
+}
+
+fn ambiguous_width(ambiguous: AmbiguousWidth) -> usize {
+    match ambiguous {
+        AmbiguousWidth::Narrow => 1,
+        AmbiguousWidth::Wide => 2,
+    }
+}
+
+fn is_zero_width(ch: char) -> bool {
+    matches!(
+        GeneralCategory::of(ch),
+        GeneralCategory::Control
+            | GeneralCategory::NonspacingMark
+            | GeneralCategory::EnclosingMark
+            | GeneralCategory::Format
+    ) || is_default_ignorable_code_point(ch)
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{display_width, display_width_with, grapheme_width, AmbiguousWidth};
+
+    #[test]
+    fn test_narrow_and_wide() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("Hello, world!"), 13);
+        assert_eq!(display_width("\u{d55c}\u{ad6d}\u{c5b4}"), 6);
+        assert_eq!(display_width("\u{65e5}\u{672c}\u{8a9e}"), 6);
+        assert_eq!(display_width("\u{ff21}\u{ff22}"), 4);
+        assert_eq!(display_width("\u{ff71}\u{ff72}"), 2);
+        assert_eq!(display_width("\u{3000}"), 2);
+    }
+
+    #[test]
+    fn test_zero_width() {
+        assert_eq!(display_width("\r\n"), 0);
+        assert_eq!(display_width("a\u{0}b"), 2);
+        assert_eq!(display_width("\u{200b}"), 0);
+        assert_eq!(display_width("\u{ad}"), 0);
+        assert_eq!(display_width("\u{feff}abc"), 3);
+        assert_eq!(display_width("\u{301}"), 0);
+        assert_eq!(display_width("e\u{301}\u{20dd}"), 1);
+        assert_eq!(display_width("\u{0600}1"), 1);
+        assert_eq!(display_width("\u{1100}\u{1161}\u{11a8}"), 2);
+    }
+
+    #[test]
+    fn test_emoji() {
+        assert_eq!(display_width("\u{1f600}"), 2);
+        assert_eq!(display_width("\u{263a}"), 1);
+        assert_eq!(display_width("\u{263a}\u{fe0f}"), 2);
+        assert_eq!(display_width("\u{231a}"), 2);
+        assert_eq!(display_width("\u{231a}\u{fe0e}"), 1);
+        assert_eq!(display_width("\u{261d}\u{1f3fb}"), 2);
+        assert_eq!(display_width("\u{1f3fb}"), 2);
+        assert_eq!(display_width("1\u{fe0f}\u{20e3}"), 2);
+        assert_eq!(display_width("1\u{20e3}"), 1);
+        assert_eq!(
+            display_width("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"),
+            2
+        );
+        assert_eq!(display_width("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}"), 2);
+    }
+
+    #[test]
+    fn test_regional_indicators() {
+        assert_eq!(display_width("\u{1f1ef}\u{1f1f5}"), 2);
+        assert_eq!(display_width("\u{1f1ef}\u{1f1f5}\u{1f1fa}\u{1f1f8}"), 4);
+        assert_eq!(display_width("\u{1f1ef}"), 1);
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        assert_eq!(display_width("\u{3b1}\u{3b2}"), 2);
+        assert_eq!(
+            display_width_with("\u{3b1}\u{3b2}", AmbiguousWidth::Wide),
+            4
+        );
+        assert_eq!(display_width_with("\u{2500}", AmbiguousWidth::Wide), 2);
+        assert_eq!(display_width_with("abc", AmbiguousWidth::Wide), 3);
+        assert_eq!(display_width_with("\u{65e5}", AmbiguousWidth::Wide), 2);
+        assert_eq!(
+            grapheme_width("\u{2122}\u{fe0e}", AmbiguousWidth::Narrow),
+            1
+        );
+        assert_eq!(grapheme_width("\u{2122}\u{fe0e}", AmbiguousWidth::Wide), 2);
+        assert_eq!(grapheme_width("\u{2122}\u{fe0f}", AmbiguousWidth::Wide), 2);
+    }
+}